@@ -4,9 +4,9 @@ use std::hint::black_box;
 use arinc424::records::{Airport, Runway, Waypoint};
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 
-const AIRPORT: &'static [u8] = b"SUSAP KJFKK6AJFK     0     145YHN40382374W073464329W013000013         1800018000C    MNAR    JOHN F KENNEDY INTL           300671912";
-const WAYPOINT: &'static [u8] = b"SEURPCEDDHED W1    ED0    V     N53341894E009404512                                 WGE           WHISKEY1                 122922407";
-const RUNWAY: &'static [u8] = b"SUSAP KJFKK6GRW04L   0120790440 N40372318W073470505         -0028300012046057200IIHIQ1                                     305541709";
+const AIRPORT: &[u8] = b"SUSAP KJFKK6AJFK     0     145YHN40382374W073464329W013000013         1800018000C    MNAR    JOHN F KENNEDY INTL           300671912";
+const WAYPOINT: &[u8] = b"SEURPCEDDHED W1    ED0    V     N53341894E009404512                                 WGE           WHISKEY1                 122922407";
+const RUNWAY: &[u8] = b"SUSAP KJFKK6GRW04L   0120790440 N40372318W073470505         -0028300012046057200IIHIQ1                                     305541709";
 
 /// Benchmark individual record parsing
 fn bench_records(c: &mut Criterion) {
@@ -33,7 +33,10 @@ fn bench_to_owned(c: &mut Criterion) {
     c.bench_function("numeric to u32", |b| {
         b.iter(|| {
             let rwy = Runway::try_from(black_box(RUNWAY)).expect("runway should parse");
-            let _: u32 = rwy.runway_length.as_u32().expect("runway length should parse");
+            let _: u32 = rwy
+                .runway_length
+                .as_u32()
+                .expect("runway length should parse");
         })
     });
 }
@@ -61,10 +64,8 @@ fn bench_faa_cifp(c: &mut Criterion) {
             let mut count = 0;
             for chunk in data.chunks_exact(132) {
                 // Section 'P', Subsection 'A' = Airport
-                if chunk[4] == b'P' && chunk[5] == b'A' {
-                    if let Ok(_) = Airport::try_from(chunk) {
-                        count += 1;
-                    }
+                if chunk[4] == b'P' && chunk[5] == b'A' && Airport::try_from(chunk).is_ok() {
+                    count += 1;
                 }
             }
             black_box(count)
@@ -77,10 +78,8 @@ fn bench_faa_cifp(c: &mut Criterion) {
             let mut count = 0;
             for chunk in data.chunks_exact(132) {
                 // Section 'P', Subsection 'G' = Runway
-                if chunk[4] == b'P' && chunk[5] == b'G' {
-                    if let Ok(_) = Runway::try_from(chunk) {
-                        count += 1;
-                    }
+                if chunk[4] == b'P' && chunk[5] == b'G' && Runway::try_from(chunk).is_ok() {
+                    count += 1;
                 }
             }
             black_box(count)
@@ -93,10 +92,8 @@ fn bench_faa_cifp(c: &mut Criterion) {
             let mut count = 0;
             for chunk in data.chunks_exact(132) {
                 // Section 'E', Subsection 'A' = Waypoint
-                if chunk[4] == b'E' && chunk[5] == b'A' {
-                    if let Ok(_) = Waypoint::try_from(chunk) {
-                        count += 1;
-                    }
+                if chunk[4] == b'E' && chunk[5] == b'A' && Waypoint::try_from(chunk).is_ok() {
+                    count += 1;
                 }
             }
             black_box(count)
@@ -1,7 +1,7 @@
 use arinc424::records::{Airport, RecordKind, Records, Runway};
 use arinc424::Error;
 
-const DATA: &'static [u8] = br#"
+const DATA: &[u8] = br#"
 SUSAP KJFKK6AJFK     0     145YHN40382374W073464329W013000013         1800018000C    MNAR    JOHN F KENNEDY INTL           300671912
 SUSAP KJFKK6GRW04L   0120790440 N40372318W073470505         -0028300012046057200IIHIQ1                                     305541709
 "#;
@@ -35,9 +35,17 @@ pub enum Error {
         bytes: Vec<u8>,
         expected: &'static str,
     },
+    InvalidValue {
+        field: &'static str,
+        bytes: Vec<u8>,
+        expected: &'static str,
+    },
     NotANumber {
         bytes: Vec<u8>,
     },
+    InvalidUtf8 {
+        bytes: Vec<u8>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -68,10 +76,22 @@ impl fmt::Display for Error {
                 let s = String::from_utf8_lossy(bytes);
                 write!(f, "found \"{s}\" in {field} but should be {expected}")
             }
+            Self::InvalidValue {
+                field,
+                bytes,
+                expected,
+            } => {
+                let s = String::from_utf8_lossy(bytes);
+                write!(f, "found \"{s}\" in {field} but should be {expected}")
+            }
             Self::NotANumber { bytes } => {
                 let s = String::from_utf8_lossy(bytes);
                 write!(f, "field should be a number but is \"{s}\"")
             }
+            Self::InvalidUtf8 { bytes } => {
+                let s = String::from_utf8_lossy(bytes);
+                write!(f, "field should be valid UTF-8 but is \"{s}\"")
+            }
         }
     }
 }
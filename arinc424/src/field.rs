@@ -51,12 +51,29 @@ impl<'a, const N: usize> Alphanumeric<'a, N> {
 
     /// Returns the field as a UTF-8 string with trailing spaces removed.
     ///
+    /// Only the padding is removed, spaces within the text are kept. Use
+    /// [`as_raw_str`](Self::as_raw_str) to get the padded field.
+    ///
     /// Returns an empty string if the field contains invalid UTF-8.
     #[inline]
     pub fn as_str(&self) -> &'a str {
         self.as_raw_str().trim_end()
     }
 
+    /// Returns the field as a UTF-8 string with trailing spaces removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidUtf8`] if the field contains invalid UTF-8.
+    #[inline]
+    pub fn as_str_checked(&self) -> Result<&'a str, Error> {
+        std::str::from_utf8(self.0)
+            .map(str::trim_end)
+            .map_err(|_| Error::InvalidUtf8 {
+                bytes: self.0.to_vec(),
+            })
+    }
+
     /// Returns the field as a UTF-8 string.
     ///
     /// Returns an empty string if the field contains invalid UTF-8.
@@ -142,8 +159,51 @@ numeric_impl!(2 => as_u8: u8, as_u16: u16);
 numeric_impl!(3 => as_u8: u8, as_u16: u16);
 numeric_impl!(4 => as_u16: u16, as_u32: u32);
 numeric_impl!(5 => as_u32: u32);
+numeric_impl!(7 => as_u32: u32);
 
 impl<'a, const N: usize> Numeric<'a, N> {
+    /// Returns the field as signed integer.
+    ///
+    /// The first byte may be a sign (`-` or `+`) and the number may be padded
+    /// with leading spaces, e.g. `-0011` or `  -11` for an elevation of 11 ft
+    /// below sea level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotANumber`] if the field contains no digits or any
+    /// other character than a leading sign or padding.
+    pub fn as_i32(&self) -> Result<i32, Error> {
+        let bytes = self.0.trim_ascii_start();
+        let (sign, digits) = match bytes.split_first() {
+            Some((b'-', digits)) => (-1, digits),
+            Some((b'+', digits)) => (1, digits),
+            _ => (1, bytes),
+        };
+
+        let not_a_number = || Error::NotANumber {
+            bytes: self.0.to_vec(),
+        };
+
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(not_a_number());
+        }
+
+        digits
+            .iter()
+            .try_fold(0i32, |value, &digit| {
+                value.checked_mul(10)?.checked_add((digit & 0x0F) as i32)
+            })
+            .map(|value| sign * value)
+            .ok_or_else(not_a_number)
+    }
+
+    /// Returns the field as signed float.
+    ///
+    /// See [`as_i32`](Self::as_i32) for the accepted format.
+    pub fn as_f32(&self) -> Result<f32, Error> {
+        self.as_i32().map(|value| value as f32)
+    }
+
     /// Returns `true` if the field contains only spaces.
     #[inline]
     pub fn is_blank(&self) -> bool {
@@ -202,3 +262,54 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_trims_padding_only() {
+        let field = Alphanumeric::<30>::from_bytes(b"ITZEHOE/HUNGRIGER  WOLF       ")
+            .expect("field should parse");
+
+        assert_eq!(field.as_str(), "ITZEHOE/HUNGRIGER  WOLF");
+        assert_eq!(field.as_raw_str(), "ITZEHOE/HUNGRIGER  WOLF       ");
+    }
+
+    #[test]
+    fn checked_str_of_valid_field() {
+        let field = Alphanumeric::<5>::from_bytes(b"EDDH ").expect("field should parse");
+        assert_eq!(field.as_str_checked(), Ok("EDDH"));
+    }
+
+    #[test]
+    fn signed_numeric() {
+        let field = Numeric::<5>::from_bytes(b"  -11").expect("field should parse");
+        assert_eq!(field.as_i32(), Ok(-11));
+
+        let field = Numeric::<5>::from_bytes(b"+0042").expect("field should parse");
+        assert_eq!(field.as_i32(), Ok(42));
+    }
+
+    #[test]
+    fn malformed_signed_numeric() {
+        let field = Numeric::<5>::from_bytes(b"-00-1").expect("field should parse");
+        assert_eq!(
+            field.as_i32(),
+            Err(Error::NotANumber {
+                bytes: b"-00-1".to_vec()
+            })
+        );
+    }
+
+    #[test]
+    fn checked_str_of_invalid_utf8() {
+        let field = Alphanumeric::<5>::from_bytes(b"ED\xffH ").expect("field should parse");
+        assert_eq!(
+            field.as_str_checked(),
+            Err(Error::InvalidUtf8 {
+                bytes: b"ED\xffH ".to_vec()
+            })
+        );
+    }
+}
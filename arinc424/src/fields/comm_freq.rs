@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Error, FixedField, Numeric};
+
+/// 5.103 Communication Frequency (COMM FREQ)
+pub struct CommFreq<'a>(Numeric<'a, 7>);
+
+impl<'a> CommFreq<'a> {
+    /// Returns the frequency in kHz.
+    ///
+    /// HF frequencies are given with a resolution of 100 Hz, all other
+    /// frequencies with a resolution of 1 kHz.
+    pub fn khz(&self, units: FreqUnits) -> Result<f32, Error> {
+        self.0.as_u32().map(|freq| match units {
+            FreqUnits::HighFrequency => freq as f32 / 10.0,
+            _ => freq as f32,
+        })
+    }
+}
+
+impl<'a> FixedField<'a> for CommFreq<'a> {
+    const LENGTH: usize = 7;
+
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+        Ok(Self(Numeric::from_bytes(bytes)?))
+    }
+}
+
+/// 5.104 Frequency Units (FREQ UNITS)
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FreqUnits {
+    /// High Frequency (3 MHz - 30 MHz).
+    HighFrequency,
+    /// Very High Frequency (30 MHz - 200 MHz) with 25 kHz channel spacing.
+    VeryHighFrequency,
+    /// Very High Frequency (30 MHz - 200 MHz) with 8.33 kHz channel spacing.
+    VeryHighFrequency833,
+    /// Ultra High Frequency (200 MHz - 3000 MHz).
+    UltraHighFrequency,
+}
+
+impl FixedField<'_> for FreqUnits {
+    const LENGTH: usize = 1;
+
+    fn from_bytes(bytes: &'_ [u8]) -> Result<Self, Error> {
+        match bytes[0] {
+            b'H' => Ok(Self::HighFrequency),
+            b'V' => Ok(Self::VeryHighFrequency),
+            b'C' => Ok(Self::VeryHighFrequency833),
+            b'U' => Ok(Self::UltraHighFrequency),
+            byte => Err(Error::InvalidCharacter {
+                field: "Frequency Units",
+                byte,
+                expected: "H, V, C or U",
+            }),
+        }
+    }
+}
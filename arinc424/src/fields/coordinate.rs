@@ -22,7 +22,8 @@ impl<'a> Latitude<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if blank or if the hemisphere is neither `N` nor `S`.
+    /// Returns an error if blank, if the hemisphere is neither `N` nor `S` or
+    /// if the latitude is out of range.
     pub fn as_decimal(&self) -> Result<f64, Error> {
         let hem = self.first();
         let deg = parse_numeric!(2, u8, &self.0[1..3])? as f64;
@@ -31,6 +32,14 @@ impl<'a> Latitude<'a> {
 
         let decimal = deg + min / 60.0 + sec / 3600.0;
 
+        if min >= 60.0 || sec >= 60.0 || decimal > 90.0 {
+            return Err(Error::InvalidValue {
+                field: "Latitude",
+                bytes: self.0.to_vec(),
+                expected: "latitude within 90°",
+            });
+        }
+
         match hem {
             b'N' => Ok(decimal),
             b'S' => Ok(-decimal),
@@ -50,15 +59,24 @@ impl<'a> Longitude<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if blank or if the hemisphere is neither `W` nor `E`.
+    /// Returns an error if blank, if the hemisphere is neither `W` nor `E` or
+    /// if the longitude is out of range.
     pub fn as_decimal(&self) -> Result<f64, Error> {
         let hem = self.first();
-        let deg = parse_numeric!(3, u8, &self.0[1..4])? as f64;
+        let deg = parse_numeric!(3, u16, &self.0[1..4])? as f64;
         let min = parse_numeric!(2, u8, &self.0[4..6])? as f64;
         let sec = parse_numeric!(4, u32, &self.0[6..10])? as f64 / 100.0; // includes centiseconds
 
         let decimal = deg + min / 60.0 + sec / 3600.0;
 
+        if min >= 60.0 || sec >= 60.0 || decimal > 180.0 {
+            return Err(Error::InvalidValue {
+                field: "Longitude",
+                bytes: self.0.to_vec(),
+                expected: "longitude within 180°",
+            });
+        }
+
         match hem {
             b'E' => Ok(decimal),
             b'W' => Ok(-decimal),
@@ -88,4 +106,29 @@ mod tests {
         let long = Longitude::from_bytes(b"W0741444230").expect("longitude should parse");
         assert_eq!(long.as_decimal(), Ok(-74.24561944444444));
     }
+
+    #[test]
+    fn rejects_latitude_out_of_range() {
+        let lat = Latitude::from_bytes(b"N95000000").expect("latitude should parse");
+        assert_eq!(
+            lat.as_decimal(),
+            Err(Error::InvalidValue {
+                field: "Latitude",
+                bytes: b"N95000000".to_vec(),
+                expected: "latitude within 90°",
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_longitude_out_of_range() {
+        let long = Longitude::from_bytes(b"E9990000000").expect("longitude should parse");
+        assert!(matches!(
+            long.as_decimal(),
+            Err(Error::InvalidValue {
+                field: "Longitude",
+                ..
+            })
+        ));
+    }
 }
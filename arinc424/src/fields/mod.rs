@@ -18,6 +18,7 @@ use crate::{Alphanumeric, Numeric};
 mod arc_dist_brg;
 mod arsp_type;
 mod boundary_via;
+mod comm_freq;
 mod coordinate;
 mod cust_area;
 mod cycle;
@@ -43,6 +44,7 @@ mod waypoint_usage;
 pub use arc_dist_brg::{ArcBearing, ArcDistance};
 pub use arsp_type::ArspType;
 pub use boundary_via::{BoundaryPath, BoundaryVia};
+pub use comm_freq::{CommFreq, FreqUnits};
 pub use coordinate::{Latitude, Longitude};
 pub use cust_area::CustArea;
 pub use cycle::Cycle;
@@ -68,6 +70,7 @@ pub use waypoint_usage::WaypointUsage;
 pub type ArptHeliIdent<'a> = Alphanumeric<'a, 4>;
 pub type FileRecordNumber<'a> = Numeric<'a, 5>;
 pub type FixIdent<'a> = Alphanumeric<'a, 5>;
+pub type GateIdent<'a> = Alphanumeric<'a, 5>;
 pub type Iata<'a> = Alphanumeric<'a, 3>;
 pub type IcaoCode<'a> = Alphanumeric<'a, 2>;
 pub type NameDesc<'a> = Alphanumeric<'a, 25>;
@@ -75,6 +78,15 @@ pub type NameField<'a> = Alphanumeric<'a, 30>;
 pub type RegnCode<'a> = Alphanumeric<'a, 4>;
 pub type WaypointType<'a> = Alphanumeric<'a, 3>;
 
+/// 5.101 Communication Type (COMM TYPE)
+pub type CommType<'a> = Alphanumeric<'a, 3>;
+
+/// 5.105 Call Sign
+pub type CallSign<'a> = Alphanumeric<'a, 25>;
+
+/// 5.106 Service Indicator
+pub type ServiceInd<'a> = Alphanumeric<'a, 3>;
+
 /// 5.12 Sequence Number
 pub type SequenceNumber<'a, const N: usize> = Numeric<'a, N>;
 
@@ -86,3 +98,6 @@ pub type RequiredNavigationPerformance<'a> = Numeric<'a, 3>;
 
 /// 5.215 Controlled Airspace Classification (ARSP CLASS)
 pub type AirspaceClassification<'a> = Alphanumeric<'a, 1>;
+
+/// 5.55 Elevation (ELEV)
+pub type Elevation<'a> = Numeric<'a, 5>;
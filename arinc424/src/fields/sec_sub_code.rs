@@ -65,6 +65,7 @@ pub enum SubCodeKind {
     Gate,
     Runway,
     // Heliport, Airport
+    Communication,
     TerminalWaypoint,
     MSA,
     // CompanyRoute
@@ -133,6 +134,10 @@ impl<'a> SubCode<'a> {
                 SecCode::Heliport | SecCode::Airport => Ok(SubCodeKind::MSA),
                 _ => sub_code_error!(b'S'),
             },
+            b'V' => match sec_code {
+                SecCode::Heliport | SecCode::Airport => Ok(SubCodeKind::Communication),
+                _ => sub_code_error!(b'V'),
+            },
             _ => unimplemented!("SUB CODE D..Z"),
         }
     }
@@ -87,3 +87,4 @@ pub(crate) use arinc424_derive::Record;
 pub mod fields;
 pub mod records;
 pub use error::Error;
+pub use record::RECORD_LENGTH;
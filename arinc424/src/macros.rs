@@ -65,4 +65,19 @@ macro_rules! parse_numeric {
                 + ($b[4] & 0x0F) as $t
         )
     };
+
+    (7, $t:ty, $b:expr) => {
+        parse_numeric!(
+            7,
+            $t,
+            $b,
+            ($b[0] & 0x0F) as $t * 1000000
+                + ($b[1] & 0x0F) as $t * 100000
+                + ($b[2] & 0x0F) as $t * 10000
+                + ($b[3] & 0x0F) as $t * 1000
+                + ($b[4] & 0x0F) as $t * 100
+                + ($b[5] & 0x0F) as $t * 10
+                + ($b[6] & 0x0F) as $t
+        )
+    };
 }
@@ -33,6 +33,7 @@ pub struct Airport<'a> {
     pub latitude: Latitude<'a>,
     pub longitude: Longitude<'a>,
     pub mag_var: Option<MagVar>,
+    pub elevation: Elevation<'a>,
     #[arinc424(field = 86)]
     pub mag_true_ind: MagTrueInd,
     pub datum: Datum,
@@ -47,7 +48,7 @@ pub struct Airport<'a> {
 mod tests {
     use super::*;
 
-    const AIRPORT: &'static [u8] = b"SUSAP KJFKK6AJFK     0     145YHN40382374W073464329W013000013         1800018000C    MNAR    JOHN F KENNEDY INTL           300671912";
+    const AIRPORT: &[u8] = b"SUSAP KJFKK6AJFK     0     145YHN40382374W073464329W013000013         1800018000C    MNAR    JOHN F KENNEDY INTL           300671912";
 
     #[test]
     fn airport_record() {
@@ -67,6 +68,7 @@ mod tests {
         assert_eq!(arpt.latitude.as_decimal(), Ok(40.63992777777778));
         assert_eq!(arpt.longitude.as_decimal(), Ok(-73.77869166666666));
         assert_eq!(arpt.mag_var, Some(MagVar::West(1.3)));
+        assert_eq!(arpt.elevation.as_i32(), Ok(13));
         assert_eq!(arpt.mag_true_ind, MagTrueInd::Magnetic);
         assert_eq!(arpt.datum, Datum::NAR);
         assert_eq!(arpt.airport_name.as_str(), "JOHN F KENNEDY INTL");
@@ -74,4 +76,14 @@ mod tests {
         assert_eq!(arpt.cycle.year(), Ok(19));
         assert_eq!(arpt.cycle.cycle(), Ok(12));
     }
+
+    #[test]
+    fn airport_below_sea_level() {
+        const EHAM: &[u8] = b"SEURP EHAMEHAAMS     0     145YHN52182000E004454000E0010-0011         1800018000C    MNAR    SCHIPHOL                      300671912";
+
+        let arpt = Airport::try_from(EHAM).expect("airport should parse");
+
+        assert_eq!(arpt.elevation.as_i32(), Ok(-11));
+        assert_eq!(arpt.elevation.as_f32(), Ok(-11.0));
+    }
 }
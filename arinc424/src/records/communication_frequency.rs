@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fields::*;
+use crate::Record;
+
+// TODO: add missing fields
+#[derive(Record)]
+pub struct CommunicationFrequency<'a> {
+    pub record_type: RecordType,
+    pub cust_area: CustArea<'a>,
+    pub sec_code: SecCode,
+    #[arinc424(skip(1))]
+    pub arpt_ident: ArptHeliIdent<'a>,
+    pub icao_code: IcaoCode<'a>,
+    pub sub_code: SubCode<'a>,
+    pub comm_type: CommType<'a>,
+    pub comm_freq: CommFreq<'a>,
+    #[arinc424(skip(1))]
+    pub freq_units: FreqUnits,
+    pub cont_nr: ContNr<'a>,
+    pub service_ind: ServiceInd<'a>,
+    #[arinc424(field = 33)]
+    pub latitude: Option<Latitude<'a>>,
+    pub longitude: Option<Longitude<'a>>,
+    #[arinc424(field = 99)]
+    pub call_sign: CallSign<'a>,
+    pub frn: FileRecordNumber<'a>,
+    pub cycle: Cycle<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ATIS: &[u8] = b"SEURP EDDHEDVATI0123125 V0      N53374900E009591762                                               HAMBURG INFORMATION      356472409";
+
+    #[test]
+    fn communication_frequency_record() {
+        let comm = CommunicationFrequency::try_from(ATIS).expect("frequency should parse");
+
+        assert_eq!(comm.record_type, RecordType::Standard);
+        assert_eq!(comm.cust_area, CustArea::EUR);
+        assert_eq!(comm.sec_code, SecCode::Airport);
+        assert_eq!(comm.arpt_ident.as_str(), "EDDH");
+        assert_eq!(comm.icao_code.as_str(), "ED");
+        assert_eq!(
+            comm.sub_code.kind(&comm.sec_code),
+            Ok(SubCodeKind::Communication)
+        );
+        assert_eq!(comm.comm_type.as_str(), "ATI");
+        assert_eq!(comm.freq_units, FreqUnits::VeryHighFrequency);
+        assert_eq!(comm.comm_freq.khz(comm.freq_units), Ok(123125.0));
+        assert_eq!(comm.cont_nr.as_str(), "0");
+        assert!(comm.service_ind.is_blank());
+        assert_eq!(
+            comm.latitude.map(|lat| lat.as_decimal()),
+            Some(Ok(53.63027777777778))
+        );
+        assert_eq!(comm.call_sign.as_str(), "HAMBURG INFORMATION");
+        assert_eq!(comm.frn.as_u32(), Ok(35647));
+        assert_eq!(comm.cycle.year(), Ok(24));
+        assert_eq!(comm.cycle.cycle(), Ok(9));
+    }
+}
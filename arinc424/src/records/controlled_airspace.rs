@@ -58,7 +58,7 @@ pub struct ControlledAirspace<'a> {
 mod tests {
     use super::*;
 
-    const AIRSPACE: &'static [u8] = b"SUSAUCK6TKJFK PAB  A00100     R N40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703";
+    const AIRSPACE: &[u8] = b"SUSAUCK6TKJFK PAB  A00100     R N40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703";
 
     #[test]
     fn airport_record() {
@@ -81,8 +81,8 @@ mod tests {
         assert_eq!(arsp.cont_nr.as_str(), "0");
         assert_eq!(arsp.level, None);
         assert_eq!(arsp.time_cd, None);
-        assert_eq!(bool::from(arsp.notam), false);
-        assert_eq!(bool::from(arsp.uav), false);
+        assert!(!bool::from(arsp.notam));
+        assert!(!bool::from(arsp.uav));
         assert_eq!(arsp.bdry_via.path, BoundaryPath::ClockwiseArc);
         assert_eq!(
             arsp.latitude.map(|lat| lat.as_decimal()),
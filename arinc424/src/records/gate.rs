@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fields::*;
+use crate::Record;
+
+#[derive(Record)]
+pub struct Gate<'a> {
+    pub record_type: RecordType,
+    pub cust_area: CustArea<'a>,
+    pub sec_code: SecCode,
+    #[arinc424(skip(1))]
+    pub arpt_ident: ArptHeliIdent<'a>,
+    pub icao_code: IcaoCode<'a>,
+    pub sub_code: SubCode<'a>,
+    pub gate_ident: GateIdent<'a>,
+    #[arinc424(skip(3))]
+    pub cont_nr: ContNr<'a>,
+    #[arinc424(skip(10))]
+    pub latitude: Latitude<'a>,
+    pub longitude: Longitude<'a>,
+    #[arinc424(field = 99)]
+    pub name: NameDesc<'a>,
+    pub frn: FileRecordNumber<'a>,
+    pub cycle: Cycle<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GATE: &[u8] = b"SEURP EDDHEDBA12     0          N53374200E009591700                                               GATE A12                 123452401";
+
+    #[test]
+    fn gate_record() {
+        let gate = Gate::try_from(GATE).expect("gate should parse");
+
+        assert_eq!(gate.record_type, RecordType::Standard);
+        assert_eq!(gate.cust_area, CustArea::EUR);
+        assert_eq!(gate.sec_code, SecCode::Airport);
+        assert_eq!(gate.arpt_ident.as_str(), "EDDH");
+        assert_eq!(gate.icao_code.as_str(), "ED");
+        assert_eq!(gate.sub_code.kind(&gate.sec_code), Ok(SubCodeKind::Gate));
+        assert_eq!(gate.gate_ident.as_str(), "A12");
+        assert_eq!(gate.cont_nr.as_str(), "0");
+        assert_eq!(gate.latitude.as_decimal(), Ok(53.62833333333333));
+        assert_eq!(gate.longitude.as_decimal(), Ok(9.988055555555555));
+        assert_eq!(gate.name.as_str(), "GATE A12");
+        assert_eq!(gate.frn.as_u32(), Ok(12345));
+        assert_eq!(gate.cycle.year(), Ok(24));
+        assert_eq!(gate.cycle.cycle(), Ok(1));
+    }
+}
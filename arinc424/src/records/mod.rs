@@ -14,19 +14,24 @@
 // limitations under the License.
 
 mod airport;
+mod communication_frequency;
 mod controlled_airspace;
+mod gate;
 mod restrictive_airspace;
 mod runway;
 mod waypoint;
 
 pub use airport::Airport;
+pub use communication_frequency::CommunicationFrequency;
 pub use controlled_airspace::ControlledAirspace;
+pub use gate::Gate;
 pub use restrictive_airspace::RestrictiveAirspace;
 pub use runway::Runway;
 pub use waypoint::Waypoint;
 
 use log::{debug, trace, warn};
 
+use crate::fields::RecordType;
 use crate::record::RECORD_LENGTH;
 
 #[derive(Debug)]
@@ -36,11 +41,12 @@ pub enum RecordKind {
     RestrictiveAirspace,
     Waypoint,
     Runway,
+    Gate,
+    Comm,
 }
 
 pub struct Records<'a> {
-    data: &'a [u8],
-    pos: usize,
+    raw: RawRecords<'a>,
 }
 
 impl<'a> Records<'a> {
@@ -67,83 +73,168 @@ impl<'a> Records<'a> {
     /// # }
     /// ```
     pub fn new(data: &'a [u8]) -> Self {
-        debug!("parsing ARINC 424 data ({} bytes)", data.len());
-        Self { data, pos: 0 }
+        Self {
+            raw: RawRecords::new(data),
+        }
+    }
+
+    /// Skips tailored records, i.e. records with operator-specific data, and
+    /// yields only standard records.
+    pub fn standard_only(mut self) -> Self {
+        self.raw = self.raw.standard_only();
+        self
     }
 }
 
+/// Returns the fixed length of an ARINC 424 record in bytes.
+///
+/// See [`RECORD_LENGTH`].
+pub const fn record_length() -> usize {
+    RECORD_LENGTH
+}
+
 impl<'a> Iterator for Records<'a> {
     type Item = (RecordKind, &'a [u8]);
 
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((_, sec_code, sub_code, record)) = self.raw.next() {
+            let offset = self.raw.pos - RECORD_LENGTH;
+
+            // just a convenience...
+            macro_rules! record {
+                ($t:expr) => {
+                    return Some(($t, record))
+                };
+            }
+
+            match (sec_code, sub_code) {
+                (b'E', b'A') | (b'P', b'C') => {
+                    trace!("parsed waypoint record at byte offset {}", offset);
+                    record!(RecordKind::Waypoint);
+                }
+                (b'P', b' ') => match record[12] {
+                    b'A' => {
+                        trace!("parsed airport record at byte offset {}", offset);
+                        record!(RecordKind::Airport);
+                    }
+                    // primary record
+                    b'B' if record[21] == b'0' => {
+                        trace!("parsed gate record at byte offset {}", offset);
+                        record!(RecordKind::Gate)
+                    }
+                    // primary record
+                    b'V' if record[25] == b'0' => {
+                        trace!("parsed communication record at byte offset {}", offset);
+                        record!(RecordKind::Comm)
+                    }
+                    // primary record
+                    b'G' if record[21] == b'0' => {
+                        trace!("parsed runway record at byte offset {}", offset);
+                        record!(RecordKind::Runway)
+                    }
+                    _ => {}
+                },
+                (b'U', b'C') => {
+                    trace!(
+                        "parsed controlled airspace record at byte offset {}",
+                        offset
+                    );
+                    record!(RecordKind::ControlledAirspace);
+                }
+                (b'U', b'R') => {
+                    trace!(
+                        "parsed restricted airspace record at byte offset {}",
+                        offset
+                    );
+                    record!(RecordKind::RestrictiveAirspace)
+                }
+                _ => {
+                    trace!(
+                        "skipping unhandled record (sec={}, sub={}) at byte offset {}",
+                        sec_code as char,
+                        sub_code as char,
+                        offset
+                    );
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over all standard and tailored records.
+///
+/// Unlike [`Records`], the raw records are not classified into a
+/// [`RecordKind`]. Each record is yielded with its record type, its section and
+/// subsection code and its bytes, regardless of whether this crate models the
+/// record. This allows to handle record families like airways or procedures
+/// that have no typed record yet.
+///
+/// The subsection code is read from column 6. Note that airport and heliport
+/// records (sections `P` and `H`) have a blank in column 6 and carry their
+/// subsection code in column 13 instead.
+///
+/// # Examples
+///
+/// ```
+/// # use arinc424::records::RawRecords;
+/// # fn count_airways(data: &[u8]) -> usize {
+/// RawRecords::new(data)
+///     .filter(|(_, sec_code, sub_code, _)| (*sec_code, *sub_code) == (b'E', b'R'))
+///     .count()
+/// # }
+/// ```
+pub struct RawRecords<'a> {
+    data: &'a [u8],
+    pos: usize,
+    standard_only: bool,
+}
+
+impl<'a> RawRecords<'a> {
+    /// Creates a new raw record iterator from a byte slice.
+    pub fn new(data: &'a [u8]) -> Self {
+        check_length(data);
+        Self {
+            data,
+            pos: 0,
+            standard_only: false,
+        }
+    }
+
+    /// Skips tailored records, i.e. records with operator-specific data, and
+    /// yields only standard records.
+    pub fn standard_only(mut self) -> Self {
+        self.standard_only = true;
+        self
+    }
+}
+
+impl<'a> Iterator for RawRecords<'a> {
+    type Item = (RecordType, u8, u8, &'a [u8]);
+
     fn next(&mut self) -> Option<Self::Item> {
         while self.pos + RECORD_LENGTH <= self.data.len() {
             // Standard or tailored record type
             match self.data[self.pos] {
-                b'S' | b'T' => {
+                byte @ (b'S' | b'T') => {
                     let record = &self.data[self.pos..self.pos + RECORD_LENGTH];
                     self.pos += RECORD_LENGTH;
 
-                    // just a convenience...
-                    macro_rules! record {
-                        ($t:expr) => {
-                            return Some(($t, record))
-                        };
+                    if byte == b'T' && self.standard_only {
+                        trace!(
+                            "skipping tailored record at byte offset {}",
+                            self.pos - RECORD_LENGTH
+                        );
+                        continue;
                     }
 
-                    let sec_code = record[4];
-                    let sub_code = record[5];
-
-                    match (sec_code, sub_code) {
-                        (b'E', b'A') | (b'P', b'C') => {
-                            trace!(
-                                "parsed waypoint record at byte offset {}",
-                                self.pos - RECORD_LENGTH
-                            );
-                            record!(RecordKind::Waypoint);
-                        }
-                        (b'P', b' ') => match record[12] {
-                            b'A' => {
-                                trace!(
-                                    "parsed airport record at byte offset {}",
-                                    self.pos - RECORD_LENGTH
-                                );
-                                record!(RecordKind::Airport);
-                            }
-                            b'G' => {
-                                if record[21] == b'0' {
-                                    trace!(
-                                        "parsed runway record at byte offset {}",
-                                        self.pos - RECORD_LENGTH
-                                    );
-                                    // primary record
-                                    record!(RecordKind::Runway)
-                                }
-                            }
-                            _ => {}
-                        },
-                        (b'U', b'C') => {
-                            trace!(
-                                "parsed controlled airspace record at byte offset {}",
-                                self.pos - RECORD_LENGTH
-                            );
-                            record!(RecordKind::ControlledAirspace);
-                        }
-                        (b'U', b'R') => {
-                            trace!(
-                                "parsed restricted airspace record at byte offset {}",
-                                self.pos - RECORD_LENGTH
-                            );
-                            record!(RecordKind::RestrictiveAirspace)
-                        }
-                        _ => {
-                            trace!(
-                                "skipping unhandled record (sec={}, sub={}) at byte offset {}",
-                                sec_code as char,
-                                sub_code as char,
-                                self.pos - RECORD_LENGTH
-                            );
-                        }
-                    }
+                    let record_type = match byte {
+                        b'S' => RecordType::Standard,
+                        _ => RecordType::Tailored,
+                    };
+
+                    return Some((record_type, record[4], record[5], record));
                 }
                 b'\n' | b'\r' => {
                     self.pos += 1;
@@ -161,3 +252,173 @@ impl<'a> Iterator for Records<'a> {
         None
     }
 }
+
+/// An iterator over records that owns the data.
+///
+/// Unlike [`Records`], the iterator doesn't borrow the data and yields copies
+/// of the records' bytes. Thus, the records can outlive the scope in which the
+/// data was read, e.g. to load the data on one thread and parse the records on
+/// another.
+///
+/// # Examples
+///
+/// ```
+/// # use arinc424::records::{Airport, OwnedRecords, RecordKind};
+/// # fn read_file() -> std::io::Result<()> {
+/// let data = std::fs::read("FAACIFP18")?;
+///
+/// let airports = std::thread::spawn(move || {
+///     OwnedRecords::new(data)
+///         .filter(|(kind, _)| matches!(kind, RecordKind::Airport))
+///         .count()
+/// });
+/// # Ok(())
+/// # }
+/// ```
+pub struct OwnedRecords {
+    data: Vec<u8>,
+    pos: usize,
+    standard_only: bool,
+}
+
+impl OwnedRecords {
+    /// Creates a new record iterator that takes ownership of the data.
+    pub fn new(data: Vec<u8>) -> Self {
+        check_length(&data);
+        Self {
+            data,
+            pos: 0,
+            standard_only: false,
+        }
+    }
+
+    /// Skips tailored records, i.e. records with operator-specific data, and
+    /// yields only standard records.
+    pub fn standard_only(mut self) -> Self {
+        self.standard_only = true;
+        self
+    }
+}
+
+impl Iterator for OwnedRecords {
+    type Item = (RecordKind, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // continue with borrowed records where the last record ended
+        let mut records = Records {
+            raw: RawRecords {
+                data: &self.data,
+                pos: self.pos,
+                standard_only: self.standard_only,
+            },
+        };
+
+        let record = records.next().map(|(kind, bytes)| (kind, bytes.to_vec()));
+        self.pos = records.raw.pos;
+        record
+    }
+}
+
+/// Warns if the data can't be split into records.
+fn check_length(data: &[u8]) {
+    debug!("parsing ARINC 424 data ({} bytes)", data.len());
+
+    // records may be separated by line breaks which don't count
+    let record_bytes = data
+        .iter()
+        .filter(|&&byte| byte != b'\n' && byte != b'\r')
+        .count();
+
+    if record_bytes % RECORD_LENGTH != 0 {
+        warn!(
+            "ARINC 424 data of {} bytes is no multiple of {} bytes, the data may be truncated",
+            record_bytes, RECORD_LENGTH
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AIRPORT: &[u8] = b"SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409";
+
+    #[test]
+    fn records_have_record_length() {
+        assert_eq!(record_length(), 132);
+        assert_eq!(AIRPORT.len(), RECORD_LENGTH);
+
+        let data = [AIRPORT, b"\n", AIRPORT, b"\r\n"].concat();
+        let records: Vec<_> = Records::new(&data).collect();
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(
+            |(kind, bytes)| matches!(kind, RecordKind::Airport) && bytes.len() == RECORD_LENGTH
+        ));
+    }
+
+    #[test]
+    fn truncated_record_is_skipped() {
+        let data = [AIRPORT, b"\n", &AIRPORT[..100]].concat();
+        assert_eq!(Records::new(&data).count(), 1);
+    }
+
+    #[test]
+    fn raw_records_include_unmodeled_records() {
+        // an enroute airway record that has no typed record
+        let mut airway = [b' '; RECORD_LENGTH];
+        airway[..6].copy_from_slice(b"SEURER");
+
+        let data = [AIRPORT, b"\n", &airway, b"\n", AIRPORT].concat();
+        let raw: Vec<_> = RawRecords::new(&data)
+            .map(|(_, sec_code, sub_code, _)| (sec_code, sub_code))
+            .collect();
+
+        assert_eq!(raw, vec![(b'P', b' '), (b'E', b'R'), (b'P', b' ')]);
+        assert_eq!(Records::new(&data).count(), 2);
+    }
+
+    #[test]
+    fn tailored_records_are_skipped_in_standard_only_mode() {
+        let mut tailored = AIRPORT.to_vec();
+        tailored[0] = b'T';
+
+        let data = [AIRPORT, b"\n", &tailored, b"\n", AIRPORT].concat();
+
+        let record_types: Vec<_> = RawRecords::new(&data)
+            .map(|(record_type, ..)| record_type)
+            .collect();
+        assert_eq!(
+            record_types,
+            vec![
+                RecordType::Standard,
+                RecordType::Tailored,
+                RecordType::Standard
+            ]
+        );
+
+        assert_eq!(Records::new(&data).count(), 3);
+        assert_eq!(Records::new(&data).standard_only().count(), 2);
+        assert!(Records::new(&data)
+            .standard_only()
+            .all(|(_, bytes)| bytes[0] == b'S'));
+        assert_eq!(OwnedRecords::new(data).standard_only().count(), 2);
+    }
+
+    #[test]
+    fn owned_records_outlive_data() {
+        let records = {
+            let data = [AIRPORT, b"\n", AIRPORT].concat();
+            OwnedRecords::new(data)
+        };
+
+        let records: Vec<_> = std::thread::spawn(move || records.collect())
+            .join()
+            .expect("thread should not panic");
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|(kind, bytes)| {
+            matches!(kind, RecordKind::Airport) && bytes.as_slice() == AIRPORT
+        }));
+    }
+}
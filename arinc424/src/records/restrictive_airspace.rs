@@ -77,8 +77,8 @@ mod tests {
         assert_eq!(arsp.cont_nr.as_str(), "1");
         assert_eq!(arsp.level, Some(Level::LowLevelAirwaysAltitudes));
         assert_eq!(arsp.time_cd, None);
-        assert_eq!(bool::from(arsp.notam), false);
-        assert_eq!(bool::from(arsp.uav), false);
+        assert!(!bool::from(arsp.notam));
+        assert!(!bool::from(arsp.uav));
         assert_eq!(arsp.bdry_via.path, BoundaryPath::RhumbLine);
         assert!(!arsp.bdry_via.return_to_origin);
         assert_eq!(
@@ -45,7 +45,7 @@ pub struct Runway<'a> {
 mod tests {
     use super::*;
 
-    const RUNWAY: &'static [u8] = b"SUSAP KJFKK6GRW04L   0120790440 N40372318W073470505         -0028300012046057200IIHIQ1                                     305541709";
+    const RUNWAY: &[u8] = b"SUSAP KJFKK6GRW04L   0120790440 N40372318W073470505         -0028300012046057200IIHIQ1                                     305541709";
 
     #[test]
     fn runway_record() {
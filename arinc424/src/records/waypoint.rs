@@ -78,7 +78,7 @@ impl<'a> Waypoint<'a> {
 mod tests {
     use super::*;
 
-    const PC_WAYPOINT: &'static [u8] = b"SEURPCEDDHED W1    ED0    V     N53341894E009404512                                 WGE           WHISKEY1                 122922407";
+    const PC_WAYPOINT: &[u8] = b"SEURPCEDDHED W1    ED0    V     N53341894E009404512                                 WGE           WHISKEY1                 122922407";
 
     #[test]
     fn terminal_waypoint_record() {
@@ -108,7 +108,7 @@ mod tests {
         assert_eq!(wp.cycle.cycle(), Ok(7));
     }
 
-    const EA_WAYPOINT: &'static [u8] = b"SUSAEAENRT   AAARG K 0    W   B N32413827W078030466                       W0093     NAR           AAARG                    270862407";
+    const EA_WAYPOINT: &[u8] = b"SUSAEAENRT   AAARG K 0    W   B N32413827W078030466                       W0093     NAR           AAARG                    270862407";
 
     #[test]
     fn enroute_waypoint_record() {
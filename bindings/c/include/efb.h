@@ -75,7 +75,10 @@ typedef enum {
 typedef enum {
   MetersPerSecond,
   Knots,
+  /// Mach number, converted at the speed of sound at ISA mean sea level.
+  /// Use [`Speed::to_tas`] to get the true airspeed at a temperature.
   Mach,
+  KilometersPerHour,
 } EfbSpeedUnit;
 
 /// Volume with _m³_ as SI unit.
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -70,6 +70,7 @@ pub extern "C" fn efb_aircraft_builder_free(builder: Box<AircraftBuilder>) {
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn efb_aircraft_builder_registration(
     builder: &mut AircraftBuilder,
     registration: *const c_char,
@@ -82,6 +83,7 @@ pub extern "C" fn efb_aircraft_builder_registration(
 
 /// Pushes a new station to the stations and returns it.
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn efb_aircraft_builder_stations_push<'a>(
     builder: &'a mut AircraftBuilder,
     arm: Length,
@@ -245,6 +247,7 @@ pub extern "C" fn efb_aircraft_builder_cg_envelope_next<'a>(
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn efb_aircraft_builder_notes(builder: &mut AircraftBuilder, notes: *const c_char) {
     let notes = unsafe { CStr::from_ptr(notes).to_str() };
     let _ = builder.notes.insert(notes.ok().map(String::from).unwrap());
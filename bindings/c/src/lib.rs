@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -37,7 +37,7 @@ where
 {
     let s = unsafe {
         match value.as_ref() {
-            Some(ref v) => v.to_string(),
+            Some(v) => v.to_string(),
             None => String::from(""),
         }
     };
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -53,6 +53,7 @@ impl From<PyAircraft> for Aircraft {
 impl PyAircraft {
     #[new]
     #[pyo3(signature = (registration, stations, empty_mass, empty_balance, fuel_type, tanks, cg_envelope, notes=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         registration: String,
         stations: Vec<PyStation>,
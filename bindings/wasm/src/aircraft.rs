@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -111,7 +111,7 @@ impl JsStation {
     #[wasm_bindgen(constructor)]
     pub fn new(arm: &JsLength, description: Option<String>) -> Self {
         Self {
-            inner: Station::new(arm.clone().into(), description),
+            inner: Station::new((*arm).into(), description),
         }
     }
 
@@ -151,7 +151,7 @@ impl JsFuelTank {
     #[wasm_bindgen(constructor)]
     pub fn new(capacity: &JsVolume, arm: &JsLength) -> Self {
         Self {
-            inner: FuelTank::new(capacity.clone().into(), arm.clone().into()),
+            inner: FuelTank::new((*capacity).into(), (*arm).into()),
         }
     }
 
@@ -191,7 +191,7 @@ impl JsCGLimit {
     #[wasm_bindgen(constructor)]
     pub fn new(mass: &JsMass, distance: &JsLength) -> Self {
         Self {
-            inner: CGLimit::new(mass.clone().into(), distance.clone().into()),
+            inner: CGLimit::new((*mass).into(), (*distance).into()),
         }
     }
 
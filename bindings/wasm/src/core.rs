@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -28,14 +28,14 @@ impl JsFuel {
     #[wasm_bindgen(constructor)]
     pub fn new(mass: &JsMass, fuel_type: &JsFuelType) -> Self {
         Self {
-            inner: Fuel::new(mass.clone().into(), fuel_type.clone().into()),
+            inner: Fuel::new((*mass).into(), (*fuel_type).into()),
         }
     }
 
     #[wasm_bindgen(js_name = fromVolume)]
     pub fn from_volume(volume: &JsVolume, fuel_type: &JsFuelType) -> Result<Self, JsError> {
         Ok(Self {
-            inner: Fuel::from_volume(volume.clone().into(), fuel_type.clone().into()),
+            inner: Fuel::from_volume((*volume).into(), (*fuel_type).into()),
         })
     }
 
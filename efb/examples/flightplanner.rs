@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -20,7 +20,7 @@ use efb::nd::{RunwayConditionCode, RunwaySurface};
 use efb::prelude::*;
 use efb::*;
 
-const ARINC_424_RECORDS: &'static [u8] = br#"
+const ARINC_424_RECORDS: &[u8] = br#"
 SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
 SEURP EDDHEDGRW05    0106630500 N53371100E009580180                          151                                           124362502
 SEURP EDDHEDGRW23    0106632300 N53380900E009595876                          151                                           124362502
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -369,7 +369,7 @@ mod tests {
         };
 
         // the aircraft has two stations but we provide no mass for any
-        ac.stations_from_mass(&vec![], &vec![]).unwrap();
+        ac.stations_from_mass(&[], &[]).unwrap();
     }
 
     #[test]
@@ -387,7 +387,7 @@ mod tests {
             notes: None,
         };
 
-        let stations = ac.stations_from_mass(&vec![], &vec![]).unwrap();
+        let stations = ac.stations_from_mass(&[], &[]).unwrap();
 
         // we're expecting only our fuel station
         assert_eq!(stations.len(), 1);
@@ -13,8 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::{Display, Formatter, Result};
-use std::ops::{Add, Div, Mul, Sub};
+use alloc::format;
+use core::fmt::{Display, Formatter, Result};
+use core::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -203,6 +204,7 @@ impl Div<Duration> for Fuel {
 
 #[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FuelFlow {
     PerHour(Fuel),
 }
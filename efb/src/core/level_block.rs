@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::measurements::{Length, Pressure};
+use crate::VerticalDistance;
+
+/// A block of levels from a `floor` to a `ceiling`.
+///
+/// ATC may clear a flight to operate anywhere within a block of levels
+/// instead of a single level. Both limits are part of the block.
+///
+/// # Examples
+///
+/// ```
+/// # use efb::{LevelBlock, VerticalDistance};
+/// let block: LevelBlock = "FL100/FL120".parse().expect("block should parse");
+///
+/// assert_eq!(block.floor, VerticalDistance::Fl(100));
+/// assert_eq!(block.ceiling, VerticalDistance::Fl(120));
+/// assert!(block.contains(&VerticalDistance::Fl(110)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct LevelBlock {
+    /// The lowest level of the block.
+    pub floor: VerticalDistance,
+    /// The highest level of the block.
+    pub ceiling: VerticalDistance,
+}
+
+impl LevelBlock {
+    /// Returns `true` if the `level` is within the block.
+    ///
+    /// The levels are compared above mean sea level at standard pressure, so
+    /// a level of any datum can be checked against the block. Heights above
+    /// ground are compared as if the ground was at sea level.
+    pub fn contains(&self, level: &VerticalDistance) -> bool {
+        msl(&self.floor) <= msl(level) && msl(level) <= msl(&self.ceiling)
+    }
+}
+
+/// Resolves a vertical distance above mean sea level at standard pressure and
+/// with ground at sea level. An unlimited vertical distance is infinite.
+fn msl(vd: &VerticalDistance) -> f32 {
+    vd.to_msl(Pressure::STD, Length::ft(0.0))
+        .map_or(f32::INFINITY, |alt| alt.to_si())
+}
+
+impl FromStr for LevelBlock {
+    type Err = Error;
+
+    /// Parses a string `s` to return a LevelBlock.
+    ///
+    /// The string should be the floor and ceiling separated by a `/` or `-`,
+    /// e.g. `FL100/FL120`. Each level is either a flight level written as `FL`
+    /// followed by its figures, or a level according to ICAO Doc. 4444 like
+    /// `F100` or `A045`. The floor must be below the ceiling.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (floor, ceiling) = s.split_once(['/', '-']).ok_or(Error::UnexpectedString)?;
+
+        let (floor, ceiling) = (level(floor)?, level(ceiling)?);
+
+        if msl(&floor) < msl(&ceiling) {
+            Ok(Self { floor, ceiling })
+        } else {
+            Err(Error::UnexpectedString)
+        }
+    }
+}
+
+/// Parses a level of a block.
+fn level(s: &str) -> Result<VerticalDistance, Error> {
+    match s.strip_prefix("FL") {
+        Some(fl) if (2..=3).contains(&fl.len()) && fl.bytes().all(|b| b.is_ascii_digit()) => fl
+            .parse()
+            .map(VerticalDistance::Fl)
+            .map_err(|_| Error::UnexpectedString),
+        Some(_) => Err(Error::UnexpectedString),
+        None => s.parse(),
+    }
+}
+
+impl fmt::Display for LevelBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.floor, self.ceiling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let block = LevelBlock {
+            floor: VerticalDistance::Fl(100),
+            ceiling: VerticalDistance::Fl(120),
+        };
+
+        assert_eq!("FL100/FL120".parse::<LevelBlock>(), Ok(block));
+        assert_eq!("FL100-FL120".parse::<LevelBlock>(), Ok(block));
+        assert_eq!("F100/F120".parse::<LevelBlock>(), Ok(block));
+        assert_eq!(
+            "A045/FL100".parse::<LevelBlock>(),
+            Ok(LevelBlock {
+                floor: VerticalDistance::Altitude(4500),
+                ceiling: VerticalDistance::Fl(100),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_block() {
+        for s in [
+            "FL100",
+            "FL100/",
+            "/FL120",
+            "FL1X0/FL120",
+            "FL120/FL100",
+            "FL100/FL100",
+            "F999/F700",
+        ] {
+            assert_eq!(s.parse::<LevelBlock>(), Err(Error::UnexpectedString), "{s}");
+        }
+    }
+
+    #[test]
+    fn from_str_above_fl655() {
+        assert_eq!(
+            "F700/F999".parse::<LevelBlock>(),
+            Ok(LevelBlock {
+                floor: VerticalDistance::Fl(700),
+                ceiling: VerticalDistance::Fl(999),
+            })
+        );
+    }
+
+    #[test]
+    fn contains_level_of_other_datum() {
+        let block: LevelBlock = "FL100/FL120".parse().unwrap();
+
+        assert!(block.contains(&VerticalDistance::PressureAltitude(11000)));
+        assert!(!block.contains(&VerticalDistance::Agl(500)));
+        assert!(!block.contains(&VerticalDistance::Unlimited));
+    }
+
+    #[test]
+    fn contains_its_limits() {
+        let block: LevelBlock = "FL100/FL120".parse().unwrap();
+
+        assert!(block.contains(&VerticalDistance::Fl(100)));
+        assert!(block.contains(&VerticalDistance::Fl(120)));
+        assert!(!block.contains(&VerticalDistance::Fl(130)));
+        assert_eq!(block.to_string(), "FL100/FL120");
+    }
+}
@@ -13,23 +13,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::{Display, Formatter, Result};
-use std::hash::{Hash, Hasher};
+use core::fmt::{Display, Formatter, Result};
+use core::hash::{Hash, Hasher};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use time::OffsetDateTime;
+#[cfg(feature = "std")]
+use log::warn;
+#[cfg(feature = "wmm")]
+use time::{Date, OffsetDateTime};
+#[cfg(feature = "wmm")]
 use world_magnetic_model::uom::si::{
     angle::degree, angle::radian, f32::Angle, f32::Length, length::meter,
 };
+#[cfg(feature = "wmm")]
 use world_magnetic_model::GeomagneticField;
 
+#[cfg(feature = "std")]
 use geo::Point;
 
 /// The magnetic variation (declination) of a point.
 ///
-/// Any [`Point<f64>`] can be converted into a declination.
+/// With the `std` feature, any `Point<f64>` can be converted into a
+/// declination, which is computed by the World Magnetic Model with the `wmm`
+/// feature.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
@@ -60,30 +68,72 @@ impl Hash for MagneticVariation {
     }
 }
 
-impl From<Point<f64>> for MagneticVariation {
-    fn from(value: Point<f64>) -> Self {
+#[cfg(feature = "wmm")]
+impl MagneticVariation {
+    /// Returns the declination at the point and date computed by the World
+    /// Magnetic Model (WMM).
+    ///
+    /// Use this if the navigation data doesn't provide the magnetic variation
+    /// of a point. Returns `None` if the date is outside of the model's
+    /// validity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::MagneticVariation;
+    /// # use geo::Point;
+    /// # use time::{Date, Month};
+    /// let hamburg = Point::new(9.99, 53.63); // (lon, lat)
+    /// let date = Date::from_calendar_date(2025, Month::June, 1).expect("date should be valid");
+    ///
+    /// if let Some(mag_var) = MagneticVariation::at(hamburg, date) {
+    ///     println!("Variation at Hamburg: {mag_var}");
+    /// }
+    /// ```
+    pub fn at(point: Point<f64>, date: Date) -> Option<Self> {
         // geo uses (x, y) = (longitude, latitude)
-        let latitude = value.y();
-        let longitude = value.x();
+        let latitude = point.y();
+        let longitude = point.x();
 
-        let mag_var = match GeomagneticField::new(
+        let field = GeomagneticField::new(
             Length::new::<meter>(0.0),
             Angle::new::<radian>(latitude.to_radians() as f32),
             Angle::new::<radian>(longitude.to_radians() as f32),
-            OffsetDateTime::now_utc().date(),
-        ) {
-            Ok(field) => field.declination().get::<degree>(),
-            Err(_) => todo!("implement TryFrom to handle unavailable variation!"),
-        };
+            date,
+        )
+        .ok()?;
+
+        let mag_var = field.declination().get::<degree>();
 
         if mag_var.is_sign_negative() {
-            Self::West(mag_var.abs())
+            Some(Self::West(mag_var.abs()))
         } else {
-            Self::East(mag_var)
+            Some(Self::East(mag_var))
         }
     }
 }
 
+/// Computes the current magnetic variation at a point.
+///
+/// Falls back to [`MagneticVariation::OrientedToTrueNorth`] with a warning if
+/// the `wmm` feature is disabled or today is outside of the World Magnetic
+/// Model's validity. Magnetic courses then equal true courses.
+#[cfg(feature = "std")]
+impl From<Point<f64>> for MagneticVariation {
+    fn from(value: Point<f64>) -> Self {
+        #[cfg(feature = "wmm")]
+        if let Some(mag_var) = Self::at(value, OffsetDateTime::now_utc().date()) {
+            return mag_var;
+        }
+
+        warn!(
+            "no magnetic variation at {:?}, assuming orientation to true north",
+            value.x_y()
+        );
+        Self::OrientedToTrueNorth
+    }
+}
+
 impl Display for MagneticVariation {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -93,3 +143,33 @@ impl Display for MagneticVariation {
         }
     }
 }
+
+#[cfg(all(test, feature = "wmm"))]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).expect("date should be valid")
+    }
+
+    #[test]
+    fn declination_from_wmm() {
+        // WMM declination at Gdańsk on 2023-01-15 is 6.44° E
+        let gdansk = Point::new(18.67, 54.20);
+
+        match MagneticVariation::at(gdansk, date(2023, Month::January, 15)) {
+            Some(MagneticVariation::East(value)) => assert!((value - 6.44).abs() < 0.05),
+            mag_var => panic!("expected easterly variation, got {mag_var:?}"),
+        }
+    }
+
+    #[test]
+    fn no_declination_outside_of_model_validity() {
+        assert_eq!(
+            MagneticVariation::at(Point::new(18.67, 54.20), date(1990, Month::January, 1)),
+            None
+        );
+    }
+}
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -20,11 +20,13 @@
 //! distances, and magnetic variation.
 
 mod fuel;
+mod level_block;
 mod mag_var;
 mod vertical_distance;
 mod wind;
 
 pub use fuel::*;
+pub use level_block::LevelBlock;
 pub use mag_var::*;
 pub use vertical_distance::VerticalDistance;
 pub use wind::*;
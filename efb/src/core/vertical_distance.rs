@@ -13,20 +13,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::cmp::{Ord, Ordering, PartialOrd};
 use core::f32;
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::fmt;
-use std::ops::Div;
-use std::str::FromStr;
+use core::fmt;
+use core::ops::Div;
+use core::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
-use crate::measurements::{Altitude, Length, LengthUnit, Pressure};
+#[cfg(not(feature = "std"))]
+use crate::float::F32Ext;
+use crate::measurements::{
+    Altitude, AltitudeUnit, Angle, AngleUnit, Length, LengthUnit, Pressure, PressureUnit,
+};
 
 mod constants {
     pub const METER_IN_FEET: f32 = 3.28084;
+
+    // https://www.weather.gov/media/epz/wxcalc/pressureAltitude.pdf
+    pub const PRESSURE_ALTITUDE_FT: f32 = 145366.45;
+    pub const PRESSURE_EXPONENT: f32 = 0.190284;
 }
 
 /// A vertical distance.
@@ -98,6 +106,29 @@ impl VerticalDistance {
         }))
     }
 
+    /// Resolves this vertical distance to an altitude above mean sea level
+    /// like [`to_msl`](Self::to_msl).
+    ///
+    /// # Errors
+    ///
+    /// Will return [`UnlimitedHasNoAltitude`] if the vertical distance is
+    /// [unlimited](Self::Unlimited).
+    ///
+    /// [`UnlimitedHasNoAltitude`]: Error::UnlimitedHasNoAltitude
+    pub fn resolve_or_err(&self, qnh: Pressure, elevation: Length) -> Result<Altitude, Error> {
+        self.to_msl(qnh, elevation)
+            .ok_or(Error::UnlimitedHasNoAltitude)
+    }
+
+    /// Returns `true` if the vertical distance resolves to an altitude, i.e.
+    /// it is not [unlimited](Self::Unlimited).
+    ///
+    /// An unlimited vertical distance is infinite if converted to `f32` and
+    /// has no altitude from [`to_msl`](Self::to_msl).
+    pub fn is_finite(&self) -> bool {
+        !matches!(self, Self::Unlimited)
+    }
+
     /// Returns the pressure altitude based on the elevation and the QNH.
     ///
     /// # Errors
@@ -108,9 +139,10 @@ impl VerticalDistance {
     /// [`ImplausibleValue`]: Error::ImplausibleValue
     // TODO: Change elevation to be a length measurement.
     pub fn pa(elevation: i16, qnh: Pressure) -> Result<Self, Error> {
-        // https://www.weather.gov/media/epz/wxcalc/pressureAltitude.pdf
         let (pa, overflowed) = elevation.overflowing_add(
-            (145366.45 * (1.0 - (qnh / Pressure::STD).powf(0.190284))).round() as i16,
+            (constants::PRESSURE_ALTITUDE_FT
+                * (1.0 - (qnh / Pressure::STD).powf(constants::PRESSURE_EXPONENT)))
+            .round() as i16,
         );
 
         if overflowed {
@@ -119,6 +151,143 @@ impl VerticalDistance {
             Ok(Self::PressureAltitude(pa))
         }
     }
+
+    /// Returns the air pressure at this vertical distance.
+    ///
+    /// The vertical distance is resolved to an altitude with
+    /// [`to_msl`](Self::to_msl) first. The pressure decreases from the `qnh`
+    /// at mean sea level by the inverse of the barometric formula used for the
+    /// [pressure altitude](Self::pa).
+    ///
+    /// Returns `None` for an [unlimited](Self::Unlimited) vertical distance or
+    /// if the altitude is beyond the formula's range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::VerticalDistance;
+    /// # use efb::measurements::{Length, Pressure};
+    /// let pressure = VerticalDistance::Fl(100).to_pressure(Pressure::STD, Length::ft(0.0));
+    /// assert!(pressure.is_some_and(|p| p < Pressure::h_pa(700.0)));
+    /// ```
+    pub fn to_pressure(&self, qnh: Pressure, elevation: Length) -> Option<Pressure> {
+        let ft = *self
+            .to_msl(qnh, elevation)?
+            .convert_to(AltitudeUnit::Feet)
+            .value();
+        let ratio = 1.0 - ft / constants::PRESSURE_ALTITUDE_FT;
+
+        (ratio > 0.0).then(|| {
+            let qnh = *qnh.convert_to(PressureUnit::Hektopascal).value();
+            Pressure::h_pa(qnh * ratio.powf(1.0 / constants::PRESSURE_EXPONENT))
+        })
+    }
+
+    /// Adds `ft` feet to this vertical distance.
+    ///
+    /// The vertical distance keeps its reference. A flight level is rounded to
+    /// the nearest hundred feet and [ground](Self::Gnd) becomes a height
+    /// [above ground level](Self::Agl). An [unlimited](Self::Unlimited)
+    /// vertical distance stays unlimited.
+    ///
+    /// Returns `None` if the result doesn't fit into the variant's value, e.g.
+    /// a negative height or an altitude above 65535 ft.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::VerticalDistance;
+    /// assert_eq!(
+    ///     VerticalDistance::Agl(1000).checked_add_feet(500),
+    ///     Some(VerticalDistance::Agl(1500))
+    /// );
+    /// assert_eq!(VerticalDistance::Msl(1000).checked_add_feet(-1500), None);
+    /// ```
+    pub fn checked_add_feet(&self, ft: i32) -> Option<Self> {
+        let add = |value: i32| value.checked_add(ft);
+
+        match self {
+            Self::Agl(n) => add(*n as i32)
+                .and_then(|v| u16::try_from(v).ok())
+                .map(Self::Agl),
+            Self::Altitude(n) => add(*n as i32)
+                .and_then(|v| u16::try_from(v).ok())
+                .map(Self::Altitude),
+            Self::Msl(n) => add(*n as i32)
+                .and_then(|v| u16::try_from(v).ok())
+                .map(Self::Msl),
+            Self::PressureAltitude(n) => add(*n as i32)
+                .and_then(|v| i16::try_from(v).ok())
+                .map(Self::PressureAltitude),
+            Self::Fl(n) => add(*n as i32 * 100)
+                .map(|v| (v as f32 / 100.0).round() as i32)
+                .and_then(|v| u16::try_from(v).ok())
+                .map(Self::Fl),
+            Self::Gnd if ft == 0 => Some(Self::Gnd),
+            Self::Gnd => u16::try_from(ft).ok().map(Self::Agl),
+            Self::Unlimited => Some(Self::Unlimited),
+        }
+    }
+
+    /// Rounds this vertical distance to the nearest cruising level for the
+    /// magnetic `track` according to the semicircular rule (ICAO Annex 2,
+    /// Appendix 3).
+    ///
+    /// Eastbound tracks (000° to 179°) are flown at odd thousands and westbound
+    /// tracks (180° to 359°) at even thousands of feet. VFR flights add 500 ft
+    /// to those levels. A vertical distance halfway between two cruising levels
+    /// is rounded up.
+    ///
+    /// Returns `None` for vertical distances that are no cruising level
+    /// ([`Gnd`], [`Agl`], [`PressureAltitude`] and [`Unlimited`]) or if the
+    /// rounded level overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::VerticalDistance;
+    /// # use efb::measurements::Angle;
+    /// // eastbound IFR flights cruise at odd flight levels
+    /// let level = VerticalDistance::Fl(85).round_to_cruise_level(&Angle::m(90.0), true);
+    /// assert_eq!(level, Some(VerticalDistance::Fl(90)));
+    ///
+    /// // westbound VFR flights cruise at even thousands plus 500 ft
+    /// let level = VerticalDistance::Altitude(4000).round_to_cruise_level(&Angle::m(270.0), false);
+    /// assert_eq!(level, Some(VerticalDistance::Altitude(4500)));
+    /// ```
+    ///
+    /// [`Gnd`]: Self::Gnd
+    /// [`Agl`]: Self::Agl
+    /// [`PressureAltitude`]: Self::PressureAltitude
+    /// [`Unlimited`]: Self::Unlimited
+    pub fn round_to_cruise_level(&self, track: &Angle, ifr: bool) -> Option<Self> {
+        let eastbound = *track.convert_to(AngleUnit::MagneticNorth).value() < 180.0;
+
+        // the lowest cruising level in hundreds of feet
+        let lowest: f32 = match (eastbound, ifr) {
+            (true, true) => 10.0,
+            (false, true) => 20.0,
+            (true, false) => 15.0,
+            (false, false) => 25.0,
+        };
+
+        // Rounds the value, which is in `scale` feet, to the next level that
+        // is a multiple of 2000 ft above the lowest cruising level.
+        let round = |value: u16, scale: f32| -> Option<u16> {
+            let hundreds = value as f32 * scale / 100.0;
+            let n = ((hundreds - lowest) / 20.0).round().max(0.0);
+            let level = ((lowest + n * 20.0) * 100.0 / scale).round();
+
+            (level <= u16::MAX as f32).then_some(level as u16)
+        };
+
+        match self {
+            Self::Fl(value) => round(*value, 100.0).map(Self::Fl),
+            Self::Altitude(value) => round(*value, 1.0).map(Self::Altitude),
+            Self::Msl(value) => round(*value, 1.0).map(Self::Msl),
+            Self::Gnd | Self::Agl(_) | Self::PressureAltitude(_) | Self::Unlimited => None,
+        }
+    }
 }
 
 impl FromStr for VerticalDistance {
@@ -306,6 +475,68 @@ mod tests {
         assert!(VerticalDistance::Msl(1000) < VerticalDistance::Fl(100));
     }
 
+    #[test]
+    fn eastbound_ifr_cruises_at_odd_levels() {
+        let track = Angle::m(45.0);
+
+        assert_eq!(
+            VerticalDistance::Fl(85).round_to_cruise_level(&track, true),
+            Some(VerticalDistance::Fl(90))
+        );
+        assert_eq!(
+            VerticalDistance::Fl(100).round_to_cruise_level(&track, true),
+            Some(VerticalDistance::Fl(110))
+        );
+        assert_eq!(
+            VerticalDistance::Fl(64).round_to_cruise_level(&track, true),
+            Some(VerticalDistance::Fl(70))
+        );
+        assert_eq!(
+            VerticalDistance::Altitude(500).round_to_cruise_level(&track, true),
+            Some(VerticalDistance::Altitude(1000))
+        );
+    }
+
+    #[test]
+    fn westbound_vfr_cruises_at_even_levels_plus_500ft() {
+        let track = Angle::m(270.0);
+
+        assert_eq!(
+            VerticalDistance::Altitude(4000).round_to_cruise_level(&track, false),
+            Some(VerticalDistance::Altitude(4500))
+        );
+        assert_eq!(
+            VerticalDistance::Msl(6000).round_to_cruise_level(&track, false),
+            Some(VerticalDistance::Msl(6500))
+        );
+        assert_eq!(
+            VerticalDistance::Fl(80).round_to_cruise_level(&track, false),
+            Some(VerticalDistance::Fl(85))
+        );
+        assert_eq!(
+            VerticalDistance::Fl(50).round_to_cruise_level(&track, false),
+            Some(VerticalDistance::Fl(45))
+        );
+    }
+
+    #[test]
+    fn no_cruise_level_without_common_datum() {
+        let track = Angle::m(90.0);
+
+        assert_eq!(
+            VerticalDistance::Gnd.round_to_cruise_level(&track, true),
+            None
+        );
+        assert_eq!(
+            VerticalDistance::Agl(1000).round_to_cruise_level(&track, true),
+            None
+        );
+        assert_eq!(
+            VerticalDistance::Unlimited.round_to_cruise_level(&track, true),
+            None
+        );
+    }
+
     #[test]
     fn to_msl_at_standard_pressure() {
         let std_qnh = Pressure::STD;
@@ -356,4 +587,79 @@ mod tests {
         let alt = VerticalDistance::Gnd.to_msl(std_qnh, ground).unwrap();
         assert!((alt.to_si() - Length::ft(500.0).to_si()).abs() < 1.0);
     }
+
+    #[test]
+    fn checked_add_feet_returns_none_on_overflow() {
+        assert_eq!(
+            VerticalDistance::Agl(u16::MAX - 10).checked_add_feet(100),
+            None
+        );
+        assert_eq!(VerticalDistance::Msl(100).checked_add_feet(-200), None);
+        assert_eq!(
+            VerticalDistance::PressureAltitude(i16::MIN).checked_add_feet(-1),
+            None
+        );
+
+        assert_eq!(
+            VerticalDistance::Agl(u16::MAX - 10).checked_add_feet(10),
+            Some(VerticalDistance::Agl(u16::MAX))
+        );
+        assert_eq!(
+            VerticalDistance::PressureAltitude(-100).checked_add_feet(-100),
+            Some(VerticalDistance::PressureAltitude(-200))
+        );
+        assert_eq!(
+            VerticalDistance::Fl(65).checked_add_feet(500),
+            Some(VerticalDistance::Fl(70))
+        );
+        assert_eq!(
+            VerticalDistance::Gnd.checked_add_feet(1500),
+            Some(VerticalDistance::Agl(1500))
+        );
+        assert_eq!(
+            VerticalDistance::Unlimited.checked_add_feet(1500),
+            Some(VerticalDistance::Unlimited)
+        );
+    }
+
+    #[test]
+    fn pressure_decreases_with_level() {
+        let pressure = |vd: VerticalDistance| {
+            vd.to_pressure(Pressure::STD, Length::ft(0.0))
+                .expect("level should have a pressure")
+        };
+
+        let fl0 = pressure(VerticalDistance::Fl(0));
+        assert!((fl0.to_si() - Pressure::STD.to_si()).abs() < 1.0, "{fl0}");
+
+        // FL100 is at about 697 hPa in the standard atmosphere
+        let fl100 = pressure(VerticalDistance::Fl(100));
+        assert!((fl100.to_si() - Pressure::h_pa(696.8).to_si()).abs() < 100.0);
+
+        assert!(pressure(VerticalDistance::Fl(50)) < fl0);
+        assert!(fl100 < pressure(VerticalDistance::Fl(50)));
+
+        assert_eq!(
+            VerticalDistance::Unlimited.to_pressure(Pressure::STD, Length::ft(0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn unlimited_has_no_altitude() {
+        let std_qnh = Pressure::STD;
+        let ground = Length::ft(0.0);
+
+        assert!(VerticalDistance::Fl(65).is_finite());
+        assert!(VerticalDistance::Gnd.is_finite());
+        assert!(VerticalDistance::Msl(2500)
+            .resolve_or_err(std_qnh, ground)
+            .is_ok_and(|alt| (alt.to_si() - Length::ft(2500.0).to_si()).abs() < 1.0));
+
+        assert!(!VerticalDistance::Unlimited.is_finite());
+        assert_eq!(
+            VerticalDistance::Unlimited.resolve_or_err(std_qnh, ground),
+            Err(Error::UnlimitedHasNoAltitude)
+        );
+    }
 }
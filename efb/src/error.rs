@@ -21,16 +21,21 @@
 //! - Route decoding errors (invalid elements, unknown identifiers)
 //! - Parsing errors (malformed strings, invalid values)
 //! - Navigation data errors (unknown identifiers, invalid runway codes)
+//! - Flight planning errors (level above service ceiling)
 //! - Mass & balance planning errors (mismatched dimensions, exceeded capacities)
 //! - Aircraft building errors (missing required fields)
 
-use std::error;
-use std::fmt;
-use std::result;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error;
+use core::fmt;
+use core::result;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::VerticalDistance;
+
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -51,9 +56,21 @@ pub enum Error {
     UnexpectedRunwayInRoute(String),
     /// The route includes a runway that is not found on the associated airport.
     UnknownRunwayInRoute { arpt: String, rwy: String },
+    /// The route includes a runway without suffix, but the associated airport
+    /// has parallel runways with that number.
+    AmbiguousRunway {
+        arpt: String,
+        rwy: String,
+        candidates: Vec<String>,
+    },
     /// A terminal waypoint needs to match to exactly one of the terminal areas
     /// in scope.
     AmbiguousTerminalArea { wp: String, a: String, b: String },
+    /// The route is missing an origin or destination airport.
+    IncompleteRoute,
+    /// The route includes the same fix twice in a row, which would result in
+    /// a leg without distance.
+    RepeatedFix(String),
 
     // Errors that are related to parsing of input data:
     //
@@ -63,6 +80,8 @@ pub enum Error {
     UnexpectedString,
     /// The value that should be returned is implausible.
     ImplausibleValue,
+    /// An unlimited vertical distance can't be resolved to an altitude.
+    UnlimitedHasNoAltitude,
     /// The location indicator should be a two-letter code according to ICAO
     /// Document No. 7910.
     UnknownLocationIndicator(String),
@@ -73,6 +92,16 @@ pub enum Error {
     UnknownIdent(String),
     /// The RWYCC should be between 0 and 6.
     InvalidRWYCC,
+    /// The polygon of the airspace is not simple, e.g. because it intersects
+    /// itself.
+    InvalidAirspace { name: String, reason: String },
+    /// Loading of the navigation data was cancelled before all records were
+    /// read.
+    Cancelled,
+    /// The compressed navigation data could not be decompressed. The wrapped
+    /// string is the stringified I/O error message.
+    #[cfg(feature = "flate2")]
+    Decompression(String),
 
     // Errors that originate from the SQLite-backed navigation data store:
     //
@@ -83,6 +112,21 @@ pub enum Error {
     #[cfg(feature = "sqlite")]
     Database(String),
 
+    // Errors that originate from (de)serialization:
+    //
+    /// The (de)serialization failed. The wrapped string is the stringified
+    /// `serde_json` error message.
+    #[cfg(feature = "serde")]
+    Serialization(String),
+
+    // Errors that originate from the flight planning:
+    //
+    /// The planned level is above the aircraft's service ceiling.
+    AboveServiceCeiling {
+        level: VerticalDistance,
+        ceiling: VerticalDistance,
+    },
+
     // Errors that originate from the mass & balance planning:
     //
     /// The number of masses doesn't match the number of stations to which the
@@ -120,9 +164,19 @@ impl fmt::Display for Error {
             Self::UnknownRunwayInRoute { arpt, rwy } => {
                 write!(f, "unknown runway {rwy} found for {arpt}")
             }
+            Self::AmbiguousRunway {
+                arpt,
+                rwy,
+                candidates,
+            } => {
+                let candidates = candidates.join(", ");
+                write!(f, "runway {rwy} at {arpt} should be one of {candidates}")
+            }
             Self::AmbiguousTerminalArea { wp, a, b } => {
                 write!(f, "waypoint {wp} found in terminal area {a} and {b}")
             }
+            Self::IncompleteRoute => write!(f, "route requires an origin and a destination"),
+            Self::RepeatedFix(ident) => write!(f, "fix {ident} should not repeat"),
 
             Self::InvalidA424 { record, error } => {
                 let s = String::from_utf8_lossy(record);
@@ -130,6 +184,9 @@ impl fmt::Display for Error {
             }
             Self::UnexpectedString => write!(f, "unexpected string"),
             Self::ImplausibleValue => write!(f, "value seams implausuble"),
+            Self::UnlimitedHasNoAltitude => {
+                write!(f, "unlimited vertical distance has no altitude")
+            }
             Self::UnknownLocationIndicator(code) => write!(
                 f,
                 "location {code} should be according to ICAO document no. 7910"
@@ -137,10 +194,26 @@ impl fmt::Display for Error {
 
             Self::UnknownIdent(ident) => write!(f, "unknown ident {ident}"),
             Self::InvalidRWYCC => write!(f, "RWYCC should be between 0 and 6"),
+            Self::InvalidAirspace { name, reason } => {
+                write!(f, "airspace {name} should be simple but {reason}")
+            }
+            Self::Cancelled => write!(f, "loading was cancelled"),
+            #[cfg(feature = "flate2")]
+            Self::Decompression(msg) => write!(f, "decompression failed: {msg}"),
 
             #[cfg(feature = "sqlite")]
             Self::Database(msg) => write!(f, "database error: {msg}"),
 
+            #[cfg(feature = "serde")]
+            Self::Serialization(msg) => write!(f, "serialization error: {msg}"),
+
+            Self::AboveServiceCeiling { level, ceiling } => {
+                write!(
+                    f,
+                    "level {level} should not exceed service ceiling {ceiling}"
+                )
+            }
+
             Self::UnexpectedMassesForStations => {
                 write!(f, "mass should match to aircraft's stations")
             }
@@ -177,3 +250,10 @@ impl From<rusqlite_migration::Error> for Error {
         Self::Database(err.to_string())
     }
 }
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serialization(err.to_string())
+    }
+}
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -19,3 +19,15 @@
 pub fn dms_to_decimal(degree: u8, minutes: u8, seconds: u8) -> f64 {
     degree as f64 + minutes as f64 / 60.0 + seconds as f64 / 3600.0
 }
+
+/// Converts an angle from decimal to degree, minutes and seconds.
+///
+/// The sign of the angle is dropped and the seconds are rounded.
+pub fn decimal_to_dms(decimal: f64) -> (u8, u8, u8) {
+    let seconds = (decimal.abs() * 3600.0).round() as u32;
+    (
+        (seconds / 3600) as u8,
+        (seconds / 60 % 60) as u8,
+        (seconds % 60) as u8,
+    )
+}
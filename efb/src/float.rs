@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Floating-point functions that `core` lacks.
+//!
+//! With `std`, the functions are inherent to `f32`. Without it, this trait
+//! provides them backed by [`libm`], so the same calls work in both builds.
+
+/// Math functions of `f32` that are only available with `std`.
+pub(crate) trait F32Ext {
+    fn cos(self) -> f32;
+    fn powf(self, n: f32) -> f32;
+    fn round(self) -> f32;
+    fn sin(self) -> f32;
+    fn sqrt(self) -> f32;
+}
+
+impl F32Ext for f32 {
+    fn cos(self) -> f32 {
+        libm::cosf(self)
+    }
+
+    fn powf(self, n: f32) -> f32 {
+        libm::powf(self, n)
+    }
+
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+}
@@ -21,18 +21,21 @@
 //! modify e.g. the navigation data and takes care that the route is reevaluated
 //! based on the new data.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use log::{debug, error, info, trace, warn};
 
 use crate::error::{Error, Result};
 use crate::fp::{FlightPlanning, FlightPlanningBuilder};
-use crate::nd::{Fix, NavigationData};
-use crate::route::Route;
+use crate::nd::{Fix, NavigationData, Shared};
+use crate::route::{Route, TokenKind};
 
 mod printer;
 pub use printer::*;
 
+/// Maximum number of changes that can be undone.
+const HISTORY_LENGTH: usize = 50;
+
 #[derive(Clone, PartialEq, Debug, Default)]
 struct Context {
     route: String,
@@ -48,6 +51,8 @@ pub struct FMS {
     context: Context,
     route: Route,
     flight_planning: Option<FlightPlanning>,
+    undo_history: VecDeque<Context>,
+    redo_history: Vec<Context>,
 }
 
 impl FMS {
@@ -90,6 +95,11 @@ impl FMS {
         &self.route
     }
 
+    /// Returns the route as it was entered, e.g. to refill a text field.
+    pub fn route_string(&self) -> &str {
+        &self.context.route
+    }
+
     /// Modifies the [`Route`].
     pub fn modify_route<F>(&mut self, f: F) -> Result<()>
     where
@@ -97,12 +107,14 @@ impl FMS {
     {
         debug!("modifying route");
         f(&mut self.route);
+        self.record_history();
         self.context.route = self.route.to_string();
         EvalPipeline::default().eval(self)
     }
 
     pub fn decode(&mut self, route: String) -> Result<()> {
         info!("decoding route: {:?}", route);
+        self.record_history();
         self.context.route = route;
         EvalPipeline::default().eval(self)
     }
@@ -129,18 +141,152 @@ impl FMS {
         }
     }
 
+    /// Sets the takeoff runway of the route's origin.
+    ///
+    /// Returns an [UnknownRunwayInRoute] error if the origin has no runway with
+    /// the `designator`.
+    ///
+    /// [UnknownRunwayInRoute]: Error::UnknownRunwayInRoute
+    pub fn set_takeoff_runway(&mut self, designator: &str) -> Result<()> {
+        info!("setting takeoff runway to {:?}", designator);
+        let origin = self
+            .route
+            .tokens()
+            .iter()
+            .position(|t| matches!(t.kind(), TokenKind::Airport { .. }));
+        self.set_runway(origin, designator)
+    }
+
+    /// Sets the landing runway of the route's destination.
+    ///
+    /// Returns an [UnknownRunwayInRoute] error if the destination has no runway
+    /// with the `designator`.
+    ///
+    /// [UnknownRunwayInRoute]: Error::UnknownRunwayInRoute
+    pub fn set_landing_runway(&mut self, designator: &str) -> Result<()> {
+        info!("setting landing runway to {:?}", designator);
+        // the destination is the last airport but never the origin
+        let destination = self.route.destination().and_then(|_| {
+            self.route
+                .tokens()
+                .iter()
+                .rposition(|t| matches!(t.kind(), TokenKind::Airport { .. }))
+        });
+        self.set_runway(destination, designator)
+    }
+
+    /// Replaces the airport token at `index` of the route by the airport with
+    /// the runway `designator` and re-evaluates the route.
+    fn set_runway(&mut self, index: Option<usize>, designator: &str) -> Result<()> {
+        let Some((range, arpt)) =
+            index
+                .and_then(|i| self.route.tokens().get(i))
+                .and_then(|token| match token.kind() {
+                    TokenKind::Airport { arpt, .. } => {
+                        Some((token.range().clone(), Shared::clone(arpt)))
+                    }
+                    _ => None,
+                })
+        else {
+            warn!("no airport in route for runway {:?}", designator);
+            return Err(Error::UnexpectedRunwayInRoute(designator.to_string()));
+        };
+
+        if !arpt
+            .runways
+            .iter()
+            .any(|rwy| rwy.matches_designator(designator))
+        {
+            warn!("runway {:?} not found on {}", designator, arpt.ident());
+            return Err(Error::UnknownRunwayInRoute {
+                arpt: arpt.ident(),
+                rwy: designator.to_string(),
+            });
+        }
+
+        debug!("replacing {:?} by {}{}", range, arpt.ident(), designator);
+        self.record_history();
+        self.context
+            .route
+            .replace_range(range, &format!("{}{}", arpt.ident(), designator));
+        EvalPipeline::default().eval(self)
+    }
+
     pub fn set_flight_planning(&mut self, builder: FlightPlanningBuilder) -> Result<()> {
         info!("setting flight planning");
+        self.record_history();
         self.context.flight_planning_builder = Some(builder);
         EvalPipeline::default()
             .skip_until(EvalStage::FlightPlanning)
             .eval(self)
     }
 
+    /// Clears the flight planning but keeps the route.
+    pub fn clear_flight_planning(&mut self) {
+        info!("clearing flight planning");
+        self.record_history();
+        self.context.flight_planning_builder = None;
+        self.flight_planning = None;
+    }
+
     pub fn flight_planning(&self) -> Option<&FlightPlanning> {
         self.flight_planning.as_ref()
     }
 
+    /// Reverts the last change of the route or flight planning.
+    ///
+    /// Up to 50 changes can be undone. Does nothing if there is no change to
+    /// undo.
+    pub fn undo(&mut self) -> Result<()> {
+        match self.undo_history.pop_back() {
+            Some(context) => {
+                info!("undoing last change");
+                let current = std::mem::replace(&mut self.context, context);
+                self.redo_history.push(current);
+                EvalPipeline::default().eval(self)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Restores the last change that was [undone].
+    ///
+    /// Does nothing if there is no change to redo. Any new change discards the
+    /// changes that could be redone.
+    ///
+    /// [undone]: Self::undo
+    pub fn redo(&mut self) -> Result<()> {
+        match self.redo_history.pop() {
+            Some(context) => {
+                info!("redoing last undone change");
+                let current = std::mem::replace(&mut self.context, context);
+                self.undo_history.push_back(current);
+                EvalPipeline::default().eval(self)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if there is a change to [undo](Self::undo).
+    pub fn can_undo(&self) -> bool {
+        !self.undo_history.is_empty()
+    }
+
+    /// Returns `true` if there is a change to [redo](Self::redo).
+    pub fn can_redo(&self) -> bool {
+        !self.redo_history.is_empty()
+    }
+
+    /// Records the current context before it is changed.
+    fn record_history(&mut self) {
+        if self.undo_history.len() == HISTORY_LENGTH {
+            self.undo_history.pop_front();
+        }
+
+        self.undo_history.push_back(self.context.clone());
+        self.redo_history.clear();
+    }
+
     /// Prints the route and planning with a defined line length.
     pub fn print(&self, line_length: usize) -> String {
         let printer = Printer { line_length };
@@ -238,7 +384,7 @@ impl EvalStage {
                 fms.route.decode(&fms.context.route, &fms.nd)?;
                 debug!(
                     "route decoded: {} leg(s), origin={:?}, destination={:?}",
-                    fms.route.legs().len(),
+                    fms.route.num_legs(),
                     fms.route.origin().as_ref().map(|a| a.ident()),
                     fms.route.destination().as_ref().map(|a| a.ident()),
                 );
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -16,9 +16,11 @@
 use std::fmt::{Error, Write as _};
 
 use crate::fp::{FlightPlanning, FuelPlanning, RunwayAnalysis};
+use crate::measurements::Angle;
 use crate::measurements::LengthUnit;
 use crate::nd::*;
-use crate::route::Route;
+use crate::route::{Leg, Route};
+use crate::MagneticVariation;
 
 /// Prints the flight planning of the FMS.
 ///
@@ -75,28 +77,24 @@ impl Printer {
         self.write_section(buffer, "ROUTE")?;
 
         for leg in route.legs() {
-            let space = (self.line_length - 23) / 3;
-
-            let is_heading = leg.mh().is_some();
+            let space = self.line_length.saturating_sub(31) / 4;
 
             writeln!(
                 buffer,
-                "{:<6}{:space$}{:^6}{:space$}{:>8}{:space$}{:^5}",
-                "TO",
-                "",
-                if is_heading { "HDG" } else { "TRK" },
-                "",
-                "DIST",
-                "",
-                "ETE"
+                "{:<6}{:space$}{:^6}{:space$}{:^6}{:space$}{:>8}{:space$}{:^5}",
+                "TO", "", "MC", "", "HDG", "", "DIST", "", "ETE"
             )?;
 
             writeln!(
                 buffer,
-                "{:<6}{:space$}{:^6.0}{:space$}{:>8.1}{:space$}{:^5}",
+                "{:<6}{:space$}{:^6.0}{:space$}{:^6}{:space$}{:>8.1}{:space$}{:^5}",
                 leg.to().ident(),
                 "",
-                leg.mh().unwrap_or(leg.mc()),
+                course(leg),
+                "",
+                leg.mh()
+                    .map(|mh| format!("{mh:.0}"))
+                    .unwrap_or("-".to_string()),
                 "",
                 leg.dist().convert_to(LengthUnit::NauticalMiles),
                 "",
@@ -263,3 +261,42 @@ impl Printer {
         Ok(())
     }
 }
+
+/// Returns the magnetic course of the leg.
+///
+/// If no magnetic variation is available at the start of the leg, the true
+/// course is returned, which is printed with a `T` suffix.
+fn course(leg: &Leg) -> Angle {
+    match leg.from().mag_var() {
+        MagneticVariation::OrientedToTrueNorth => *leg.bearing(),
+        _ => *leg.mc(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARINC_424_RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E100000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+"#;
+
+    #[test]
+    fn prints_magnetic_course() {
+        let nd =
+            NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+        let mut route = Route::new();
+        route.decode("EDDH EDHF", &nd).expect("route should decode");
+
+        let printer = Printer { line_length: 40 };
+        let output = printer.print(&route, None).expect("route should print");
+
+        // the record of Hamburg has a magnetic variation of 10° east
+        let tc = route.legs()[0].bearing().value();
+        let mc = format!("{:.0}", Angle::m(tc - 10.0));
+
+        assert!(output.contains(&mc), "{mc:?} should be in {output}");
+        assert!(output.contains("MC"));
+    }
+}
@@ -19,10 +19,10 @@ use super::*;
 
 use crate::aircraft::Aircraft;
 use crate::error::Error;
-use crate::measurements::{Mass, Temperature};
+use crate::measurements::{Length, Mass, Pressure, Temperature};
 use crate::nd::RunwayConditionCode;
 use crate::route::Route;
-use crate::{Fuel, Wind};
+use crate::{Fuel, VerticalDistance, Wind};
 
 /// Flight planning factory, which is used to build a flight planning.
 #[derive(Clone, PartialEq, Debug, Default)]
@@ -68,8 +68,12 @@ impl FlightPlanningBuilder {
                 debug!("computing fuel planning (policy={:?})", policy);
                 let leg_perf = LegPerformance::new(
                     self.perf.as_ref(),
-                    self.climb_perf.as_ref(),
-                    self.descent_perf.as_ref(),
+                    self.climb_perf
+                        .as_ref()
+                        .or_else(|| self.perf.as_ref().and_then(Performance::climb)),
+                    self.descent_perf
+                        .as_ref()
+                        .or_else(|| self.perf.as_ref().and_then(Performance::descent)),
                 );
                 let fp = FuelPlanning::new(aircraft, policy, taxi, route, reserve, &leg_perf);
 
@@ -169,6 +173,29 @@ impl FlightPlanningBuilder {
             }
         };
 
+        let mut warnings = Vec::new();
+
+        // levels and ceiling may be given in different datums, which are
+        // compared above mean sea level
+        let msl = |vd: &VerticalDistance| {
+            vd.to_msl(Pressure::STD, Length::ft(0.0))
+                .map_or(f32::INFINITY, |alt| alt.to_si())
+        };
+
+        let max_level = route
+            .legs()
+            .iter()
+            .filter_map(|leg| leg.level())
+            .max_by(|a, b| msl(a).total_cmp(&msl(b)));
+        let ceiling = self.perf.as_ref().and_then(|perf| perf.ceiling());
+
+        if let (Some(&level), Some(&ceiling)) = (max_level, ceiling) {
+            if msl(&level) > msl(&ceiling) {
+                warn!("level {level} is above the service ceiling {ceiling}");
+                warnings.push(Error::AboveServiceCeiling { level, ceiling });
+            }
+        }
+
         info!(
             "flight planning built: fuel={}, mb={}, takeoff_rwy={}, landing_rwy={}",
             fuel_planning.is_some(),
@@ -184,6 +211,7 @@ impl FlightPlanningBuilder {
             is_balanced,
             takeoff_rwy_analysis,
             landing_rwy_analysis,
+            warnings,
         })
     }
 
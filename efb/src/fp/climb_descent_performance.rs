@@ -18,7 +18,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::measurements::{Altitude, Duration, Length, Pressure, Speed, VerticalRate, Volume};
+use crate::measurements::{
+    Altitude, AltitudeUnit, Duration, Length, Mass, Pressure, Speed, VerticalRate, Volume,
+};
 use crate::{Fuel, FuelFlow, FuelType, VerticalDistance};
 
 /// One row of a climb or descent performance table.
@@ -46,7 +48,8 @@ use crate::{Fuel, FuelFlow, FuelType, VerticalDistance};
 ///     ff: FuelFlow::PerHour(Fuel::new(Mass::kg(15.0), FuelType::AvGas)),
 /// };
 /// ```
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct ClimbDescentBand {
     /// Upper bound of this altitude band (e.g. `Altitude(5000)` for a band
     /// that covers everything up to 5 000 ft).
@@ -203,7 +206,8 @@ pub struct CumulativeClimbDescentEntry {
 ///     .between(&VerticalDistance::Altitude(500), &VerticalDistance::Fl(80))
 ///     .unwrap();
 /// ```
-#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct ClimbDescentPerformance {
     table: Vec<ClimbDescentBand>,
 }
@@ -270,6 +274,10 @@ fn to_altitude(vd: &VerticalDistance) -> Option<Altitude> {
     vd.to_msl(Pressure::STD, Length::ft(0.0))
 }
 
+/// Rule of thumb for the increase of the density altitude per °C deviation
+/// from ISA temperature.
+const DENSITY_ALTITUDE_FT_PER_ISA_DEV: f32 = 120.0;
+
 impl ClimbDescentPerformance {
     /// Creates a performance table from a pre-built vector of rows.
     ///
@@ -281,6 +289,30 @@ impl ClimbDescentPerformance {
         Self { table }
     }
 
+    /// Returns `true` if the table can be used to plan a climb or descent.
+    ///
+    /// The table must have at least one band, list the bands in ascending
+    /// order above mean sea level without heights above ground, and have a
+    /// positive speed, vertical rate and fuel flow in every band.
+    #[cfg(feature = "serde")]
+    pub(crate) fn is_plausible(&self) -> bool {
+        let msl = |band: &ClimbDescentBand| match band.level {
+            VerticalDistance::Agl(_) => None,
+            level => to_altitude(&level),
+        };
+
+        let levels: Option<Vec<Altitude>> = self.table.iter().map(msl).collect();
+
+        !self.table.is_empty()
+            && levels.is_some_and(|levels| levels.windows(2).all(|alts| alts[0] < alts[1]))
+            && self.table.iter().all(|band| {
+                let FuelFlow::PerHour(fuel) = band.ff;
+                band.tas.to_si() > 0.0
+                    && band.vertical_rate.to_si() > 0.0
+                    && fuel.mass.to_si() > 0.0
+            })
+    }
+
     /// Builds a performance table by sampling `f` in 1000 ft steps from
     /// ground up to `ceiling` (inclusive).
     ///
@@ -537,6 +569,68 @@ impl ClimbDescentPerformance {
             horizontal_distance: total_dist,
         })
     }
+
+    /// Returns the still-air distance, time and fuel mass to climb from one
+    /// level to another at a deviation from ISA temperature.
+    ///
+    /// The climb is computed like [`between`](Self::between), but the bands
+    /// are looked up at the density altitude. It's approximated by shifting
+    /// both levels 120 ft up per °C of `isa_dev`, though not below sea level.
+    /// Thus, a climb on a hot day uses the performance of higher bands.
+    ///
+    /// Returns zeros if `from` isn't below `to`, since no climb is needed.
+    /// Returns `None` if the table is empty or either level can't be resolved
+    /// above mean sea level without the ground elevation ([AGL]) or at all
+    /// ([Unlimited]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::prelude::*;
+    /// let ff = FuelFlow::PerHour(Fuel::new(Mass::kg(15.0), FuelType::AvGas));
+    /// let climb = ClimbDescentPerformance::from_fn(
+    ///     |_| (Speed::kt(90.0), VerticalRate::fpm(500.0), ff),
+    ///     VerticalDistance::Fl(100),
+    /// );
+    ///
+    /// let (_, time, _) = climb
+    ///     .climb_to(VerticalDistance::Gnd, VerticalDistance::Fl(100), 0.0)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(time, Duration::m(20));
+    /// ```
+    ///
+    /// [AGL]: `VerticalDistance::Agl`
+    /// [Unlimited]: `VerticalDistance::Unlimited`
+    pub fn climb_to(
+        &self,
+        from: VerticalDistance,
+        to: VerticalDistance,
+        isa_dev: f32,
+    ) -> Option<(Length, Duration, Mass)> {
+        if self.table.is_empty()
+            || matches!(from, VerticalDistance::Agl(_))
+            || matches!(to, VerticalDistance::Agl(_))
+        {
+            return None;
+        }
+
+        let from_ft = *to_altitude(&from)?.convert_to(AltitudeUnit::Feet).value();
+        let to_ft = *to_altitude(&to)?.convert_to(AltitudeUnit::Feet).value();
+        let shift = (DENSITY_ALTITUDE_FT_PER_ISA_DEV * isa_dev).max(-from_ft);
+        let from_density = (from_ft + shift).round() as u16;
+        let to_density = (to_ft + shift).round() as u16;
+
+        if from_density >= to_density {
+            return Some((Length::nm(0.0), Duration::s(0), Mass::kg(0.0)));
+        }
+
+        self.between(
+            &VerticalDistance::Altitude(from_density),
+            &VerticalDistance::Altitude(to_density),
+        )
+        .map(|result| (result.horizontal_distance, result.time, result.fuel.mass))
+    }
 }
 
 #[cfg(test)]
@@ -621,6 +715,80 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn climb_to_fl100_from_sea_level() {
+        let perf = ClimbDescentPerformance::from_fn(
+            |_| (Speed::kt(90.0), VerticalRate::fpm(500.0), avgas_ff(40.0)),
+            VerticalDistance::Fl(100),
+        );
+
+        // 10000 ft / 500 fpm = 20 min at 90 kt = 30 NM
+        let (dist, time, _) = perf
+            .climb_to(VerticalDistance::Gnd, VerticalDistance::Fl(100), 0.0)
+            .expect("climb should be computed");
+
+        assert_eq!(time, Duration::m(20));
+        assert!(
+            (*dist.convert_to(LengthUnit::NauticalMiles).value() - 30.0).abs() < 0.05,
+            "distance ~30 NM, got {dist}"
+        );
+    }
+
+    #[test]
+    fn climb_to_lower_level_is_zero() {
+        let perf = simple_table();
+        let (dist, time, fuel) = perf
+            .climb_to(
+                VerticalDistance::Altitude(3000),
+                VerticalDistance::Altitude(1000),
+                0.0,
+            )
+            .expect("climb should be computed");
+
+        assert_eq!(dist, Length::nm(0.0));
+        assert_eq!(time, Duration::s(0));
+        assert_eq!(fuel, Mass::kg(0.0));
+    }
+
+    #[test]
+    fn climb_to_unresolved_level_is_none() {
+        let perf = simple_table();
+
+        assert_eq!(
+            perf.climb_to(VerticalDistance::Gnd, VerticalDistance::Unlimited, 0.0),
+            None
+        );
+        assert_eq!(
+            perf.climb_to(VerticalDistance::Gnd, VerticalDistance::Agl(1000), 0.0),
+            None
+        );
+        assert_eq!(
+            ClimbDescentPerformance::new(Vec::new()).climb_to(
+                VerticalDistance::Gnd,
+                VerticalDistance::Altitude(2000),
+                0.0
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn climb_to_is_slower_on_hot_day() {
+        let perf = simple_table();
+        let (_, isa, _) = perf
+            .climb_to(VerticalDistance::Gnd, VerticalDistance::Altitude(2000), 0.0)
+            .expect("climb should be computed");
+        let (_, hot, _) = perf
+            .climb_to(
+                VerticalDistance::Gnd,
+                VerticalDistance::Altitude(2000),
+                20.0,
+            )
+            .expect("climb should be computed");
+
+        assert!(hot > isa, "{hot} should be longer than {isa}");
+    }
+
     #[test]
     fn with_wind_reduces_distance_for_headwind() {
         let perf = simple_table();
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON export of a flight planning.
+
+use serde::Serialize;
+
+use super::{FlightPlanning, FuelPlanning, MassAndBalance};
+use crate::error::Error;
+use crate::measurements::{AngleUnit, DurationUnit, LengthUnit, MassUnit, SpeedUnit};
+use crate::nd::Fix;
+use crate::route::{Leg, Route};
+use crate::Fuel;
+
+#[derive(Serialize)]
+struct FlightPlanningJson {
+    legs: Vec<LegJson>,
+    fuel: Option<FuelJson>,
+    mb: Option<MassAndBalanceJson>,
+}
+
+#[derive(Serialize)]
+struct LegJson {
+    from: String,
+    to: String,
+    level: Option<String>,
+    mc_deg: f32,
+    dist_nm: f32,
+    tas_kt: Option<f32>,
+    gs_kt: Option<f32>,
+    mh_deg: Option<f32>,
+    ete_s: Option<u32>,
+}
+
+impl From<&Leg> for LegJson {
+    fn from(leg: &Leg) -> Self {
+        Self {
+            from: leg.from().ident(),
+            to: leg.to().ident(),
+            level: leg.level().map(|level| level.to_string()),
+            mc_deg: *leg.mc().convert_to(AngleUnit::MagneticNorth).value(),
+            dist_nm: *leg.dist().convert_to(LengthUnit::NauticalMiles).value(),
+            tas_kt: leg
+                .tas()
+                .map(|tas| *tas.convert_to(SpeedUnit::Knots).value()),
+            gs_kt: leg.gs().map(|gs| *gs.convert_to(SpeedUnit::Knots).value()),
+            mh_deg: leg
+                .mh()
+                .map(|mh| *mh.convert_to(AngleUnit::MagneticNorth).value()),
+            ete_s: leg
+                .ete()
+                .map(|ete| *ete.convert_to(DurationUnit::Seconds).value()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FuelJson {
+    taxi_kg: f32,
+    trip_kg: f32,
+    alternate_kg: Option<f32>,
+    reserve_kg: f32,
+    min_kg: f32,
+    extra_kg: Option<f32>,
+    total_kg: f32,
+    after_landing_kg: f32,
+}
+
+fn kg(fuel: &Fuel) -> f32 {
+    *fuel.mass.convert_to(MassUnit::Kilograms).value()
+}
+
+impl From<&FuelPlanning> for FuelJson {
+    fn from(fuel: &FuelPlanning) -> Self {
+        Self {
+            taxi_kg: kg(fuel.taxi()),
+            trip_kg: kg(fuel.trip()),
+            alternate_kg: fuel.alternate().map(kg),
+            reserve_kg: kg(fuel.reserve()),
+            min_kg: kg(fuel.min()),
+            extra_kg: fuel.extra().map(kg),
+            total_kg: kg(fuel.total()),
+            after_landing_kg: kg(fuel.after_landing()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct MassAndBalanceJson {
+    mass_on_ramp_kg: f32,
+    mass_after_landing_kg: f32,
+    balance_on_ramp_m: f32,
+    balance_after_landing_m: f32,
+    is_balanced: Option<bool>,
+}
+
+impl MassAndBalanceJson {
+    fn new(mb: &MassAndBalance, is_balanced: Option<bool>) -> Self {
+        Self {
+            mass_on_ramp_kg: *mb.mass_on_ramp().convert_to(MassUnit::Kilograms).value(),
+            mass_after_landing_kg: *mb
+                .mass_after_landing()
+                .convert_to(MassUnit::Kilograms)
+                .value(),
+            balance_on_ramp_m: *mb.balance_on_ramp().convert_to(LengthUnit::Meters).value(),
+            balance_after_landing_m: *mb
+                .balance_after_landing()
+                .convert_to(LengthUnit::Meters)
+                .value(),
+            is_balanced,
+        }
+    }
+}
+
+impl FlightPlanning {
+    /// Exports the nav-log, fuel planning and mass & balance as JSON.
+    ///
+    /// The nav-log is taken from the `route` for which this planning was
+    /// built. The exported structure is independent of the internal
+    /// representation of the flight planning and is kept stable between
+    /// releases. All values are plain numbers with the unit encoded in the key:
+    ///
+    /// ```json
+    /// {
+    ///   "legs": [
+    ///     {
+    ///       "from": "EDDH",
+    ///       "to": "EDHF",
+    ///       "level": "A0250",
+    ///       "mc_deg": 310.2,
+    ///       "dist_nm": 22.1,
+    ///       "tas_kt": 107.0,
+    ///       "gs_kt": 95.3,
+    ///       "mh_deg": 318.4,
+    ///       "ete_s": 835
+    ///     }
+    ///   ],
+    ///   "fuel": {
+    ///     "taxi_kg": 7.5,
+    ///     "trip_kg": 9.1,
+    ///     "alternate_kg": null,
+    ///     "reserve_kg": 17.4,
+    ///     "min_kg": 34.0,
+    ///     "extra_kg": null,
+    ///     "total_kg": 34.0,
+    ///     "after_landing_kg": 26.5
+    ///   },
+    ///   "mb": {
+    ///     "mass_on_ramp_kg": 1090.0,
+    ///     "mass_after_landing_kg": 1080.9,
+    ///     "balance_on_ramp_m": 1.0,
+    ///     "balance_after_landing_m": 1.0,
+    ///     "is_balanced": true
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Values that are unknown, e.g. the ground speed of a leg without a TAS,
+    /// are `null`. The `fuel` and `mb` objects are `null` if the respective
+    /// planning wasn't computed.
+    pub fn to_json(&self, route: &Route) -> Result<String, Error> {
+        let json = FlightPlanningJson {
+            legs: route.legs().iter().map(LegJson::from).collect(),
+            fuel: self.fuel_planning.as_ref().map(FuelJson::from),
+            mb: self
+                .mb
+                .as_ref()
+                .map(|mb| MassAndBalanceJson::new(mb, self.is_balanced)),
+        };
+
+        Ok(serde_json::to_string(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aircraft::{Aircraft, CGLimit, FuelTank, Station};
+    use crate::fp::{FuelPolicy, Performance, Reserve};
+    use crate::measurements::{Duration, Length, Mass, Speed, Volume};
+    use crate::nd::NavigationData;
+    use crate::{FuelFlow, FuelType, VerticalDistance};
+
+    const RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+SEURP EDHFEDGRW20    0034122060 N53594752E009344856                          098                                           120792502
+"#;
+
+    #[test]
+    fn json_has_legs_fuel_and_mb() {
+        let nd = NavigationData::try_from_arinc424(RECORDS).expect("records should be valid");
+        let mut route = Route::new();
+        route
+            .decode("29020KT N0107 A0250 EDDH33 EDHF20", &nd)
+            .expect("route should decode");
+
+        let aircraft = Aircraft::builder()
+            .registration(String::from("N12345"))
+            .stations(vec![Station::new(Length::m(1.0), None)])
+            .empty_mass(Mass::kg(800.0))
+            .empty_balance(Length::m(1.0))
+            .fuel_type(FuelType::Diesel)
+            .tanks(vec![FuelTank::new(Volume::l(100.0), Length::m(1.0))])
+            .cg_envelope(vec![
+                CGLimit::new(Mass::kg(0.0), Length::m(0.5)),
+                CGLimit::new(Mass::kg(1200.0), Length::m(0.5)),
+                CGLimit::new(Mass::kg(1200.0), Length::m(1.5)),
+                CGLimit::new(Mass::kg(0.0), Length::m(1.5)),
+            ])
+            .build()
+            .expect("aircraft should build");
+
+        let fp = FlightPlanning::builder()
+            .aircraft(aircraft)
+            .mass(vec![Mass::kg(80.0)])
+            .policy(FuelPolicy::MinimumFuel)
+            .taxi(diesel!(Volume::l(10.0)))
+            .reserve(Reserve::Manual(Duration::s(1800)))
+            .perf(Performance::from_fn(
+                |_| {
+                    (
+                        Speed::kt(107.0),
+                        FuelFlow::PerHour(diesel!(Volume::l(21.0))),
+                    )
+                },
+                VerticalDistance::Fl(100),
+            ))
+            .build(&route)
+            .expect("flight planning should build");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fp.to_json(&route).expect("planning should serialize"))
+                .expect("JSON should be valid");
+
+        assert_eq!(
+            json["legs"].as_array().map(Vec::len),
+            Some(route.legs().len())
+        );
+        assert_eq!(json["fuel"].as_object().map(|fuel| fuel.len()), Some(8));
+        assert_eq!(json["mb"].as_object().map(|mb| mb.len()), Some(5));
+    }
+}
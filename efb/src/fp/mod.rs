@@ -36,6 +36,8 @@ use serde::{Deserialize, Serialize};
 mod builder;
 mod climb_descent_performance;
 mod fuel_planning;
+#[cfg(feature = "serde")]
+mod json;
 mod leg_performance;
 mod mb;
 mod perf;
@@ -54,6 +56,7 @@ pub use runway_analysis::*;
 pub use takeoff_landing_performance::*;
 
 use crate::aircraft::Aircraft;
+use crate::error::Error;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq)]
@@ -64,6 +67,7 @@ pub struct FlightPlanning {
     is_balanced: Option<bool>,
     takeoff_rwy_analysis: Option<RunwayAnalysis>,
     landing_rwy_analysis: Option<RunwayAnalysis>,
+    warnings: Vec<Error>,
 }
 
 impl FlightPlanning {
@@ -90,4 +94,10 @@ impl FlightPlanning {
     pub fn landing_rwy_analysis(&self) -> Option<&RunwayAnalysis> {
         self.landing_rwy_analysis.as_ref()
     }
+
+    /// Returns the issues found while planning that don't prevent the flight
+    /// planning from being built, e.g. a level above the service ceiling.
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
 }
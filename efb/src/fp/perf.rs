@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,11 +13,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "serde")]
+use std::io::{Read, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ClimbDescentPerformance;
+#[cfg(feature = "serde")]
+use crate::error::Error;
 use crate::measurements::Speed;
+#[cfg(feature = "serde")]
+use crate::measurements::{Length, Pressure};
 use crate::{FuelFlow, VerticalDistance};
 
 /// A row of the performance table presenting a performance up to a specific
 /// level.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct PerformanceTableRow {
     pub level: VerticalDistance,
@@ -30,19 +42,57 @@ pub type PerformanceTable = Vec<PerformanceTableRow>;
 
 /// Used to provide [Speed] or [FuelFlow] for a defined performance setting at
 /// different level.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct Performance {
     table: PerformanceTable,
+    #[cfg_attr(feature = "serde", serde(default))]
+    ceiling: Option<VerticalDistance>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    climb: Option<ClimbDescentPerformance>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    descent: Option<ClimbDescentPerformance>,
 }
 
 impl Performance {
     pub fn new(table: PerformanceTable) -> Self {
-        Self { table }
+        Self {
+            table,
+            ceiling: None,
+            climb: None,
+            descent: None,
+        }
+    }
+
+    /// Sets the aircraft's service ceiling.
+    pub fn with_ceiling(mut self, ceiling: VerticalDistance) -> Self {
+        self.ceiling = Some(ceiling);
+        self
+    }
+
+    /// Sets the aircraft's climb performance.
+    ///
+    /// The flight planning uses it unless the builder has its own
+    /// [`climb_perf`](super::FlightPlanningBuilder::climb_perf).
+    pub fn with_climb(mut self, climb: ClimbDescentPerformance) -> Self {
+        self.climb = Some(climb);
+        self
+    }
+
+    /// Sets the aircraft's descent performance.
+    ///
+    /// The flight planning uses it unless the builder has its own
+    /// [`descent_perf`](super::FlightPlanningBuilder::descent_perf).
+    pub fn with_descent(mut self, descent: ClimbDescentPerformance) -> Self {
+        self.descent = Some(descent);
+        self
     }
 
     /// Creates the performance profile from a function.
     ///
-    /// The function `f` is called in 1000 ft intervals up to the ceiling.
+    /// The function `f` is called in 1000 ft intervals up to the ceiling. The
+    /// ceiling only limits the table and isn't taken as the aircraft's service
+    /// ceiling, which is set with [`with_ceiling`](Self::with_ceiling).
     pub fn from_fn<F>(f: F, ceiling: VerticalDistance) -> Self
     where
         F: Fn(&VerticalDistance) -> (Speed, FuelFlow),
@@ -59,7 +109,142 @@ impl Performance {
             vd = VerticalDistance::Altitude(alt);
         }
 
-        Self { table }
+        Self::new(table)
+    }
+
+    /// Returns the aircraft's service ceiling if known.
+    pub fn ceiling(&self) -> Option<&VerticalDistance> {
+        self.ceiling.as_ref()
+    }
+
+    /// Returns the aircraft's climb performance if known.
+    pub fn climb(&self) -> Option<&ClimbDescentPerformance> {
+        self.climb.as_ref()
+    }
+
+    /// Returns the aircraft's descent performance if known.
+    pub fn descent(&self) -> Option<&ClimbDescentPerformance> {
+        self.descent.as_ref()
+    }
+
+    /// Reads the performance profile from JSON.
+    ///
+    /// The profile is a table of rows, each providing the performance from
+    /// its level up to the level of the next row:
+    ///
+    /// ```json
+    /// {
+    ///   "table": [
+    ///     {
+    ///       "level": "Gnd",
+    ///       "tas": { "value": 107.0, "unit": "Knots" },
+    ///       "ff": {
+    ///         "PerHour": {
+    ///           "fuel_type": "Diesel",
+    ///           "mass": { "value": 17.6, "unit": "Kilograms" }
+    ///         }
+    ///       }
+    ///     },
+    ///     {
+    ///       "level": { "Altitude": 5000 },
+    ///       "tas": { "value": 110.0, "unit": "Knots" },
+    ///       "ff": {
+    ///         "PerHour": {
+    ///           "fuel_type": "Diesel",
+    ///           "mass": { "value": 16.8, "unit": "Kilograms" }
+    ///         }
+    ///       }
+    ///     }
+    ///   ],
+    ///   "ceiling": { "Altitude": 14000 },
+    ///   "climb": {
+    ///     "table": [
+    ///       {
+    ///         "level": { "Altitude": 14000 },
+    ///         "tas": { "value": 75.0, "unit": "Knots" },
+    ///         "vertical_rate": { "value": 500.0, "unit": "FeetPerMinute" },
+    ///         "ff": {
+    ///           "PerHour": {
+    ///             "fuel_type": "Diesel",
+    ///             "mass": { "value": 21.0, "unit": "Kilograms" }
+    ///           }
+    ///         }
+    ///       }
+    ///     ]
+    ///   },
+    ///   "descent": { "table": [...] }
+    /// }
+    /// ```
+    ///
+    /// The `ceiling` is the optional service ceiling of the aircraft. The
+    /// table must start at `Gnd` and list the other rows in ascending order as
+    /// `Altitude`, `Fl` or `Msl`, so that every level of a route can be looked
+    /// up in it.
+    ///
+    /// The optional `climb` and `descent` performance lists bands up to their
+    /// `level` like a [`ClimbDescentPerformance`]. Their bands must be in
+    /// ascending order and not above ground.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Serialization`] if the profile doesn't match the
+    /// schema, or [`Error::ImplausibleValue`] if the table doesn't start at
+    /// `Gnd`, a table isn't sorted in one comparable datum, or a speed,
+    /// vertical rate or fuel flow isn't positive.
+    #[cfg(feature = "serde")]
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, Error> {
+        let perf: Self = serde_json::from_reader(reader)?;
+
+        let starts_at_gnd = perf
+            .table
+            .first()
+            .is_some_and(|row| row.level == VerticalDistance::Gnd);
+
+        // Only levels above mean sea level can be compared to the levels of a
+        // route, so we reject anything else above the ground row.
+        let is_comparable = perf.table.iter().skip(1).all(|row| {
+            matches!(
+                row.level,
+                VerticalDistance::Altitude(_) | VerticalDistance::Fl(_) | VerticalDistance::Msl(_)
+            )
+        });
+
+        // compared above mean sea level, since flight levels above 655
+        // overflow the order of vertical distances
+        let msl = |row: &PerformanceTableRow| {
+            row.level
+                .to_msl(Pressure::STD, Length::ft(0.0))
+                .map(|alt| alt.to_si())
+        };
+
+        let is_plausible = starts_at_gnd
+            && is_comparable
+            && perf
+                .table
+                .windows(2)
+                .all(|rows| msl(&rows[0]) < msl(&rows[1]))
+            && perf.table.iter().all(|row| {
+                let FuelFlow::PerHour(fuel) = row.ff;
+                row.tas.to_si() > 0.0 && fuel.mass.to_si() > 0.0
+            })
+            && [&perf.climb, &perf.descent]
+                .into_iter()
+                .flatten()
+                .all(ClimbDescentPerformance::is_plausible);
+
+        if is_plausible {
+            Ok(perf)
+        } else {
+            Err(Error::ImplausibleValue)
+        }
+    }
+
+    /// Writes the performance profile as JSON.
+    ///
+    /// The written profile can be read by [`Performance::from_reader`].
+    #[cfg(feature = "serde")]
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), Error> {
+        Ok(serde_json::to_writer(writer, self)?)
     }
 
     /// Returns the true airspeed at a level.
@@ -88,4 +273,187 @@ impl Performance {
     }
 }
 
-// TODO: Add unit tests!
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::measurements::{Duration, Length};
+
+    const PROFILE: &str = r#"{
+        "table": [
+            {
+                "level": "Gnd",
+                "tas": { "value": 107.0, "unit": "Knots" },
+                "ff": {
+                    "PerHour": {
+                        "fuel_type": "Diesel",
+                        "mass": { "value": 17.6, "unit": "Kilograms" }
+                    }
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn leg_ete_from_loaded_profile() {
+        let perf = Performance::from_reader(PROFILE.as_bytes()).expect("profile should be valid");
+
+        let ete = Length::nm(107.0) / perf.tas(&VerticalDistance::Altitude(2500));
+        assert_eq!(ete, Duration::s(3600));
+    }
+
+    #[test]
+    fn writes_readable_profile() {
+        let perf = Performance::from_reader(PROFILE.as_bytes()).expect("profile should be valid");
+
+        let mut buf = Vec::new();
+        perf.to_writer(&mut buf).expect("profile should be written");
+
+        assert_eq!(Performance::from_reader(buf.as_slice()), Ok(perf));
+    }
+
+    #[test]
+    fn rejects_table_above_ground() {
+        let profile = PROFILE.replace(r#""Gnd""#, r#"{ "Altitude": 3000 }"#);
+
+        assert_eq!(
+            Performance::from_reader(profile.as_bytes()),
+            Err(Error::ImplausibleValue)
+        );
+    }
+
+    #[test]
+    fn rejects_unsorted_table() {
+        let row = |level: &str| {
+            format!(
+                r#"{{
+                    "level": {level},
+                    "tas": {{ "value": 107.0, "unit": "Knots" }},
+                    "ff": {{
+                        "PerHour": {{
+                            "fuel_type": "Diesel",
+                            "mass": {{ "value": 17.6, "unit": "Kilograms" }}
+                        }}
+                    }}
+                }}"#
+            )
+        };
+        let profile = |levels: &[&str]| {
+            let rows: Vec<String> = levels.iter().map(|level| row(level)).collect();
+            format!(r#"{{ "table": [{}] }}"#, rows.join(","))
+        };
+
+        assert!(Performance::from_reader(
+            profile(&[r#""Gnd""#, r#"{ "Altitude": 5000 }"#]).as_bytes()
+        )
+        .is_ok());
+
+        assert_eq!(
+            Performance::from_reader(
+                profile(&[
+                    r#""Gnd""#,
+                    r#"{ "Altitude": 5000 }"#,
+                    r#"{ "Altitude": 3000 }"#
+                ])
+                .as_bytes()
+            ),
+            Err(Error::ImplausibleValue)
+        );
+
+        assert_eq!(
+            Performance::from_reader(profile(&[r#""Gnd""#, r#"{ "Agl": 1000 }"#]).as_bytes()),
+            Err(Error::ImplausibleValue)
+        );
+    }
+
+    const CLIMB: &str = r#"{
+        "table": [
+            {
+                "level": { "Altitude": 10000 },
+                "tas": { "value": 75.0, "unit": "Knots" },
+                "vertical_rate": { "value": 500.0, "unit": "FeetPerMinute" },
+                "ff": {
+                    "PerHour": {
+                        "fuel_type": "Diesel",
+                        "mass": { "value": 21.0, "unit": "Kilograms" }
+                    }
+                }
+            }
+        ]
+    }"#;
+
+    fn profile_with_climb(climb: &str) -> String {
+        PROFILE.replacen(
+            "]",
+            &format!(r#"], "climb": {climb}, "descent": {CLIMB}"#),
+            1,
+        )
+    }
+
+    #[test]
+    fn climb_from_loaded_profile() {
+        let perf = Performance::from_reader(profile_with_climb(CLIMB).as_bytes())
+            .expect("profile should be valid");
+
+        let climb = perf.climb().expect("climb should be loaded");
+        let (_, time, _) = climb
+            .climb_to(VerticalDistance::Gnd, VerticalDistance::Altitude(5000), 0.0)
+            .expect("climb should be computed");
+
+        assert_eq!(time, Duration::m(10));
+        assert!(perf.descent().is_some());
+    }
+
+    #[test]
+    fn rejects_implausible_climb() {
+        let unsorted = r#"{
+            "table": [
+                {
+                    "level": { "Altitude": 5000 },
+                    "tas": { "value": 75.0, "unit": "Knots" },
+                    "vertical_rate": { "value": 500.0, "unit": "FeetPerMinute" },
+                    "ff": {
+                        "PerHour": {
+                            "fuel_type": "Diesel",
+                            "mass": { "value": 21.0, "unit": "Kilograms" }
+                        }
+                    }
+                },
+                {
+                    "level": { "Altitude": 3000 },
+                    "tas": { "value": 75.0, "unit": "Knots" },
+                    "vertical_rate": { "value": 500.0, "unit": "FeetPerMinute" },
+                    "ff": {
+                        "PerHour": {
+                            "fuel_type": "Diesel",
+                            "mass": { "value": 21.0, "unit": "Kilograms" }
+                        }
+                    }
+                }
+            ]
+        }"#;
+
+        for climb in [
+            r#"{ "table": [] }"#.to_string(),
+            unsorted.to_string(),
+            CLIMB.replace("500.0", "0.0"),
+            CLIMB.replace("75.0", "-75.0"),
+            CLIMB.replace(r#"{ "Altitude": 10000 }"#, r#"{ "Agl": 1000 }"#),
+        ] {
+            assert_eq!(
+                Performance::from_reader(profile_with_climb(&climb).as_bytes()),
+                Err(Error::ImplausibleValue),
+                "{climb}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_negative_speed() {
+        let profile = PROFILE.replace("107.0", "-107.0");
+
+        assert_eq!(
+            Performance::from_reader(profile.as_bytes()),
+            Err(Error::ImplausibleValue)
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Geometric helpers for geographic coordinates.
+//!
+//! Coordinates are in degrees with the longitude as `x` and the latitude as
+//! `y`. Planar geometry on those coordinates breaks at the antimeridian where
+//! the longitude wraps from 180° to -180°: A line from 179°E to 179°W would
+//! span across the entire globe. The helpers of this module _unwrap_
+//! longitudes, so that geometries crossing the antimeridian stay continuous.
+
+use std::borrow::Cow;
+
+use geo::{Coord, Distance, Geodesic, InterpolatePoint, LineString, Point, Polygon};
+use rstar::AABB;
+
+use crate::measurements::Length;
+
+/// Returns the coordinate at a latitude and longitude.
+///
+/// The latitude becomes `y` and the longitude `x` of the coordinate.
+pub(crate) fn coord_from_lat_lon(lat: f64, lon: f64) -> Coord<f64> {
+    Coord { x: lon, y: lat }
+}
+
+/// Returns the point at a latitude and longitude.
+///
+/// See [`coord_from_lat_lon`].
+pub(crate) fn point_from_lat_lon(lat: f64, lon: f64) -> Point<f64> {
+    Point(coord_from_lat_lon(lat, lon))
+}
+
+/// Returns the coordinates of `(latitude, longitude)` pairs.
+///
+/// Used to write test geometries in the order of a chart. See
+/// [`coord_from_lat_lon`].
+#[cfg(test)]
+pub(crate) fn coords_from_lat_lon_pairs(pairs: &[(f64, f64)]) -> Vec<Coord<f64>> {
+    pairs
+        .iter()
+        .map(|&(lat, lon)| coord_from_lat_lon(lat, lon))
+        .collect()
+}
+
+/// Returns the length of the geodesic from `start` to `end`.
+///
+/// Legs and the vertical profile measure distances with this function, so that
+/// distances along a route are consistent.
+pub(crate) fn geodesic_distance(start: Point<f64>, end: Point<f64>) -> Length {
+    Length::m(Geodesic.distance(start, end) as f32)
+}
+
+/// Returns the point at a `fraction` of the geodesic from `start` to `end`.
+///
+/// A fraction of 0 returns the `start` and 1 the `end`. The longitude of the
+/// returned point is within -180°..180°.
+pub(crate) fn intermediate_point(start: Point<f64>, end: Point<f64>, fraction: f64) -> Point<f64> {
+    Geodesic.point_at_ratio_between(start, end, fraction)
+}
+
+/// Returns the point halfway along the geodesic from `start` to `end`.
+///
+/// See [`intermediate_point`].
+pub(crate) fn midpoint(start: Point<f64>, end: Point<f64>) -> Point<f64> {
+    intermediate_point(start, end, 0.5)
+}
+
+/// Wraps a longitude into the range -180°..180°.
+pub(crate) fn wrap_longitude(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Unwraps the longitudes of a line string.
+///
+/// The first coordinate is shifted by a multiple of 360° to be within 180° of
+/// the `reference` longitude. Each following coordinate is shifted to be within
+/// 180° of its predecessor. Thus, the longitudes of the returned line string
+/// may lie outside of -180°..180°.
+pub(crate) fn unwrap_line_string(line: &LineString<f64>, reference: f64) -> LineString<f64> {
+    let mut prev = reference;
+
+    line.coords()
+        .map(|c| {
+            // shift by whole turns only, to keep unshifted longitudes exact
+            let x = c.x + ((prev - c.x) / 360.0).round() * 360.0;
+            prev = x;
+            Coord { x, y: c.y }
+        })
+        .collect()
+}
+
+/// Returns the polygon with unwrapped longitudes close to the `reference`
+/// longitude.
+///
+/// The polygon is only copied if it crosses the antimeridian or has to be
+/// shifted towards the reference. See [`unwrap_line_string`].
+pub(crate) fn unwrap_polygon(polygon: &Polygon<f64>, reference: f64) -> Cow<'_, Polygon<f64>> {
+    let exterior = polygon.exterior();
+
+    let is_unwrapped = exterior
+        .0
+        .first()
+        .is_none_or(|first| (first.x - reference).abs() <= 180.0)
+        && exterior
+            .lines()
+            .all(|line| (line.end.x - line.start.x).abs() <= 180.0);
+
+    if is_unwrapped {
+        return Cow::Borrowed(polygon);
+    }
+
+    let exterior = unwrap_line_string(exterior, reference);
+    let start = exterior.0.first().map_or(reference, |c| c.x);
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|interior| unwrap_line_string(interior, start))
+        .collect();
+
+    Cow::Owned(Polygon::new(exterior, interiors))
+}
+
+/// Splits an envelope with unwrapped longitudes into envelopes within
+/// -180°..180°.
+///
+/// An envelope that reaches beyond the antimeridian is split into the part
+/// east and west of the antimeridian.
+pub(crate) fn split_envelope(envelope: &AABB<Point<f64>>) -> Vec<AABB<Point<f64>>> {
+    let (lower, upper) = (envelope.lower(), envelope.upper());
+
+    if upper.x() - lower.x() >= 360.0 {
+        return vec![AABB::from_corners(
+            Point::new(-180.0, lower.y()),
+            Point::new(180.0, upper.y()),
+        )];
+    }
+
+    // shift the envelope such that its lower longitude is within -180°..180°
+    let shift = wrap_longitude(lower.x()) - lower.x();
+    let (west, east) = (lower.x() + shift, upper.x() + shift);
+
+    if east <= 180.0 {
+        vec![AABB::from_corners(
+            Point::new(west, lower.y()),
+            Point::new(east, upper.y()),
+        )]
+    } else {
+        vec![
+            AABB::from_corners(Point::new(west, lower.y()), Point::new(180.0, upper.y())),
+            AABB::from_corners(
+                Point::new(-180.0, lower.y()),
+                Point::new(east - 360.0, upper.y()),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latitude_is_y_and_longitude_is_x() {
+        assert_eq!(coord_from_lat_lon(53.6, 9.9), Coord { x: 9.9, y: 53.6 });
+        assert_eq!(point_from_lat_lon(53.6, 9.9), Point::new(9.9, 53.6));
+        assert_eq!(
+            coords_from_lat_lon_pairs(&[(53.6, 9.9), (-17.0, 179.5)]),
+            vec![Coord { x: 9.9, y: 53.6 }, Coord { x: 179.5, y: -17.0 }]
+        );
+    }
+
+    #[test]
+    fn midpoint_on_equator() {
+        let mid = midpoint(point_from_lat_lon(0.0, 10.0), point_from_lat_lon(0.0, 20.0));
+
+        assert!(mid.y().abs() < 1e-9);
+        assert!((mid.x() - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn intermediate_point_on_equator() {
+        let (start, end) = (point_from_lat_lon(0.0, 0.0), point_from_lat_lon(0.0, 90.0));
+
+        assert_eq!(intermediate_point(start, end, 0.0), start);
+        assert!((intermediate_point(start, end, 0.25).x() - 22.5).abs() < 1e-9);
+        assert!((intermediate_point(start, end, 1.0).x() - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn midpoint_across_antimeridian() {
+        let mid = midpoint(
+            point_from_lat_lon(0.0, 179.0),
+            point_from_lat_lon(0.0, -179.0),
+        );
+
+        assert!((mid.x().abs() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wraps_longitudes() {
+        assert_eq!(wrap_longitude(181.0), -179.0);
+        assert_eq!(wrap_longitude(-181.0), 179.0);
+        assert_eq!(wrap_longitude(9.5), 9.5);
+    }
+
+    #[test]
+    fn unwraps_line_crossing_antimeridian() {
+        let line = LineString::from(vec![(179.5, -17.0), (-179.5, -17.0)]);
+        let unwrapped = unwrap_line_string(&line, 179.5);
+
+        assert_eq!(unwrapped.0[0].x, 179.5);
+        assert_eq!(unwrapped.0[1].x, 180.5);
+    }
+
+    #[test]
+    fn keeps_polygon_not_crossing_antimeridian() {
+        let polygon = polygon![(53.0, 9.0), (53.0, 10.0), (54.0, 10.0), (53.0, 9.0)];
+        assert!(matches!(unwrap_polygon(&polygon, 9.5), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn splits_envelope_at_antimeridian() {
+        let envelope = AABB::from_corners(Point::new(179.0, -18.0), Point::new(181.0, -16.0));
+        let split = split_envelope(&envelope);
+
+        assert_eq!(
+            split,
+            vec![
+                AABB::from_corners(Point::new(179.0, -18.0), Point::new(180.0, -16.0)),
+                AABB::from_corners(Point::new(-180.0, -18.0), Point::new(-179.0, -16.0)),
+            ]
+        );
+    }
+}
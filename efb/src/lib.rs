@@ -108,10 +108,31 @@
 //! ## V
 //!
 //! - **VRP** Visual Reporting Point
+//!
+//! # Features
+//!
+//! The `std` feature is enabled by default. Without it, the library is
+//! `no_std` and provides only the [measurements], the [`VerticalDistance`] and
+//! the other core types like [`Fuel`] and [`Wind`], e.g. to do the math on an
+//! embedded device. The navigation data, route, flight planning and FMS
+//! require `std`.
+//!
+//! The `wmm` feature is enabled by default and computes the magnetic variation
+//! of fixes with the World Magnetic Model if the navigation data provides
+//! none. Without it, such fixes are oriented to true north.
+//!
+//! The `sync` feature shares the records of the navigation data through an
+//! [`Arc`](std::sync::Arc) instead of an [`Rc`](std::rc::Rc) to send
+//! navigation data and routes to other threads. The feature isn't additive:
+//! code that names `Rc` for the shared records stops compiling once any crate
+//! in the dependency graph enables it. Name [`nd::Shared`] instead.
 
 ////////////////////////////////////////////////////////////////////////////////
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -121,15 +142,27 @@ pub mod macros;
 mod core;
 pub use core::*;
 
-pub mod aircraft;
 pub mod error;
+#[cfg(not(feature = "std"))]
+mod float;
+pub mod measurements;
+
+#[cfg(feature = "std")]
+pub mod aircraft;
+#[cfg(feature = "std")]
 pub mod fc;
+#[cfg(feature = "std")]
 pub mod fms;
+#[cfg(feature = "std")]
 pub mod fp;
-pub mod measurements;
+#[cfg(feature = "std")]
+mod geom;
+#[cfg(feature = "std")]
 pub mod nd;
+#[cfg(feature = "std")]
 pub mod route;
 
+#[cfg(feature = "std")]
 pub mod prelude {
     pub use crate::aircraft::{Aircraft, AircraftBuilder, CGLimit, FuelTank, Station};
     pub use crate::core::{Fuel, FuelFlow, FuelType, VerticalDistance};
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::ops::Div;
+use core::ops::Div;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -22,6 +22,8 @@ use super::{
     constants, Duration, DurationUnit, Measurement, PhysicalQuantity, UnitOfMeasure, VerticalRate,
     VerticalRateUnit,
 };
+#[cfg(not(feature = "std"))]
+use crate::float::F32Ext;
 
 /// Altitude unit with _m_ as SI unit.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
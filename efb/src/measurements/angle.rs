@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::ops::Add;
+use core::ops::Add;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -138,7 +138,7 @@ mod tests {
         let west = Angle::t(270.0);
         assert_eq!(
             west,
-            Angle::from_si(1.5 * std::f32::consts::PI, AngleUnit::TrueNorth)
+            Angle::from_si(1.5 * core::f32::consts::PI, AngleUnit::TrueNorth)
         );
     }
 
@@ -157,7 +157,7 @@ mod tests {
         let west = Angle::t(-90.0);
         assert_eq!(west, Angle::t(270.0));
 
-        let south = Angle::rad(std::f32::consts::PI);
+        let south = Angle::rad(core::f32::consts::PI);
         assert_eq!(south, Angle::t(180.0));
     }
 }
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{constants, Measurement, PhysicalQuantity, UnitOfMeasure};
+
+const SQUARE_NAUTICAL_MILE_IN_SQUARE_METER: f32 =
+    constants::NAUTICAL_MILE_IN_METER * constants::NAUTICAL_MILE_IN_METER;
+const SQUARE_STATUTE_MILE_IN_SQUARE_METER: f32 =
+    constants::STATUTE_MILE_IN_METER * constants::STATUTE_MILE_IN_METER;
+
+/// Area with _m²_ as SI unit.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum AreaUnit {
+    SquareMeters,
+    SquareKilometers,
+    SquareNauticalMiles,
+    SquareStatuteMiles,
+}
+
+impl UnitOfMeasure<f32> for AreaUnit {
+    fn quantity() -> PhysicalQuantity {
+        PhysicalQuantity::Area
+    }
+
+    fn si() -> Self {
+        Self::SquareMeters
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::SquareMeters => "m²",
+            Self::SquareKilometers => "km²",
+            Self::SquareNauticalMiles => "NM²",
+            Self::SquareStatuteMiles => "SM²",
+        }
+    }
+
+    fn from_si(value: f32, to: &Self) -> f32 {
+        match to {
+            Self::SquareMeters => value,
+            Self::SquareKilometers => value / 1e6,
+            Self::SquareNauticalMiles => value / SQUARE_NAUTICAL_MILE_IN_SQUARE_METER,
+            Self::SquareStatuteMiles => value / SQUARE_STATUTE_MILE_IN_SQUARE_METER,
+        }
+    }
+
+    fn to_si(&self, value: &f32) -> f32 {
+        match self {
+            Self::SquareMeters => *value,
+            Self::SquareKilometers => value * 1e6,
+            Self::SquareNauticalMiles => value * SQUARE_NAUTICAL_MILE_IN_SQUARE_METER,
+            Self::SquareStatuteMiles => value * SQUARE_STATUTE_MILE_IN_SQUARE_METER,
+        }
+    }
+}
+
+pub type Area = Measurement<f32, AreaUnit>;
+
+impl Area {
+    /// Returns the area in square meters _m²_.
+    pub const fn sq_m(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: AreaUnit::SquareMeters,
+        }
+    }
+
+    /// Returns the area in square kilometers _km²_.
+    pub const fn sq_km(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: AreaUnit::SquareKilometers,
+        }
+    }
+
+    /// Returns the area in square nautical miles _NM²_.
+    pub const fn sq_nm(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: AreaUnit::SquareNauticalMiles,
+        }
+    }
+
+    /// Returns the area in square statute miles _SM²_.
+    pub const fn sq_sm(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: AreaUnit::SquareStatuteMiles,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sq_nm_to_sq_km() {
+        let area = Area::sq_nm(1.0).convert_to(AreaUnit::SquareKilometers);
+        assert_eq!(format!("{:.4}", area), "3.4299 km²");
+    }
+
+    #[test]
+    fn convert_area_round_trip() {
+        for unit in [
+            AreaUnit::SquareKilometers,
+            AreaUnit::SquareNauticalMiles,
+            AreaUnit::SquareStatuteMiles,
+        ] {
+            let area = Area::sq_m(1_000_000.0).convert_to(unit);
+            let round_trip = area.convert_to(AreaUnit::SquareMeters);
+            assert!((round_trip.value() - 1_000_000.0).abs() < 1.0, "{area}");
+        }
+    }
+}
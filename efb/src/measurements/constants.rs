@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -15,10 +15,17 @@
 
 pub const FEET_IN_METER: f32 = 0.3048;
 pub const INCH_IN_METER: f32 = 0.0254;
+pub const ISA_LAPSE_RATE_K_PER_M: f32 = 0.0065;
+pub const ISA_MSL_TEMPERATURE_K: f32 = 288.15;
+pub const ISA_TROPOPAUSE_M: f32 = 11_000.0;
 pub const KELVIN_IN_CELSIUS: f32 = 273.15;
+pub const METER_PER_SECONDS_IN_KILOMETERS_PER_HOUR: f32 = 3.6;
 pub const METER_PER_SECONDS_IN_KNOTS: f32 = 1.943844;
 pub const NAUTICAL_MILE_IN_METER: f32 = 1852.0;
 pub const STATUTE_MILE_IN_METER: f32 = 1609.344;
-pub const PI2: f32 = std::f32::consts::PI * 2.0;
+pub const SPEED_OF_SOUND_AT_ISA_MSL_MPS: f32 = 340.294;
+// speed of sound per square root of temperature in K, i.e. sqrt(γ·R) of dry air
+pub const SPEED_OF_SOUND_PER_SQRT_K: f32 = 20.0468;
+pub const PI2: f32 = core::f32::consts::PI * 2.0;
 pub const POUNDS_IN_KILOGRAMS: f32 = 0.4535924;
 pub const US_GALLON_IN_QUBIC_METER: f32 = 0.003785412;
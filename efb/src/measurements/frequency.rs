@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Measurement, PhysicalQuantity, UnitOfMeasure};
+
+/// Frequency with _Hz_ as SI unit.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum FrequencyUnit {
+    Hertz,
+    Kilohertz,
+    Megahertz,
+}
+
+impl UnitOfMeasure<f32> for FrequencyUnit {
+    fn quantity() -> PhysicalQuantity {
+        PhysicalQuantity::Frequency
+    }
+
+    fn si() -> Self {
+        Self::Hertz
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Hertz => "Hz",
+            Self::Kilohertz => "kHz",
+            Self::Megahertz => "MHz",
+        }
+    }
+
+    fn from_si(value: f32, to: &Self) -> f32 {
+        match to {
+            Self::Hertz => value,
+            Self::Kilohertz => value / 1e3,
+            Self::Megahertz => value / 1e6,
+        }
+    }
+
+    fn to_si(&self, value: &f32) -> f32 {
+        match self {
+            Self::Hertz => *value,
+            Self::Kilohertz => value * 1e3,
+            Self::Megahertz => value * 1e6,
+        }
+    }
+}
+
+pub type Frequency = Measurement<f32, FrequencyUnit>;
+
+impl Frequency {
+    /// Returns the frequency in Hertz _Hz_.
+    pub const fn hz(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: FrequencyUnit::Hertz,
+        }
+    }
+
+    /// Returns the frequency in Kilohertz _kHz_.
+    pub const fn khz(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: FrequencyUnit::Kilohertz,
+        }
+    }
+
+    /// Returns the frequency in Megahertz _MHz_.
+    pub const fn mhz(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: FrequencyUnit::Megahertz,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn khz_to_mhz() {
+        let freq = Frequency::khz(123125.0).convert_to(FrequencyUnit::Megahertz);
+        assert_eq!(format!("{:.3}", freq), "123.125 MHz");
+    }
+}
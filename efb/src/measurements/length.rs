@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,13 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::ops::{Div, Mul};
+use core::ops::{Div, Mul};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::{constants, SpeedUnit};
 use super::{Duration, DurationUnit, Measurement, PhysicalQuantity, Speed, UnitOfMeasure};
+#[cfg(not(feature = "std"))]
+use crate::float::F32Ext;
 
 /// Length unit with _m_ as SI unit.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -205,6 +207,18 @@ mod tests {
         assert_eq!(Length::nm(1.0), Length::m(1852.0));
     }
 
+    #[test]
+    fn display_in_unit() {
+        let dist = Length::nm(10.0);
+
+        assert_eq!(format!("{:.1}", dist), "10.0 NM");
+        assert_eq!(
+            format!("{:.1}", dist.in_unit(LengthUnit::Kilometers)),
+            "18.5 km"
+        );
+        assert_eq!(dist.unit(), &LengthUnit::NauticalMiles);
+    }
+
     #[test]
     fn div_length_by_speed() {
         let time = Length::nm(1.0) / Speed::kt(1.0);
@@ -13,11 +13,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::iter::Sum;
-use std::ops::{Add, Div, Mul, Sub};
+use alloc::format;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -97,6 +98,47 @@ where
             unit: other,
         }
     }
+
+    /// Displays the measurement in another unit.
+    ///
+    /// The measurement itself keeps its unit. This allows to choose the unit
+    /// when rendering e.g. a navigation log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::measurements::{Length, LengthUnit};
+    /// let dist = Length::nm(10.0);
+    /// assert_eq!(format!("{:.1}", dist.in_unit(LengthUnit::Kilometers)), "18.5 km");
+    /// ```
+    pub fn in_unit(&self, unit: U) -> InUnit<'_, T, U> {
+        InUnit {
+            measurement: self,
+            unit,
+        }
+    }
+}
+
+/// A measurement displayed in another unit.
+///
+/// See [`Measurement::in_unit`].
+#[derive(Copy, Clone, Debug)]
+pub struct InUnit<'a, T, U>
+where
+    U: UnitOfMeasure<T>,
+{
+    measurement: &'a Measurement<T, U>,
+    unit: U,
+}
+
+impl<T, U> fmt::Display for InUnit<'_, T, U>
+where
+    T: fmt::Display,
+    U: UnitOfMeasure<T> + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.measurement.convert_to(self.unit), f)
+    }
 }
 
 macro_rules! abs_impl {
@@ -130,7 +172,7 @@ where
 
 impl<T, U> fmt::Display for Measurement<T, U>
 where
-    T: std::fmt::Display,
+    T: core::fmt::Display,
     U: UnitOfMeasure<T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
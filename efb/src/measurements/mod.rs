@@ -44,9 +44,11 @@
 
 mod altitude;
 mod angle;
+mod area;
 mod constants;
 mod density;
 mod duration;
+mod frequency;
 mod length;
 mod mass;
 mod measurement;
@@ -59,8 +61,10 @@ mod volume;
 
 pub use altitude::{Altitude, AltitudeUnit};
 pub use angle::{Angle, AngleUnit};
+pub use area::{Area, AreaUnit};
 pub use density::{Density, DensityUnit};
 pub use duration::{Duration, DurationUnit};
+pub use frequency::{Frequency, FrequencyUnit};
 pub use length::{Length, LengthUnit};
 pub use mass::{Mass, MassUnit};
 pub use measurement::*;
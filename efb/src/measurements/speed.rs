@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,14 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::constants;
-use super::{Measurement, PhysicalQuantity, UnitOfMeasure};
+use super::{Length, Measurement, PhysicalQuantity, Pressure, Temperature, UnitOfMeasure};
 use crate::error::Error;
+#[cfg(not(feature = "std"))]
+use crate::float::F32Ext;
+use crate::VerticalDistance;
 
 /// Speed unit with _m/s_ as SI unit.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -29,7 +32,10 @@ use crate::error::Error;
 pub enum SpeedUnit {
     MetersPerSecond,
     Knots,
+    /// Mach number, converted at the speed of sound at ISA mean sea level.
+    /// Use [`Speed::to_tas`] to get the true airspeed at a temperature.
     Mach,
+    KilometersPerHour,
 }
 
 impl UnitOfMeasure<f32> for SpeedUnit {
@@ -46,6 +52,7 @@ impl UnitOfMeasure<f32> for SpeedUnit {
             Self::MetersPerSecond => "mps",
             Self::Knots => "kt",
             Self::Mach => "mach",
+            Self::KilometersPerHour => "km/h",
         }
     }
 
@@ -53,7 +60,8 @@ impl UnitOfMeasure<f32> for SpeedUnit {
         match to {
             Self::MetersPerSecond => value,
             Self::Knots => value * constants::METER_PER_SECONDS_IN_KNOTS,
-            Self::Mach => unimplemented!(),
+            Self::Mach => value / constants::SPEED_OF_SOUND_AT_ISA_MSL_MPS,
+            Self::KilometersPerHour => value * constants::METER_PER_SECONDS_IN_KILOMETERS_PER_HOUR,
         }
     }
 
@@ -61,7 +69,8 @@ impl UnitOfMeasure<f32> for SpeedUnit {
         match self {
             Self::MetersPerSecond => *value,
             Self::Knots => value / constants::METER_PER_SECONDS_IN_KNOTS,
-            Self::Mach => unimplemented!(),
+            Self::Mach => value * constants::SPEED_OF_SOUND_AT_ISA_MSL_MPS,
+            Self::KilometersPerHour => value / constants::METER_PER_SECONDS_IN_KILOMETERS_PER_HOUR,
         }
     }
 }
@@ -89,6 +98,45 @@ impl Speed {
             unit: SpeedUnit::Mach,
         }
     }
+
+    pub fn kmh(value: f32) -> Self {
+        Measurement {
+            value,
+            unit: SpeedUnit::KilometersPerHour,
+        }
+    }
+
+    /// Returns the true airspeed of the calibrated airspeed `cas` at the
+    /// `pressure_altitude` and outside air temperature `oat`.
+    ///
+    /// The TAS is corrected for the density of the air but not for its
+    /// compressibility, which is accurate for the speeds of light aircraft.
+    /// Returns `None` if the pressure altitude is unlimited.
+    pub fn cas_to_tas(
+        cas: Speed,
+        pressure_altitude: VerticalDistance,
+        oat: Temperature,
+    ) -> Option<Speed> {
+        let pressure = pressure_altitude.to_pressure(Pressure::STD, Length::ft(0.0))?;
+        let density_ratio =
+            (pressure / Pressure::STD) * (constants::ISA_MSL_TEMPERATURE_K / oat.to_si());
+
+        Some(cas / density_ratio.sqrt())
+    }
+
+    /// Returns the true airspeed in knots of a Mach number at the outside air
+    /// `temperature`.
+    ///
+    /// Speeds in other units are returned unchanged.
+    pub fn to_tas(&self, temperature: Temperature) -> Self {
+        match self.unit {
+            SpeedUnit::Mach => {
+                let a = constants::SPEED_OF_SOUND_PER_SQRT_K * temperature.to_si().sqrt();
+                Speed::mps(self.value * a).convert_to(SpeedUnit::Knots)
+            }
+            _ => *self,
+        }
+    }
 }
 
 impl FromStr for Speed {
@@ -124,13 +172,60 @@ impl FromStr for Speed {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::measurements::Altitude;
 
     #[test]
     fn from_icao_4444_2_str() {
         assert_eq!("K0360".parse::<Speed>(), Ok(Speed::mps(100.0)));
         assert_eq!("N0485".parse::<Speed>(), Ok(Speed::kt(485.0)));
-        // TODO: Implement conversion of Mach to SI.
-        // assert_eq!("M082".parse::<Speed>(), Ok(Speed::mach(0.82)));
+        assert_eq!("M082".parse::<Speed>(), Ok(Speed::mach(0.82)));
         assert_eq!("M08".parse::<Speed>(), Err(Error::UnexpectedString));
     }
+
+    #[test]
+    fn mach_to_tas_at_isa_level() {
+        let mach: Speed = "M082".parse().expect("Mach should parse");
+        let tas = mach.to_tas(Temperature::isa(Altitude::ft(35000.0)));
+
+        // 0.82 of the speed of sound at -56 °C
+        assert_eq!(tas.unit(), &SpeedUnit::Knots);
+        assert!((tas.value() - 472.7).abs() < 0.5, "{tas}");
+    }
+
+    #[test]
+    fn cas_to_tas_at_sea_level() {
+        let tas = Speed::cas_to_tas(
+            Speed::kt(100.0),
+            VerticalDistance::Gnd,
+            Temperature::c(15.0),
+        )
+        .expect("TAS should be computed");
+
+        assert!((tas.value() - 100.0).abs() < 0.1, "{tas}");
+    }
+
+    #[test]
+    fn cas_to_tas_at_altitude() {
+        let oat = Temperature::isa(Altitude::ft(10000.0));
+        let tas = Speed::cas_to_tas(Speed::kt(100.0), VerticalDistance::Fl(100), oat)
+            .expect("TAS should be computed");
+
+        // the rule of thumb adds 2 % per 1000 ft
+        assert!((tas.value() - 116.0).abs() < 1.0, "{tas}");
+        assert_eq!(
+            Speed::cas_to_tas(Speed::kt(100.0), VerticalDistance::Unlimited, oat),
+            None
+        );
+    }
+
+    #[test]
+    fn display_in_unit() {
+        let tas = Speed::kt(100.0);
+
+        assert_eq!(tas.to_string(), "100 kt");
+        assert_eq!(
+            format!("{:.0}", tas.in_unit(SpeedUnit::KilometersPerHour)),
+            "185 km/h"
+        );
+    }
 }
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -17,7 +17,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::constants;
-use super::{Measurement, PhysicalQuantity, UnitOfMeasure};
+use super::{Altitude, Measurement, PhysicalQuantity, UnitOfMeasure};
 
 /// Temperature with _K_ as SI unit.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -86,6 +86,16 @@ impl Temperature {
             unit: TemperatureUnit::Fahrenheit,
         }
     }
+
+    /// Returns the temperature of the International Standard Atmosphere at
+    /// the pressure `altitude`.
+    ///
+    /// The temperature decreases by 6.5 K per km from 15 °C at mean sea level
+    /// and stays at -56.5 °C above the tropopause at 11 km.
+    pub fn isa(altitude: Altitude) -> Self {
+        let m = altitude.to_si().min(constants::ISA_TROPOPAUSE_M);
+        Self::k(constants::ISA_MSL_TEMPERATURE_K - constants::ISA_LAPSE_RATE_K_PER_M * m)
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -16,9 +16,11 @@
 /// Physical quantities that can be measured with units.
 pub enum PhysicalQuantity {
     Angle,
+    Area,
     Length,
     Density,
     Duration,
+    Frequency,
     Mass,
     Pressure,
     Speed,
@@ -151,7 +151,7 @@ mod tests {
     fn test_effective_dates() {
         // TODO: Add more known cycles.
         let cycle = AiracCycle::new(25, 9);
-        assert_eq!(cycle.effective_date(), NaiveDate::from_ymd_opt(2025, 09, 4));
+        assert_eq!(cycle.effective_date(), NaiveDate::from_ymd_opt(2025, 9, 4));
         assert_eq!(cycle.end_date(), NaiveDate::from_ymd_opt(2025, 10, 1));
     }
 
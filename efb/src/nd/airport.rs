@@ -31,16 +31,97 @@ pub struct Airport {
     pub(crate) mag_var: Option<MagneticVariation>,
     pub(crate) elevation: VerticalDistance,
     pub(crate) runways: Vec<Runway>,
+    pub(crate) gates: Vec<Gate>,
+    pub(crate) frequencies: Vec<CommunicationFrequency>,
     pub(crate) location: Option<LocationIndicator>,
     pub(crate) cycle: Option<AiracCycle>,
 }
 
+impl Airport {
+    /// Returns the gates and parking positions of the airport.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Returns the communication frequencies of the airport.
+    pub fn frequencies(&self) -> &[CommunicationFrequency] {
+        &self.frequencies
+    }
+
+    /// Returns the opposite direction of the `runway`, e.g. `27R` for `09L`.
+    ///
+    /// Each runway direction is a separate [`Runway`] with its own bearing and
+    /// declared distances. This pairs both directions of a physical runway.
+    pub fn reciprocal(&self, runway: &Runway) -> Option<&Runway> {
+        let designator = runway.reciprocal_designator()?;
+        self.runways
+            .iter()
+            .find(|rwy| rwy.matches_designator(&designator))
+    }
+}
+
 impl Fix for Airport {
     fn ident(&self) -> String {
         self.icao_ident.clone()
     }
 
+    fn label(&self) -> String {
+        if self.name.is_empty() {
+            self.ident()
+        } else {
+            format!("{} — {}", self.ident(), self.name)
+        }
+    }
+
     fn coordinate(&self) -> Point<f64> {
         self.coordinate
     }
+
+    /// Returns the magnetic variation of the navigation data or the one
+    /// computed by the World Magnetic Model if the data provides none.
+    fn mag_var(&self) -> MagneticVariation {
+        self.mag_var.unwrap_or_else(|| self.coordinate.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::measurements::Angle;
+    use crate::nd::fixtures::{airport, runway};
+
+    use super::*;
+
+    #[test]
+    fn label_with_ident_and_name() {
+        let eddh = Airport {
+            name: String::from("HAMBURG"),
+            ..airport("EDDH", Point::new(9.991667, 53.630278))
+        };
+
+        assert_eq!(eddh.label(), "EDDH — HAMBURG");
+    }
+
+    #[test]
+    fn reciprocal_runway_has_opposite_bearing() {
+        let runway = |designator: &str, bearing: f32| Runway {
+            bearing: Angle::m(bearing),
+            ..runway(designator)
+        };
+
+        let airport = Airport {
+            runways: vec![
+                runway("07L", 69.0),
+                runway("25R", 249.0),
+                runway("18", 180.0),
+            ],
+            ..airport("EDDF", Point::new(8.570556, 50.033333))
+        };
+
+        let rwy07l = &airport.runways[0];
+        let rwy25r = airport.reciprocal(rwy07l).expect("25R should be found");
+        assert_eq!(rwy25r.designator, "25R");
+        assert_eq!(rwy25r.bearing, Angle::m(249.0));
+        assert_eq!(airport.reciprocal(rwy25r), Some(rwy07l));
+        assert_eq!(airport.reciprocal(&airport.runways[2]), None);
+    }
 }
@@ -18,8 +18,20 @@ use std::fmt::{Display, Formatter, Result};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use geo::{Area as _, BooleanOps, GeodesicArea, Intersects, MapCoords, Simplify, Validation};
+
+use crate::error::Error;
+use crate::geom::{unwrap_polygon, wrap_longitude};
+use crate::measurements::{Area, AreaUnit, Length, Pressure};
 use crate::VerticalDistance;
 
+/// Length of one degree of latitude in meters.
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// Minimum overlap of two polygons relative to their combined area, for the
+/// airspaces to be considered duplicates.
+const DUPLICATE_OVERLAP: f64 = 0.95;
+
 /// ICAO Airspace Classification (ICAO Annex 11, Chapter 2).
 ///
 /// Defines the rules governing IFR/VFR operations, separation services,
@@ -65,11 +77,46 @@ pub enum AirspaceType {
     RadarZone,
 }
 
+/// Path along an airspace boundary to the next point.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BoundaryPath {
+    /// Circle around the arc center.
+    Circle,
+    /// Great circle to the next point.
+    GreatCircle,
+    /// Rhumb line to the next point.
+    RhumbLine,
+    /// Clockwise arc around the arc center.
+    ClockwiseArc,
+    /// Counter-clockwise arc around the arc center.
+    CounterClockwiseArc,
+}
+
+/// Summary of a source record describing a segment of an airspace boundary.
+///
+/// The summaries are kept on the [`Airspace`] to inspect the source of a
+/// polygon that doesn't look right.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoundaryRecordSummary {
+    pub path: BoundaryPath,
+    /// The point at which the segment ends, or the arc center of a circle.
+    pub end_point: geo::Point<f64>,
+    pub arc_center: Option<geo::Point<f64>>,
+    pub arc_radius: Option<Length>,
+}
+
 /// Airspace.
 ///
 /// The airspace has a structural or special-use [`airspace_type`](Self::airspace_type)
 /// and an optional ICAO [`classification`](Self::classification). It is enclosed
 /// by the `polygon` and ranges from the `floor` to `ceiling` vertically.
+///
+/// The [`raw_boundary`](Self::raw_boundary()) summarizes the records the
+/// polygon was built from, if the source format describes the boundary by
+/// records and keeping them was requested when loading.
 #[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Airspace {
@@ -79,6 +126,180 @@ pub struct Airspace {
     pub ceiling: VerticalDistance,
     pub floor: VerticalDistance,
     pub polygon: geo::Polygon<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) raw_boundary: Option<Vec<BoundaryRecordSummary>>,
+}
+
+impl Airspace {
+    /// Creates an airspace without raw boundary, e.g. a user defined airspace
+    /// that isn't loaded from navigation data.
+    pub fn new(
+        name: String,
+        airspace_type: AirspaceType,
+        classification: Option<AirspaceClassification>,
+        ceiling: VerticalDistance,
+        floor: VerticalDistance,
+        polygon: geo::Polygon<f64>,
+    ) -> Self {
+        Self {
+            name,
+            airspace_type,
+            classification,
+            ceiling,
+            floor,
+            polygon,
+            raw_boundary: None,
+        }
+    }
+
+    /// Returns the summaries of the records the polygon was built from.
+    ///
+    /// The summaries are only kept for ARINC 424 airspaces if requested by
+    /// [`Arinc424Options::keep_raw_boundary`].
+    ///
+    /// [`Arinc424Options::keep_raw_boundary`]: crate::nd::Arinc424Options::keep_raw_boundary
+    pub fn raw_boundary(&self) -> Option<&[BoundaryRecordSummary]> {
+        self.raw_boundary.as_deref()
+    }
+
+    /// Returns the area of the airspace.
+    ///
+    /// The area is computed on the WGS84 ellipsoid and excludes any holes of
+    /// the airspace's polygon.
+    pub fn area(&self) -> Area {
+        Area::sq_m(self.polygon.geodesic_area_unsigned() as f32)
+            .convert_to(AreaUnit::SquareKilometers)
+    }
+
+    /// Returns `true` if the airspace's polygon is simple.
+    ///
+    /// A simple polygon has rings of at least three distinct points that
+    /// neither intersect themselves nor each other. Containment checks are
+    /// unreliable for polygons that aren't simple.
+    pub fn is_simple(&self) -> bool {
+        self.check_simple().is_ok()
+    }
+
+    /// Returns an [`Error::InvalidAirspace`] with the reason if the polygon
+    /// isn't simple.
+    pub(crate) fn check_simple(&self) -> std::result::Result<(), Error> {
+        let reference = self.polygon.exterior().0.first().map_or(0.0, |c| c.x);
+
+        unwrap_polygon(&self.polygon, reference)
+            .check_validation()
+            .map_err(|e| Error::InvalidAirspace {
+                name: self.name.clone(),
+                reason: e.to_string(),
+            })
+    }
+
+    /// Returns the airspace with a simplified polygon.
+    ///
+    /// The polygon's rings are simplified with the Ramer-Douglas-Peucker
+    /// algorithm, dropping points that deviate less than the `tolerance` from
+    /// the simplified outline. The tolerance is applied in degrees of latitude,
+    /// which is conservative for longitudes away from the equator. Rings that
+    /// would collapse to less than a triangle are kept as they are.
+    ///
+    /// The algorithm doesn't preserve the topology and may let the rings of a
+    /// concave polygon cross. The airspace is returned unchanged in that case.
+    pub fn simplified(&self, tolerance: Length) -> Airspace {
+        let epsilon = tolerance.to_si() as f64 / METERS_PER_DEGREE;
+        let reference = self.polygon.exterior().0.first().map_or(0.0, |c| c.x);
+        let polygon = unwrap_polygon(&self.polygon, reference);
+
+        let simplify = |ring: &geo::LineString<f64>| {
+            let simplified = ring.simplify(epsilon);
+            if simplified.0.len() < 4 {
+                ring.clone()
+            } else {
+                simplified
+            }
+        };
+
+        let polygon = geo::Polygon::new(
+            simplify(polygon.exterior()),
+            polygon.interiors().iter().map(simplify).collect(),
+        );
+
+        if !polygon.is_valid() {
+            return self.clone();
+        }
+
+        let polygon = polygon.map_coords(|c| geo::Coord {
+            x: if c.x.abs() > 180.0 {
+                wrap_longitude(c.x)
+            } else {
+                c.x
+            },
+            y: c.y,
+        });
+
+        Airspace {
+            name: self.name.clone(),
+            airspace_type: self.airspace_type,
+            classification: self.classification,
+            ceiling: self.ceiling,
+            floor: self.floor,
+            polygon,
+            raw_boundary: self.raw_boundary.clone(),
+        }
+    }
+
+    /// Returns `true` if the airspace overlaps the other airspace horizontally
+    /// and vertically.
+    ///
+    /// The vertical limits are compared at standard pressure and sea level
+    /// elevation, since the terrain below the airspaces is unknown. Airspaces
+    /// that only touch at their vertical limits don't overlap.
+    pub fn overlaps(&self, other: &Airspace) -> bool {
+        let (floor, ceiling) = self.vertical_limits();
+        let (other_floor, other_ceiling) = other.vertical_limits();
+
+        if floor >= other_ceiling || other_floor >= ceiling {
+            return false;
+        }
+
+        let reference = self.polygon.exterior().0.first().map_or(0.0, |c| c.x);
+        let a = unwrap_polygon(&self.polygon, reference);
+        let b = unwrap_polygon(&other.polygon, reference);
+
+        a.intersects(b.as_ref())
+    }
+
+    /// Returns the floor and ceiling as altitude in meters with an unlimited
+    /// limit being infinite.
+    fn vertical_limits(&self) -> (f32, f32) {
+        let to_si = |vd: &VerticalDistance| {
+            vd.to_msl(Pressure::STD, Length::ft(0.0))
+                .map_or(f32::INFINITY, |alt| alt.to_si())
+        };
+
+        (to_si(&self.floor), to_si(&self.ceiling))
+    }
+
+    /// Returns `true` if the other airspace describes the same airspace.
+    ///
+    /// Both airspaces need to share the name, type and classification, and
+    /// their polygons must overlap almost entirely. This is usually the case if
+    /// the same airspace is provided by multiple sources.
+    pub(crate) fn is_duplicate(&self, other: &Airspace) -> bool {
+        if self.name != other.name
+            || self.airspace_type != other.airspace_type
+            || self.classification != other.classification
+        {
+            return false;
+        }
+
+        let reference = self.polygon.exterior().0.first().map_or(0.0, |c| c.x);
+        let a = unwrap_polygon(&self.polygon, reference);
+        let b = unwrap_polygon(&other.polygon, reference);
+
+        let intersection = a.intersection(b.as_ref()).unsigned_area();
+        let union = a.unsigned_area() + b.unsigned_area() - intersection;
+
+        union > 0.0 && intersection / union >= DUPLICATE_OVERLAP
+    }
 }
 
 impl Display for AirspaceClassification {
@@ -127,3 +348,128 @@ impl Display for Airspace {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nd::fixtures;
+
+    fn airspace(floor: VerticalDistance, ceiling: VerticalDistance) -> Airspace {
+        Airspace {
+            airspace_type: AirspaceType::CTR,
+            ceiling,
+            floor,
+            ..fixtures::airspace(
+                "TEST",
+                polygon![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)],
+            )
+        }
+    }
+
+    #[test]
+    fn area_of_one_degree_square_at_equator() {
+        let airspace = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+
+        // 111.3 km along the equator and 110.6 km along the meridian
+        let area = airspace.area();
+        assert_eq!(area.unit(), &AreaUnit::SquareKilometers);
+        assert!((area.value() - 12_309.0).abs() < 10.0, "{area}");
+    }
+
+    #[test]
+    fn self_intersecting_airspace_is_not_simple() {
+        let mut ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+        assert!(ctr.is_simple());
+
+        // a bow tie crossing at its center
+        ctr.polygon = polygon![(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0), (0.0, 0.0)];
+        assert!(!ctr.is_simple());
+        assert!(matches!(
+            ctr.check_simple(),
+            Err(Error::InvalidAirspace { name, .. }) if name == "TEST"
+        ));
+    }
+
+    #[test]
+    fn simplified_circle_keeps_area() {
+        let mut ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+
+        // a circle with a radius of about 10 km and a point every degree
+        let radius = 10_000.0 / METERS_PER_DEGREE;
+        ctr.polygon = geo::Polygon::new(
+            (0..=360)
+                .map(|deg| {
+                    let angle = (deg as f64).to_radians();
+                    (radius * angle.cos(), radius * angle.sin())
+                })
+                .collect(),
+            vec![],
+        );
+
+        let tolerance = Length::m(100.0);
+        let simplified = ctr.simplified(tolerance);
+
+        assert!(simplified.polygon.exterior().0.len() < ctr.polygon.exterior().0.len());
+
+        // the outline moves at most by the tolerance along its perimeter
+        let perimeter_km = 2.0 * std::f32::consts::PI * 10.0;
+        let max_deviation = perimeter_km * tolerance.to_si() / 1000.0;
+        let deviation = (ctr.area().value() - simplified.area().value()).abs();
+        assert!(deviation < max_deviation, "{deviation} km²");
+    }
+
+    #[test]
+    fn simplified_concave_airspace_stays_simple() {
+        let mut ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+
+        // a square with a narrow notch from the top that reaches below a
+        // small dent of the bottom edge, which the simplification drops
+        ctr.polygon = polygon![
+            (0.0, 0.0),
+            (0.05, -0.002),
+            (0.1, 0.0),
+            (0.1, 0.1),
+            (0.052, 0.1),
+            (0.05, -0.001),
+            (0.048, 0.1),
+            (0.0, 0.1),
+            (0.0, 0.0)
+        ];
+        assert!(ctr.is_simple());
+
+        let simplified = ctr.simplified(Length::m(300.0));
+
+        assert!(simplified.is_simple());
+        assert_eq!(simplified.polygon, ctr.polygon);
+    }
+
+    #[test]
+    fn overlapping_airspaces() {
+        let ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+        let tma = airspace(VerticalDistance::Altitude(1500), VerticalDistance::Fl(65));
+
+        assert!(ctr.overlaps(&tma));
+        assert!(tma.overlaps(&ctr));
+    }
+
+    #[test]
+    fn vertically_separated_airspaces_dont_overlap() {
+        let ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+        let tma = airspace(
+            VerticalDistance::Altitude(2500),
+            VerticalDistance::Unlimited,
+        );
+
+        assert!(!ctr.overlaps(&tma));
+        assert!(!tma.overlaps(&ctr));
+    }
+
+    #[test]
+    fn laterally_separated_airspaces_dont_overlap() {
+        let ctr = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+        let mut other = airspace(VerticalDistance::Gnd, VerticalDistance::Altitude(2500));
+        other.polygon = polygon![(2.0, 2.0), (2.0, 3.0), (3.0, 3.0), (3.0, 2.0), (2.0, 2.0)];
+
+        assert!(!ctr.overlaps(&other));
+    }
+}
@@ -14,7 +14,6 @@
 // limitations under the License.
 
 use std::collections::{HashMap, HashSet};
-use std::rc::Rc;
 
 use super::index::{AirspaceIndex, NavAidIndex};
 use super::*;
@@ -26,8 +25,10 @@ use super::*;
 pub struct NavigationDataBuilder {
     airports: HashMap<String, Airport>,
     runways: HashMap<String, Vec<Runway>>,
-    airspaces: Vec<Rc<Airspace>>,
-    waypoints: Vec<Rc<Waypoint>>,
+    gates: HashMap<String, Vec<Gate>>,
+    frequencies: HashMap<String, Vec<CommunicationFrequency>>,
+    airspaces: Vec<Shared<Airspace>>,
+    waypoints: Vec<Shared<Waypoint>>,
     terminal_waypoints: TerminalWaypoints,
     locations: HashSet<LocationIndicator>,
     cycle: Option<AiracCycle>,
@@ -61,11 +62,25 @@ impl NavigationDataBuilder {
             }
         });
 
+        // add unassigned gates to airports
+        self.gates.iter_mut().for_each(|(ident, gates)| {
+            if let Some(arpt) = self.airports.get_mut(ident) {
+                arpt.gates.append(gates);
+            }
+        });
+
+        // add unassigned frequencies to airports
+        self.frequencies.iter_mut().for_each(|(ident, freqs)| {
+            if let Some(arpt) = self.airports.get_mut(ident) {
+                arpt.frequencies.append(freqs);
+            }
+        });
+
         // Build spatial index for airspaces
         let airspace_index = AirspaceIndex::new(self.airspaces.iter());
 
-        // Convert airports to Rc and collect
-        let airports: Vec<Rc<Airport>> = self.airports.into_values().map(Rc::new).collect();
+        // Convert airports to Shared and collect
+        let airports: Vec<Shared<Airport>> = self.airports.into_values().map(Shared::new).collect();
 
         // Build spatial index for point-based navaids
         let navaid_index = NavAidIndex::new(airports.iter(), self.waypoints.iter());
@@ -99,20 +114,44 @@ impl NavigationDataBuilder {
         }
     }
 
+    pub fn add_gate(&mut self, ident: String, gate: Gate) {
+        match self.airports.get_mut(&ident) {
+            Some(arpt) => arpt.gates.push(gate),
+            // in case we have already a gate but no airport
+            None => self.gates.entry(ident).or_default().push(gate),
+        }
+    }
+
+    pub fn add_frequency(&mut self, ident: String, freq: CommunicationFrequency) {
+        match self.airports.get_mut(&ident) {
+            Some(arpt) => arpt.frequencies.push(freq),
+            // in case we have already a frequency but no airport
+            None => self.frequencies.entry(ident).or_default().push(freq),
+        }
+    }
+
+    /// Adds the airspace and collects an error if its polygon isn't simple.
+    ///
+    /// The airspace is added anyway, since it is still depicted correctly
+    /// in most cases.
     pub fn add_airspace(&mut self, airspace: Airspace) {
-        self.airspaces.push(Rc::new(airspace));
+        if let Err(e) = airspace.check_simple() {
+            self.errors.push(e);
+        }
+
+        self.airspaces.push(Shared::new(airspace));
     }
 
     pub fn add_waypoint(&mut self, wp: Waypoint) {
         add_navaid!(self, wp);
         match &wp.region {
-            Region::Enroute => self.waypoints.push(Rc::new(wp)),
+            Region::Enroute => self.waypoints.push(Shared::new(wp)),
             Region::TerminalArea(ident) => {
                 let ident = str::from_utf8(ident).expect("ident should be valid UTF-8");
                 self.terminal_waypoints
                     .entry(ident.to_string())
                     .or_default()
-                    .push(Rc::new(wp));
+                    .push(Shared::new(wp));
             }
         }
     }
@@ -136,6 +175,7 @@ impl NavigationDataBuilder {
         self
     }
 
+    #[cfg(feature = "sqlite")]
     pub(crate) fn with_partition_id(mut self, id: u64) -> Self {
         self.partition_id = id;
         self
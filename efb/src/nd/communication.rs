@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::measurements::Frequency;
+
+/// Type of communication service provided on a frequency.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CommunicationType {
+    Approach,
+    /// Automatic Terminal Information Service.
+    Atis,
+    ClearanceDelivery,
+    Departure,
+    Ground,
+    Information,
+    Radio,
+    Tower,
+    Unicom,
+    /// Any other service with its ARINC 424 communication type code.
+    Other(String),
+}
+
+/// Communication frequency of an airport.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationFrequency {
+    pub comm_type: CommunicationType,
+    pub frequency: Frequency,
+    pub callsign: String,
+}
+
+impl fmt::Display for CommunicationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Approach => write!(f, "APP"),
+            Self::Atis => write!(f, "ATIS"),
+            Self::ClearanceDelivery => write!(f, "DEL"),
+            Self::Departure => write!(f, "DEP"),
+            Self::Ground => write!(f, "GND"),
+            Self::Information => write!(f, "INFO"),
+            Self::Radio => write!(f, "RADIO"),
+            Self::Tower => write!(f, "TWR"),
+            Self::Unicom => write!(f, "UNICOM"),
+            Self::Other(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl fmt::Display for CommunicationFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:.3}", self.callsign, self.frequency)
+    }
+}
@@ -19,13 +19,14 @@ use arinc424::fields::BoundaryPath;
 use arinc424::records::{ControlledAirspace, RestrictiveAirspace};
 use geo::{Bearing, Destination, Geodesic, Point};
 
-use super::fields::parse_classification;
+use super::fields::{lat_lon_to_point, parse_classification};
+use super::Arinc424Options;
 use crate::measurements::{Angle, Length};
-use crate::nd::{Airspace, AirspaceClassification, AirspaceType};
+use crate::nd::{Airspace, AirspaceClassification, AirspaceType, BoundaryRecordSummary};
 use crate::VerticalDistance;
 
-/// Number of points to interpolate per 90 degrees of arc.
-const ARC_POINTS_PER_QUADRANT: usize = 6;
+/// Default number of points to interpolate per 90 degrees of arc.
+pub(super) const ARC_POINTS_PER_QUADRANT: usize = 6;
 
 /// A boundary segment representing the path from one point to another.
 #[derive(Debug)]
@@ -44,8 +45,11 @@ struct BoundarySegment {
 ///
 /// ARINC 424 airspaces are defined as a sequence of records, each describing a
 /// boundary segment. This builder accumulates segments and converts them into
-/// a polygon when finalized.
-#[derive(Debug, Default)]
+/// a polygon when finalized. An airspace made up of multiple disjoint parts
+/// repeats the sequence for each part, identified by the same ICAO code,
+/// airspace center and multiple code. Arcs and circles are interpolated with a number
+/// of points per 90 degrees of arc.
+#[derive(Debug)]
 pub struct AirspaceBuilder {
     name: Option<String>,
     airspace_type: Option<AirspaceType>,
@@ -54,26 +58,53 @@ pub struct AirspaceBuilder {
     floor: Option<VerticalDistance>,
     segments: Vec<BoundarySegment>,
     start_point: Option<Point<f64>>,
+    key: Option<String>,
+    arc_points_per_quadrant: usize,
+    keep_raw_boundary: bool,
 }
 
 impl AirspaceBuilder {
+    /// Creates a builder with the conversion `options`.
+    ///
+    /// At least one point per quadrant is interpolated along arcs.
+    pub fn new(options: &Arinc424Options) -> Self {
+        Self {
+            name: None,
+            airspace_type: None,
+            classification: None,
+            ceiling: None,
+            floor: None,
+            segments: Vec::new(),
+            start_point: None,
+            key: None,
+            arc_points_per_quadrant: options.arc_points_per_quadrant.max(1),
+            keep_raw_boundary: options.keep_raw_boundary,
+        }
+    }
+
     /// Adds a controlled airspace boundary record to the builder.
     pub fn add_controlled_record(
         &mut self,
         record: ControlledAirspace,
     ) -> Result<(), arinc424::Error> {
         let coord = match (record.latitude, record.longitude) {
-            (Some(lat), Some(lon)) => {
-                // geo uses (x, y) = (longitude, latitude)
-                Some(Point::new(lon.as_decimal()?, lat.as_decimal()?))
-            }
+            (Some(lat), Some(lon)) => Some(lat_lon_to_point(lat, lon)?),
             _ => None,
         };
 
-        // First record initializes metadata and starting point
-        if self.start_point.is_none() {
-            self.start_point = coord;
-            self.name = record.arsp_name.map(|n| n.to_string());
+        let key = format!(
+            "UC{}{}{}",
+            record.icao_code.as_str_checked()?,
+            record.arsp_cntr.as_str_checked()?,
+            record.multi_cd.as_str_checked()?
+        );
+
+        // First record of an airspace initializes the metadata
+        if self.start(key, coord) {
+            self.name = record
+                .arsp_name
+                .map(|n| n.as_str_checked().map(str::to_string))
+                .transpose()?;
             self.airspace_type = Some(record.arsp_type.into());
             self.classification =
                 parse_classification(record.arsp_type, record.arsp_class.as_ref());
@@ -96,14 +127,23 @@ impl AirspaceBuilder {
         record: RestrictiveAirspace,
     ) -> Result<(), arinc424::Error> {
         let coord = match (record.latitude, record.longitude) {
-            (Some(lat), Some(lon)) => Some(Point::new(lon.as_decimal()?, lat.as_decimal()?)),
+            (Some(lat), Some(lon)) => Some(lat_lon_to_point(lat, lon)?),
             _ => None,
         };
 
-        // First record initializes metadata and starting point
-        if self.start_point.is_none() {
-            self.start_point = coord;
-            self.name = record.arsp_name.map(|n| n.to_string());
+        let key = format!(
+            "UR{}{}{}",
+            record.icao_code.as_str_checked()?,
+            record.restrictive_designation.as_str_checked()?,
+            record.multi_cd.as_str_checked()?
+        );
+
+        // First record of an airspace initializes the metadata
+        if self.start(key, coord) {
+            self.name = record
+                .arsp_name
+                .map(|n| n.as_str_checked().map(str::to_string))
+                .transpose()?;
             self.airspace_type = Some(record.restrictive_type.into());
             self.classification = None;
             self.ceiling = record.upper_limit.map(Into::into);
@@ -119,6 +159,29 @@ impl AirspaceBuilder {
         )
     }
 
+    /// Starts a new ring at the `coord` if no segments are added so far.
+    ///
+    /// Returns `true` if the ring starts a new airspace, i.e. the record's
+    /// `key` differs from the previous ring's key. Otherwise, the ring is
+    /// another part of the previous airspace and shares its metadata.
+    ///
+    /// A new airspace drops the segments left by a previous airspace that
+    /// was never closed, e.g. because one of its records failed.
+    fn start(&mut self, key: String, coord: Option<Point<f64>>) -> bool {
+        if self.key.as_ref() != Some(&key) {
+            self.segments.clear();
+            self.start_point = coord;
+            self.key = Some(key);
+            return true;
+        }
+
+        if self.segments.is_empty() {
+            self.start_point = coord;
+        }
+
+        false
+    }
+
     fn add_segment(
         &mut self,
         coord: Option<Point<f64>>,
@@ -128,7 +191,7 @@ impl AirspaceBuilder {
         arc_dist: Option<arinc424::fields::ArcDistance<'_>>,
     ) -> Result<(), arinc424::Error> {
         let arc_center = match (arc_origin_lat, arc_origin_lon) {
-            (Some(lat), Some(lon)) => Some(Point::new(lon.as_decimal()?, lat.as_decimal()?)),
+            (Some(lat), Some(lon)) => Some(lat_lon_to_point(lat, lon)?),
             _ => None,
         };
 
@@ -147,16 +210,34 @@ impl AirspaceBuilder {
     }
 
     /// Builds the airspace from accumulated segments.
-    pub fn build(self) -> Result<Airspace, arinc424::Error> {
-        let polygon = self.build_polygon()?;
+    ///
+    /// The segments are cleared afterwards but the metadata is kept. Thus, if
+    /// the airspace has multiple disjoint parts, the records following the
+    /// first part build an airspace with the same metadata.
+    pub fn build(&mut self) -> Result<Airspace, arinc424::Error> {
+        let polygon = self.build_polygon();
+        let raw_boundary = self.keep_raw_boundary.then(|| {
+            self.segments
+                .iter()
+                .map(|segment| BoundaryRecordSummary {
+                    path: segment.path.into(),
+                    end_point: segment.end_point,
+                    arc_center: segment.arc_center,
+                    arc_radius: segment.arc_radius,
+                })
+                .collect()
+        });
+        self.segments.clear();
+        self.start_point = None;
 
         Ok(Airspace {
-            name: self.name.unwrap_or_default(),
+            name: self.name.clone().unwrap_or_default(),
             airspace_type: self.airspace_type.unwrap_or(AirspaceType::CTA),
             classification: self.classification,
             ceiling: self.ceiling.unwrap_or(VerticalDistance::Unlimited),
             floor: self.floor.unwrap_or(VerticalDistance::Gnd),
-            polygon,
+            polygon: polygon?,
+            raw_boundary,
         })
     }
 
@@ -181,17 +262,11 @@ impl AirspaceBuilder {
             match segment.path {
                 BoundaryPath::Circle => {
                     // Circle in middle of sequence is unusual, treat as endpoint
-                    coords.push(geo::Coord {
-                        x: segment.end_point.x(),
-                        y: segment.end_point.y(),
-                    });
+                    coords.push(segment.end_point.0);
                 }
                 BoundaryPath::GreatCircle | BoundaryPath::RhumbLine => {
                     // Direct path - just add the endpoint
-                    coords.push(geo::Coord {
-                        x: segment.end_point.x(),
-                        y: segment.end_point.y(),
-                    });
+                    coords.push(segment.end_point.0);
                 }
                 BoundaryPath::ClockwiseArc => {
                     let arc_coords = self.interpolate_arc(prev_point, segment, true)?;
@@ -222,16 +297,13 @@ impl AirspaceBuilder {
         let center = segment.end_point;
         let radius_m = segment.arc_radius.map(|r| r.to_si()).unwrap_or(0.0) as f64;
 
-        let num_points = ARC_POINTS_PER_QUADRANT * 4;
+        let num_points = self.arc_points_per_quadrant * 4;
         let mut coords = Vec::with_capacity(num_points + 1);
 
         for i in 0..num_points {
             let bearing = Angle::t((i as f32) * 360.0 / (num_points as f32));
             let point = Geodesic.destination(center, *bearing.value() as f64, radius_m);
-            coords.push(geo::Coord {
-                x: point.x(),
-                y: point.y(),
-            });
+            coords.push(point.0);
         }
 
         // Close the circle
@@ -256,10 +328,7 @@ impl AirspaceBuilder {
     ) -> Result<Vec<geo::Coord<f64>>, arinc424::Error> {
         let (Some(center), Some(radius)) = (segment.arc_center, segment.arc_radius) else {
             // No arc center - fall back to direct line
-            return Ok(vec![geo::Coord {
-                x: segment.end_point.x(),
-                y: segment.end_point.y(),
-            }]);
+            return Ok(vec![segment.end_point.0]);
         };
 
         // Calculate bearings from center to start and end points
@@ -270,7 +339,7 @@ impl AirspaceBuilder {
         let sweep = calculate_arc_sweep(start_bearing, end_bearing, clockwise);
         let sweep_rad = sweep.to_si();
         let num_points = ((sweep_rad.abs() / std::f32::consts::FRAC_PI_2)
-            * ARC_POINTS_PER_QUADRANT as f32)
+            * self.arc_points_per_quadrant as f32)
             .ceil() as usize;
         let num_points = num_points.max(2);
 
@@ -283,10 +352,7 @@ impl AirspaceBuilder {
             let bearing_deg = (start_rad + sweep_rad * fraction).to_degrees() as f64;
 
             let point = Geodesic.destination(center, bearing_deg, radius_m);
-            coords.push(geo::Coord {
-                x: point.x(),
-                y: point.y(),
-            });
+            coords.push(point.0);
         }
 
         Ok(coords)
@@ -319,6 +385,86 @@ fn calculate_arc_sweep(start: Angle, end: Angle, clockwise: bool) -> Angle {
 mod tests {
     use super::*;
 
+    const NEW_YORK: &[u8] = b"SUSAUCK6TKJFK PAB  A00100     R N40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703";
+
+    fn build(arc_points_per_quadrant: usize) -> Airspace {
+        build_record(
+            NEW_YORK,
+            &Arinc424Options {
+                arc_points_per_quadrant,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn build_record(bytes: &[u8], options: &Arinc424Options) -> Airspace {
+        let record = ControlledAirspace::try_from(bytes).expect("record should parse");
+        let mut builder = AirspaceBuilder::new(options);
+        builder
+            .add_controlled_record(record)
+            .expect("record should be added");
+        builder.build().expect("airspace should build")
+    }
+
+    #[test]
+    fn arc_density_is_configurable() {
+        // the arc returns to its start and thus spans four quadrants
+        let coarse = build(ARC_POINTS_PER_QUADRANT);
+        let fine = build(2 * ARC_POINTS_PER_QUADRANT);
+
+        // the interpolated points and the point closing the ring
+        assert_eq!(coarse.polygon.exterior().0.len(), 4 * 6 + 1);
+        assert_eq!(fine.polygon.exterior().0.len(), 4 * 12 + 1);
+    }
+
+    #[test]
+    fn controlled_airspace_is_classified() {
+        let airspace = build(ARC_POINTS_PER_QUADRANT);
+        assert_eq!(airspace.airspace_type, AirspaceType::TMA);
+        assert_eq!(airspace.classification, Some(AirspaceClassification::B));
+
+        // a control zone with an explicit class C
+        let mut record = NEW_YORK.to_vec();
+        record[8] = b'Z';
+        record[16] = b'C';
+
+        let airspace = build_record(&record, &Arinc424Options::default());
+        assert_eq!(airspace.airspace_type, AirspaceType::CTR);
+        assert_eq!(airspace.classification, Some(AirspaceClassification::C));
+    }
+
+    #[test]
+    fn raw_boundary_summarizes_records() {
+        // an arc followed by a great circle back to the origin
+        let mut great_circle = NEW_YORK.to_vec();
+        great_circle[30..32].copy_from_slice(b"GE");
+
+        let options = Arinc424Options {
+            keep_raw_boundary: true,
+            ..Default::default()
+        };
+        let mut builder = AirspaceBuilder::new(&options);
+
+        for bytes in [NEW_YORK, &great_circle] {
+            let record = ControlledAirspace::try_from(bytes).expect("record should parse");
+            builder
+                .add_controlled_record(record)
+                .expect("record should be added");
+        }
+
+        let airspace = builder.build().expect("airspace should build");
+        let raw_boundary = airspace
+            .raw_boundary()
+            .expect("raw boundary should be kept");
+
+        assert_eq!(raw_boundary.len(), 2);
+        assert_eq!(raw_boundary[0].path, crate::nd::BoundaryPath::ClockwiseArc);
+        assert_eq!(raw_boundary[0].arc_radius, Some(Length::nm(4.0)));
+        assert_eq!(raw_boundary[1].path, crate::nd::BoundaryPath::GreatCircle);
+
+        assert_eq!(build(ARC_POINTS_PER_QUADRANT).raw_boundary(), None);
+    }
+
     #[test]
     fn test_calculate_arc_sweep_clockwise() {
         // 0° to 90° clockwise = 90°
@@ -16,6 +16,7 @@
 use arinc424::fields;
 use arinc424::fields::LowerUpperLimit;
 
+use crate::geom::point_from_lat_lon;
 use crate::measurements::Angle;
 use crate::nd::*;
 use crate::MagneticVariation;
@@ -30,6 +31,25 @@ impl<'a> TryFrom<fields::Cycle<'a>> for AiracCycle {
     }
 }
 
+impl<'a> TryFrom<fields::CommType<'a>> for CommunicationType {
+    type Error = arinc424::Error;
+
+    fn try_from(value: fields::CommType) -> Result<Self, Self::Error> {
+        Ok(match value.as_str_checked()? {
+            "APP" => Self::Approach,
+            "ATI" => Self::Atis,
+            "CLD" => Self::ClearanceDelivery,
+            "DEP" => Self::Departure,
+            "GND" => Self::Ground,
+            "INF" => Self::Information,
+            "RDO" => Self::Radio,
+            "TWR" => Self::Tower,
+            "UNI" => Self::Unicom,
+            code => Self::Other(code.to_string()),
+        })
+    }
+}
+
 impl From<fields::ArspType> for AirspaceType {
     fn from(value: fields::ArspType) -> Self {
         match value {
@@ -75,6 +95,18 @@ pub fn parse_classification(
     }
 }
 
+impl From<fields::BoundaryPath> for BoundaryPath {
+    fn from(value: fields::BoundaryPath) -> Self {
+        match value {
+            fields::BoundaryPath::Circle => Self::Circle,
+            fields::BoundaryPath::GreatCircle => Self::GreatCircle,
+            fields::BoundaryPath::RhumbLine => Self::RhumbLine,
+            fields::BoundaryPath::ClockwiseArc => Self::ClockwiseArc,
+            fields::BoundaryPath::CounterClockwiseArc => Self::CounterClockwiseArc,
+        }
+    }
+}
+
 impl From<fields::RestrictiveType> for AirspaceType {
     fn from(value: fields::RestrictiveType) -> Self {
         match value {
@@ -106,12 +138,11 @@ impl<'a> TryFrom<fields::IcaoCode<'a>> for LocationIndicator {
 }
 
 /// Convert ARINC 424 latitude/longitude fields to a geo::Point.
-/// geo uses (x, y) = (longitude, latitude).
 pub fn lat_lon_to_point<'a>(
     lat: fields::Latitude<'a>,
     lon: fields::Longitude<'a>,
 ) -> Result<Point<f64>, arinc424::Error> {
-    Ok(Point::new(lon.as_decimal()?, lat.as_decimal()?))
+    Ok(point_from_lat_lon(lat.as_decimal()?, lon.as_decimal()?))
 }
 
 impl From<fields::MagVar> for MagneticVariation {
@@ -124,16 +155,22 @@ impl From<fields::MagVar> for MagneticVariation {
     }
 }
 
-impl<'a> From<fields::RegnCode<'a>> for Region {
-    fn from(value: fields::RegnCode) -> Self {
-        match value.as_str() {
-            "ENRT" => Self::Enroute,
+impl<'a> TryFrom<fields::RegnCode<'a>> for Region {
+    type Error = arinc424::Error;
+
+    fn try_from(value: fields::RegnCode) -> Result<Self, Self::Error> {
+        match value.as_str_checked()? {
+            "ENRT" => Ok(Self::Enroute),
             // TODO: Change terminal area code.
-            icao => Self::TerminalArea(
-                icao.as_bytes()
-                    .try_into()
-                    .expect("ICAO should be 4 character"),
-            ),
+            icao => icao
+                .as_bytes()
+                .try_into()
+                .map(Self::TerminalArea)
+                .map_err(|_| arinc424::Error::InvalidVariant {
+                    field: "RegnCode",
+                    bytes: value.as_bytes().to_vec(),
+                    expected: "ENRT or ICAO airport identifier",
+                }),
         }
     }
 }
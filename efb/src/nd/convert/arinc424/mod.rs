@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use log::{debug, info, trace, warn};
 
 use arinc424;
@@ -26,9 +28,181 @@ mod records;
 
 use airspace::AirspaceBuilder;
 
+/// Number of records after which the loading progress is reported.
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// Options to convert ARINC 424 data into navigation data.
+///
+/// The options may get new fields. Start from the default options and set the
+/// fields or use the `with_` methods:
+///
+/// ```
+/// # use efb::nd::Arinc424Options;
+/// let options = Arinc424Options::default().with_keep_raw_boundary(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Arinc424Options {
+    /// Number of points to interpolate per 90 degrees of airspace arcs and
+    /// circles. More points follow the arcs closer but increase the size of
+    /// the airspace polygons. Defaults to 6.
+    pub arc_points_per_quadrant: usize,
+    /// Keeps a summary of the boundary records on each airspace as
+    /// [`Airspace::raw_boundary`] to debug its polygon. Defaults to `false`.
+    ///
+    /// [`Airspace::raw_boundary`]: crate::nd::Airspace::raw_boundary()
+    pub keep_raw_boundary: bool,
+}
+
+impl Arinc424Options {
+    /// Sets the number of points per 90 degrees of airspace arcs.
+    pub fn with_arc_points_per_quadrant(mut self, arc_points_per_quadrant: usize) -> Self {
+        self.arc_points_per_quadrant = arc_points_per_quadrant;
+        self
+    }
+
+    /// Sets whether the raw boundary of airspaces is kept.
+    pub fn with_keep_raw_boundary(mut self, keep_raw_boundary: bool) -> Self {
+        self.keep_raw_boundary = keep_raw_boundary;
+        self
+    }
+}
+
+impl Default for Arinc424Options {
+    fn default() -> Self {
+        Self {
+            arc_points_per_quadrant: airspace::ARC_POINTS_PER_QUADRANT,
+            keep_raw_boundary: false,
+        }
+    }
+}
+
+/// Number of records loaded per kind, logged once loading completes.
+#[derive(Default)]
+struct RecordCounts {
+    waypoints: u32,
+    airports: u32,
+    runways: u32,
+    gates: u32,
+    frequencies: u32,
+    airspaces: u32,
+}
+
 impl NavigationData {
     /// Creates navigation data from an ARINC 424 string.
     pub fn try_from_arinc424(data: &[u8]) -> Result<Self, Error> {
+        Self::try_from_arinc424_with_progress(data, |_| {})
+    }
+
+    /// Creates navigation data from an ARINC 424 string with the conversion
+    /// `options`.
+    ///
+    /// Use [`load_arinc424`] to report the progress or cancel the load too.
+    ///
+    /// [`load_arinc424`]: NavigationData::load_arinc424
+    pub fn try_from_arinc424_with_options(
+        data: &[u8],
+        options: &Arinc424Options,
+    ) -> Result<Self, Error> {
+        Self::load_arinc424(data, options, |_| {}, &AtomicBool::new(false))
+    }
+
+    /// Creates navigation data from an ARINC 424 string and reports the
+    /// loading progress.
+    ///
+    /// The `progress` callback receives the number of records processed so
+    /// far. It is invoked every 1000 records and once all records are
+    /// processed.
+    pub fn try_from_arinc424_with_progress<F>(data: &[u8], progress: F) -> Result<Self, Error>
+    where
+        F: FnMut(usize),
+    {
+        Self::try_from_arinc424_cancellable(data, progress, &AtomicBool::new(false))
+    }
+
+    /// Creates navigation data from an ARINC 424 string until the load is
+    /// cancelled.
+    ///
+    /// The `cancel` flag is checked before each record. Once it is set, no
+    /// further records are read and the navigation data loaded so far is
+    /// returned with an [`Error::Cancelled`] in its [errors]. The `progress`
+    /// is reported as with [`try_from_arinc424_with_progress`].
+    ///
+    /// [errors]: NavigationData::errors
+    /// [`try_from_arinc424_with_progress`]: NavigationData::try_from_arinc424_with_progress
+    pub fn try_from_arinc424_cancellable<F>(
+        data: &[u8],
+        progress: F,
+        cancel: &AtomicBool,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(usize),
+    {
+        Self::load_arinc424(data, &Arinc424Options::default(), progress, cancel)
+    }
+
+    /// Creates navigation data from a gzip-compressed ARINC 424 file, e.g. a
+    /// `.pc.gz` as shipped by data providers.
+    ///
+    /// The data is decompressed in memory, since the records are read from a
+    /// contiguous buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Decompression`] if the data is not valid gzip.
+    #[cfg(feature = "flate2")]
+    pub fn try_from_arinc424_gz(data: &[u8]) -> Result<Self, Error> {
+        use std::io::Read;
+
+        let mut decompressed = Vec::new();
+        flate2::read::MultiGzDecoder::new(data)
+            .read_to_end(&mut decompressed)
+            .map_err(|e| Error::Decompression(e.to_string()))?;
+
+        debug!(
+            "decompressed ARINC 424 data from {} to {} bytes",
+            data.len(),
+            decompressed.len()
+        );
+
+        Self::try_from_arinc424(&decompressed)
+    }
+
+    /// Creates navigation data from an ARINC 424 string with the conversion
+    /// `options`, reports the loading progress and stops once cancelled.
+    ///
+    /// This combines all other ARINC 424 constructors. The `progress` is
+    /// reported as with [`try_from_arinc424_with_progress`] and the `cancel`
+    /// flag is checked as with [`try_from_arinc424_cancellable`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::atomic::AtomicBool;
+    /// # use efb::nd::{Arinc424Options, NavigationData};
+    /// # fn load(data: &[u8], cancel: &AtomicBool) -> Result<NavigationData, efb::error::Error> {
+    /// let options = Arinc424Options::default().with_keep_raw_boundary(true);
+    /// let nd = NavigationData::load_arinc424(
+    ///     data,
+    ///     &options,
+    ///     |records| println!("{records} records loaded"),
+    ///     cancel,
+    /// )?;
+    /// # Ok(nd)
+    /// # }
+    /// ```
+    ///
+    /// [`try_from_arinc424_with_progress`]: NavigationData::try_from_arinc424_with_progress
+    /// [`try_from_arinc424_cancellable`]: NavigationData::try_from_arinc424_cancellable
+    pub fn load_arinc424<F>(
+        data: &[u8],
+        options: &Arinc424Options,
+        mut progress: F,
+        cancel: &AtomicBool,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(usize),
+    {
         info!(
             "loading navigation data from ARINC 424 ({} bytes)",
             data.len()
@@ -36,9 +210,22 @@ impl NavigationData {
 
         let mut builder = NavigationData::builder();
         let mut airspace: Option<AirspaceBuilder> = None;
-        let mut counts = (0u32, 0u32, 0u32, 0u32);
+        let mut counts = RecordCounts::default();
+        let mut processed = 0;
 
         for (kind, bytes) in arinc424::records::Records::new(data) {
+            if cancel.load(Ordering::Relaxed) {
+                info!("ARINC 424 loading cancelled after {} records", processed);
+                builder.add_error(Error::Cancelled);
+                break;
+            }
+
+            processed += 1;
+
+            if processed % PROGRESS_INTERVAL == 0 {
+                progress(processed);
+            }
+
             if let Err(e) = || -> Result<(), arinc424::Error> {
                 match kind {
                     arinc424::records::RecordKind::Waypoint => {
@@ -46,7 +233,7 @@ impl NavigationData {
                         let wp = Waypoint::try_from(record)?;
                         trace!("loaded waypoint {}", wp.fix_ident);
                         builder.add_waypoint(wp);
-                        counts.0 += 1;
+                        counts.waypoints += 1;
                     }
 
                     arinc424::records::RecordKind::Airport => {
@@ -54,34 +241,52 @@ impl NavigationData {
                         let arpt = Airport::try_from(record)?;
                         trace!("loaded airport {}", arpt.icao_ident);
                         builder.add_airport(arpt);
-                        counts.1 += 1;
+                        counts.airports += 1;
                     }
 
                     arinc424::records::RecordKind::Runway => {
                         let record = arinc424::records::Runway::try_from(bytes)?;
-                        let ident = record.arpt_ident.to_string();
+                        let ident = record.arpt_ident.as_str_checked()?.to_string();
                         let rwy = Runway::try_from(record)?;
                         trace!("loaded runway {} at {}", rwy.designator, ident);
                         builder.add_runway(ident, rwy);
-                        counts.2 += 1;
+                        counts.runways += 1;
+                    }
+
+                    arinc424::records::RecordKind::Gate => {
+                        let record = arinc424::records::Gate::try_from(bytes)?;
+                        let ident = record.arpt_ident.as_str_checked()?.to_string();
+                        let gate = Gate::try_from(record)?;
+                        trace!("loaded gate {} at {}", gate.ident, ident);
+                        builder.add_gate(ident, gate);
+                        counts.gates += 1;
+                    }
+
+                    arinc424::records::RecordKind::Comm => {
+                        let record = arinc424::records::CommunicationFrequency::try_from(bytes)?;
+                        let ident = record.arpt_ident.as_str_checked()?.to_string();
+                        let freq = CommunicationFrequency::try_from(record)?;
+                        trace!("loaded frequency {} at {}", freq, ident);
+                        builder.add_frequency(ident, freq);
+                        counts.frequencies += 1;
                     }
 
                     arinc424::records::RecordKind::ControlledAirspace => {
                         let record = arinc424::records::ControlledAirspace::try_from(bytes)?;
                         let return_to_origin = record.bdry_via.return_to_origin;
                         airspace
-                            .get_or_insert_default()
+                            .get_or_insert_with(|| AirspaceBuilder::new(options))
                             .add_controlled_record(record)?;
 
                         if return_to_origin {
                             let arsp = airspace
-                                .take()
+                                .as_mut()
                                 .expect("there should be an airspace at this point")
                                 .build()?;
 
                             trace!("loaded airspace {}", arsp.name);
                             builder.add_airspace(arsp);
-                            counts.3 += 1;
+                            counts.airspaces += 1;
                         }
                     }
 
@@ -89,18 +294,18 @@ impl NavigationData {
                         let record = arinc424::records::RestrictiveAirspace::try_from(bytes)?;
                         let return_to_origin = record.bdry_via.return_to_origin;
                         airspace
-                            .get_or_insert_default()
+                            .get_or_insert_with(|| AirspaceBuilder::new(options))
                             .add_restrictive_record(record)?;
 
                         if return_to_origin {
                             let arsp = airspace
-                                .take()
+                                .as_mut()
                                 .expect("there should be an airspace at this point")
                                 .build()?;
 
                             trace!("loaded airspace {}", arsp.name);
                             builder.add_airspace(arsp);
-                            counts.3 += 1;
+                            counts.airspaces += 1;
                         }
                     }
                 }
@@ -115,14 +320,23 @@ impl NavigationData {
             }
         }
 
+        if processed % PROGRESS_INTERVAL != 0 {
+            progress(processed);
+        }
+
         let nd = builder
             .with_source(data)
             .with_format(SourceFormat::A424)
             .build();
 
         info!(
-            "ARINC 424 loading complete: {} waypoints, {} airports, {} runways, {} airspaces",
-            counts.0, counts.1, counts.2, counts.3
+            "ARINC 424 loading complete: {} waypoints, {} airports, {} runways, {} gates, {} frequencies, {} airspaces",
+            counts.waypoints,
+            counts.airports,
+            counts.runways,
+            counts.gates,
+            counts.frequencies,
+            counts.airspaces
         );
 
         if !nd.errors().is_empty() {
@@ -136,3 +350,224 @@ impl NavigationData {
         Ok(nd)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurements::{Frequency, Length};
+    use crate::VerticalDistance;
+
+    const EDDH: &[u8] = b"SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDBA12     0          N53374200E009591700                                               GATE A12                 123452401
+SEURP EDDHEDVATI0123125 V0      N53374900E009591762                                               HAMBURG INFORMATION      356472409";
+
+    #[test]
+    fn progress_is_reported() {
+        let mut reported = Vec::new();
+        NavigationData::try_from_arinc424_with_progress(EDDH, |n| reported.push(n))
+            .expect("records should load");
+
+        assert_eq!(reported, vec![3]);
+    }
+
+    #[test]
+    fn options_are_combined_with_progress() {
+        let data = b"SUSAUCK6TKLGA PAB  A00100     REN40394857W074144423N40413000W07409590000402450   GND  A07000MLAGUARDIA                     676061703";
+        let mut reported = Vec::new();
+
+        let nd = NavigationData::load_arinc424(
+            data,
+            &Arinc424Options::default().with_keep_raw_boundary(true),
+            |n| reported.push(n),
+            &AtomicBool::new(false),
+        )
+        .expect("records should load");
+
+        let nearby = nd.at(&geo::Point::new(-74.1664, 40.6917), Length::nm(1.0));
+
+        assert_eq!(reported, vec![1]);
+        assert!(nearby.airspaces[0].raw_boundary().is_some());
+    }
+
+    #[test]
+    fn cancelled_load_returns_partial_data() {
+        // distinct airport idents EAAA, EAAB, ... to count the loaded records
+        let data: Vec<u8> = (0..2500u32)
+            .flat_map(|i| {
+                let mut record = EDDH[..132].to_vec();
+                record[7..10].copy_from_slice(&[
+                    b'A' + (i / 676) as u8,
+                    b'A' + (i / 26 % 26) as u8,
+                    b'A' + (i % 26) as u8,
+                ]);
+                record
+            })
+            .collect();
+        let cancel = AtomicBool::new(false);
+        let mut reported = Vec::new();
+
+        let nd = NavigationData::try_from_arinc424_cancellable(
+            &data,
+            |n| {
+                reported.push(n);
+                cancel.store(true, Ordering::Relaxed);
+            },
+            &cancel,
+        )
+        .expect("records should load");
+
+        let complete = NavigationData::try_from_arinc424(&data).expect("records should load");
+
+        assert_eq!(reported, vec![1000]);
+        assert_eq!(nd.errors(), [Error::Cancelled]);
+        assert_eq!(nd.fixes().count(), 1000);
+        assert_eq!(complete.fixes().count(), 2500);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn gzip_compressed_data_loads_like_plain_data() {
+        use std::io::Write;
+
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(EDDH).expect("data should compress");
+        let compressed = encoder.finish().expect("data should compress");
+
+        let plain = NavigationData::try_from_arinc424(EDDH).expect("records should load");
+        let gz = NavigationData::try_from_arinc424_gz(&compressed).expect("records should load");
+
+        assert_eq!(gz.partition_id(), plain.partition_id());
+        assert_eq!(gz.find("EDDH"), plain.find("EDDH"));
+        assert!(matches!(
+            NavigationData::try_from_arinc424_gz(EDDH),
+            Err(Error::Decompression(_))
+        ));
+    }
+
+    #[test]
+    fn same_data_has_same_partition_id() {
+        let a = NavigationData::try_from_arinc424(EDDH).expect("records should load");
+        let b = NavigationData::try_from_arinc424(EDDH).expect("records should load");
+        let other = NavigationData::try_from_arinc424(&EDDH[..132]).expect("record should load");
+
+        assert_eq!(a.partition_id(), b.partition_id());
+        assert_ne!(a.partition_id(), other.partition_id());
+    }
+
+    #[test]
+    fn disjoint_airspace_parts_share_metadata() {
+        // two circles of the same airspace with the name only in the first
+        let data = b"SUSAUCK6TKJFK PAB  A00100     REN40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703
+SUSAUCK6TKJFK PAB  A00200     REN40394857W073144423N40413000W07309590000402450   GND  A07000M                              676071703";
+
+        let nd = NavigationData::try_from_arinc424(data).expect("records should load");
+        assert!(nd.errors().is_empty());
+
+        for lon in [-74.1664, -73.1664] {
+            let nearby = nd.at(&geo::Point::new(lon, 40.6917), Length::nm(1.0));
+
+            assert_eq!(nearby.airspaces.len(), 1);
+            assert_eq!(nearby.airspaces[0].name, "NEW YORK AREA A");
+            assert_eq!(nearby.airspaces[0].floor, VerticalDistance::Gnd);
+        }
+    }
+
+    #[test]
+    fn broken_airspace_does_not_leak_into_next() {
+        // the record closing the first airspace has a latitude of N95°
+        let data = b"SUSAUCK6TKJFK PAB  A00100     R N40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703
+SUSAUCK6TKJFK PAB  A00200     REN95394857W074144423N40413000W07409590000402450   GND  A07000M                              676061703
+SUSAUCK6TKLGA PAB  A00100     REN40394857W074144423N40413000W07409590000402450   GND  A07000MLAGUARDIA                     676061703";
+
+        let nd = NavigationData::try_from_arinc424(data).expect("records should load");
+        assert_eq!(nd.errors().len(), 1);
+
+        let nearby = nd.at(&geo::Point::new(-74.1664, 40.6917), Length::nm(1.0));
+
+        assert_eq!(nearby.airspaces.len(), 1);
+        assert_eq!(nearby.airspaces[0].name, "LAGUARDIA");
+    }
+
+    #[test]
+    fn raw_boundary_excludes_broken_airspace() {
+        // the record closing the first airspace has a latitude of N95°
+        let data = b"SUSAUCK6TKJFK PAB  A00100     R N40394857W074144423N40413000W07409590000402450   GND  A07000MNEW YORK AREA A               676061703
+SUSAUCK6TKJFK PAB  A00200     REN95394857W074144423N40413000W07409590000402450   GND  A07000M                              676061703
+SUSAUCK6TKLGA PAB  A00100     REN40394857W074144423N40413000W07409590000402450   GND  A07000MLAGUARDIA                     676061703";
+
+        let nd = NavigationData::try_from_arinc424_with_options(
+            data,
+            &Arinc424Options {
+                keep_raw_boundary: true,
+                ..Default::default()
+            },
+        )
+        .expect("records should load");
+
+        let nearby = nd.at(&geo::Point::new(-74.1664, 40.6917), Length::nm(1.0));
+
+        assert_eq!(nearby.airspaces.len(), 1);
+        assert_eq!(nearby.airspaces[0].raw_boundary().map(<[_]>::len), Some(1));
+    }
+
+    #[test]
+    fn gate_is_attached_to_airport() {
+        let nd = NavigationData::try_from_arinc424(EDDH).expect("records should load");
+
+        let Some(NavAid::Airport(eddh)) = nd.find("EDDH") else {
+            panic!("EDDH should be an airport");
+        };
+
+        assert_eq!(eddh.gates().len(), 1);
+        assert_eq!(eddh.gates()[0].ident, "A12");
+        assert_eq!(eddh.gates()[0].name, "GATE A12");
+    }
+
+    #[test]
+    fn atis_frequency_is_attached_to_airport() {
+        let nd = NavigationData::try_from_arinc424(EDDH).expect("records should load");
+
+        let Some(NavAid::Airport(eddh)) = nd.find("EDDH") else {
+            panic!("EDDH should be an airport");
+        };
+
+        assert_eq!(
+            eddh.frequencies(),
+            [CommunicationFrequency {
+                comm_type: CommunicationType::Atis,
+                frequency: Frequency::khz(123125.0),
+                callsign: String::from("HAMBURG INFORMATION"),
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_is_collected_as_error() {
+        let mut record = EDDH[..132].to_vec();
+        // replace the "M" of HAMBURG by a byte that is no valid UTF-8
+        record[95] = 0xff;
+
+        let nd = NavigationData::try_from_arinc424(&record).expect("records should load");
+
+        assert!(nd.find("EDDH").is_none());
+        assert!(matches!(
+            nd.errors(),
+            [Error::InvalidA424 { error, .. }] if error.contains("UTF-8")
+        ));
+    }
+
+    #[test]
+    fn coordinate_out_of_range_is_collected_as_error() {
+        let mut record = EDDH[..132].to_vec();
+        // latitude of N95°37'49"
+        record[33..35].copy_from_slice(b"95");
+
+        let nd = NavigationData::try_from_arinc424(&record).expect("records should load");
+
+        assert!(nd.find("EDDH").is_none());
+        assert_eq!(nd.errors().len(), 1);
+    }
+}
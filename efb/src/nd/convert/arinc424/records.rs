@@ -16,7 +16,7 @@
 use arinc424::records;
 
 use super::fields::lat_lon_to_point;
-use crate::measurements::Length;
+use crate::measurements::{Frequency, Length};
 use crate::nd::*;
 use crate::VerticalDistance;
 
@@ -25,14 +25,16 @@ impl<'a> TryFrom<records::Airport<'a>> for Airport {
 
     fn try_from(arpt: records::Airport) -> Result<Self, Self::Error> {
         Ok(Airport {
-            icao_ident: arpt.arpt_ident.to_string(),
-            iata_designator: arpt.iata.to_string(),
-            name: arpt.airport_name.to_string(),
+            icao_ident: arpt.arpt_ident.as_str_checked()?.to_string(),
+            iata_designator: arpt.iata.as_str_checked()?.to_string(),
+            name: arpt.airport_name.as_str_checked()?.to_string(),
             coordinate: lat_lon_to_point(arpt.latitude, arpt.longitude)?,
             mag_var: arpt.mag_var.map(Into::into),
             // TODO: Parse elevation and runways.
             elevation: VerticalDistance::Gnd,
             runways: Vec::new(),
+            gates: Vec::new(),
+            frequencies: Vec::new(),
             location: Some(arpt.icao_code.try_into()?),
             cycle: Some(arpt.cycle.try_into()?),
         })
@@ -65,13 +67,37 @@ impl<'a> TryFrom<records::Runway<'a>> for Runway {
     }
 }
 
+impl<'a> TryFrom<records::Gate<'a>> for Gate {
+    type Error = arinc424::Error;
+
+    fn try_from(gate: records::Gate) -> Result<Self, Self::Error> {
+        Ok(Gate {
+            ident: gate.gate_ident.as_str_checked()?.to_string(),
+            name: gate.name.as_str_checked()?.to_string(),
+            coordinate: lat_lon_to_point(gate.latitude, gate.longitude)?,
+        })
+    }
+}
+
+impl<'a> TryFrom<records::CommunicationFrequency<'a>> for CommunicationFrequency {
+    type Error = arinc424::Error;
+
+    fn try_from(comm: records::CommunicationFrequency) -> Result<Self, Self::Error> {
+        Ok(CommunicationFrequency {
+            comm_type: comm.comm_type.try_into()?,
+            frequency: Frequency::khz(comm.comm_freq.khz(comm.freq_units)?),
+            callsign: comm.call_sign.as_str_checked()?.to_string(),
+        })
+    }
+}
+
 impl<'a> TryFrom<records::Waypoint<'a>> for Waypoint {
     type Error = arinc424::Error;
 
     fn try_from(wp: records::Waypoint) -> Result<Self, Self::Error> {
         Ok(Waypoint {
-            fix_ident: wp.fix_ident.to_string(),
-            desc: wp.name_desc.to_string(),
+            fix_ident: wp.fix_ident.as_str_checked()?.to_string(),
+            desc: wp.name_desc.as_str_checked()?.to_string(),
             // TODO change type to enum and add matching
             usage: if wp.waypoint_type.as_bytes() == b"V  " {
                 WaypointUsage::VFROnly
@@ -79,7 +105,7 @@ impl<'a> TryFrom<records::Waypoint<'a>> for Waypoint {
                 WaypointUsage::Unknown
             },
             coordinate: lat_lon_to_point(wp.latitude, wp.longitude)?,
-            region: wp.regn_code.into(),
+            region: wp.regn_code.try_into()?,
             mag_var: wp.mag_var.map(Into::into),
             location: wp.icao_code().try_into().ok(),
             cycle: Some(wp.cycle.try_into()?),
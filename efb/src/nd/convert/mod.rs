@@ -15,3 +15,5 @@
 
 mod arinc424;
 mod openair;
+
+pub use arinc424::Arinc424Options;
@@ -17,16 +17,19 @@
 //!
 //! The current implementation parses only the airspace descriptions as defined
 //! by the [user guide]. For each command, an [OpenAirElement] is created which
-//! is than converted to an [Airspace].
+//! is than converted to an [Airspace]. Airspaces can be written back to OpenAir
+//! too.
 //!
 //! [user guide]: http://www.winpilot.com/UsersGuide/UserAirspace.asp
 
+use std::fmt::{self, Write as _};
 use std::str::FromStr;
 
 use log::{debug, info, trace};
 
 use crate::error::Error;
 use crate::fc;
+use crate::geom::point_from_lat_lon;
 use crate::nd::{Airspace, AirspaceClassification, AirspaceType, NavigationData, SourceFormat};
 use crate::VerticalDistance;
 use geo::Point;
@@ -61,6 +64,23 @@ impl NavigationData {
         Ok(nd)
     }
 
+    /// Writes the airspaces to OpenAir.
+    ///
+    /// Each airspace is written as `AC`, `AN`, `AH`, `AL` and `DP` records.
+    /// Control areas are written with their classification as class. Other
+    /// types are written with their OpenAir class, e.g. `CTR` or `R`, since
+    /// OpenAir can't have both. Pressure altitudes are written as flight level
+    /// and coordinates are rounded to seconds.
+    pub fn to_openair(&self) -> String {
+        let mut s = String::new();
+
+        for airspace in self.airspaces() {
+            write_openair(&mut s, airspace).expect("writing to a string should not fail");
+        }
+
+        s
+    }
+
     fn parse_command(command: &str, element: &mut OpenAirElement) -> Option<Airspace> {
         let record_type = command.get(0..2);
         let record = command.get(3..);
@@ -134,6 +154,32 @@ fn parse_openair_class(ac: &str) -> (AirspaceType, Option<AirspaceClassification
     }
 }
 
+/// Writes the `airspace` as OpenAir records.
+fn write_openair(s: &mut String, airspace: &Airspace) -> fmt::Result {
+    let class = match (airspace.airspace_type, airspace.classification) {
+        (AirspaceType::CTA, Some(classification)) => classification.to_string(),
+        (AirspaceType::CTR, _) => String::from("CTR"),
+        (AirspaceType::TMA, _) => String::from("TMA"),
+        (AirspaceType::Restricted, _) => String::from("R"),
+        (AirspaceType::Danger, _) => String::from("Q"),
+        (AirspaceType::Prohibited, _) => String::from("P"),
+        (AirspaceType::TMZ, _) => String::from("TMZ"),
+        (AirspaceType::RMZ, _) => String::from("RMZ"),
+        (AirspaceType::CTA | AirspaceType::RadarZone, _) => String::from("CTA"),
+    };
+
+    writeln!(s, "AC {class}")?;
+    writeln!(s, "AN {}", airspace.name)?;
+    writeln!(s, "AH {}", OpenAirVerticalDistance(airspace.ceiling))?;
+    writeln!(s, "AL {}", OpenAirVerticalDistance(airspace.floor))?;
+
+    for coord in airspace.polygon.exterior().coords() {
+        writeln!(s, "DP {}", OpenAirCoordinate(Point::from(*coord)))?;
+    }
+
+    writeln!(s)
+}
+
 impl OpenAirElement {
     fn new() -> Self {
         Self {
@@ -166,6 +212,7 @@ impl From<&mut OpenAirElement> for Airspace {
             ceiling: element.ah.take().unwrap_or_default().into_inner(),
             floor: element.al.take().unwrap_or_default().into_inner(),
             polygon: geo::Polygon::new(geo::LineString::from(coords), vec![]),
+            raw_boundary: None,
         }
     }
 }
@@ -178,10 +225,7 @@ struct OpenAirCoordinate(Point<f64>);
 
 impl OpenAirCoordinate {
     pub fn into_inner(self) -> geo::Coord<f64> {
-        geo::Coord {
-            x: self.0.x(),
-            y: self.0.y(),
-        }
+        self.0.into()
     }
 }
 
@@ -223,13 +267,34 @@ impl FromStr for OpenAirCoordinate {
         };
 
         match (latitude, longitude) {
-            // geo uses (x, y) = (longitude, latitude)
-            (Some(latitude), Some(longitude)) => Ok(Self(Point::new(longitude, latitude))),
+            (Some(latitude), Some(longitude)) => Ok(Self(point_from_lat_lon(latitude, longitude))),
             _ => Err(ParseOpenAirCoordinateError),
         }
     }
 }
 
+impl fmt::Display for OpenAirCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lat_d, lat_m, lat_s) = fc::decimal_to_dms(self.0.y());
+        let (lon_d, lon_m, lon_s) = fc::decimal_to_dms(self.0.x());
+        let ns = if self.0.y().is_sign_negative() {
+            "S"
+        } else {
+            "N"
+        };
+        let ew = if self.0.x().is_sign_negative() {
+            "W"
+        } else {
+            "E"
+        };
+
+        write!(
+            f,
+            "{lat_d:02}:{lat_m:02}:{lat_s:02} {ns} {lon_d:03}:{lon_m:02}:{lon_s:02} {ew}"
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ParseOpenAirVerticalDistanceError;
 
@@ -248,6 +313,22 @@ impl Default for OpenAirVerticalDistance {
     }
 }
 
+impl fmt::Display for OpenAirVerticalDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            VerticalDistance::Agl(value) => write!(f, "{value} ft AGL"),
+            VerticalDistance::Altitude(value) => write!(f, "{value} ft"),
+            VerticalDistance::PressureAltitude(value) => {
+                write!(f, "FL{}", (value.max(0) as f32 / 100.0).round())
+            }
+            VerticalDistance::Fl(value) => write!(f, "FL{value}"),
+            VerticalDistance::Gnd => write!(f, "GND"),
+            VerticalDistance::Msl(value) => write!(f, "{value} ft MSL"),
+            VerticalDistance::Unlimited => write!(f, "UNLIM"),
+        }
+    }
+}
+
 impl FromStr for OpenAirVerticalDistance {
     type Err = ParseOpenAirVerticalDistanceError;
 
@@ -281,10 +362,9 @@ impl FromStr for OpenAirVerticalDistance {
 
 #[cfg(test)]
 mod tests {
-    use std::rc::Rc;
-
     use super::*;
     use crate::fc;
+    use crate::nd::Shared;
 
     #[test]
     fn parses_command() {
@@ -301,7 +381,7 @@ DP 53:06:04 N 8:58:30 E
 
         let nd = NavigationData::try_from_openair(record).expect("OpenAir should parse");
 
-        let tma_bremen_a = Rc::new(Airspace {
+        let tma_bremen_a = Shared::new(Airspace {
             name: String::from("TMA BREMEN A"),
             airspace_type: AirspaceType::CTA,
             classification: Some(AirspaceClassification::D),
@@ -314,11 +394,78 @@ DP 53:06:04 N 8:58:30 E
                 (fc::dms_to_decimal(52, 58, 8), fc::dms_to_decimal(8, 58, 56)),
                 (fc::dms_to_decimal(53, 6, 4), fc::dms_to_decimal(8, 58, 30))
             ],
+            raw_boundary: None,
         });
 
         assert_eq!(nd.airspaces, vec!(tma_bremen_a));
     }
 
+    #[test]
+    fn openair_round_trip() {
+        let record = r#"AC D
+AN TMA BREMEN A
+AH FL 65
+AL 1500msl
+DP 53:06:04 N 8:58:30 E
+DP 53:06:10 N 9:04:45 E
+DP 52:58:13 N 9:05:04 E
+DP 52:58:08 N 8:58:56 E
+DP 53:06:04 N 8:58:30 E
+"#;
+
+        let nd = NavigationData::try_from_openair(record).expect("OpenAir should parse");
+        let exported = NavigationData::try_from_openair(&nd.to_openair())
+            .expect("exported OpenAir should parse");
+
+        let (original, exported) = (&nd.airspaces[0], &exported.airspaces[0]);
+        assert_eq!(
+            exported.polygon.exterior().0.len(),
+            original.polygon.exterior().0.len()
+        );
+        assert_eq!(exported.classification, original.classification);
+        assert_eq!(exported.ceiling, original.ceiling);
+        assert_eq!(exported.floor, original.floor);
+        assert_eq!(exported.name, original.name);
+    }
+
+    #[test]
+    fn self_intersecting_airspace_is_collected_as_error() {
+        // the second and third point are swapped
+        let record = r#"AC D
+AN TMA BREMEN A
+AH FL 65
+AL 1500msl
+DP 53:06:04 N 8:58:30 E
+DP 52:58:13 N 9:05:04 E
+DP 53:06:10 N 9:04:45 E
+DP 52:58:08 N 8:58:56 E
+DP 53:06:04 N 8:58:30 E
+"#;
+
+        let nd = NavigationData::try_from_openair(record).expect("OpenAir should parse");
+
+        assert_eq!(nd.airspaces.len(), 1);
+        assert!(matches!(
+            nd.errors(),
+            [Error::InvalidAirspace { name, .. }] if name == "TMA BREMEN A"
+        ));
+    }
+
+    #[test]
+    fn writes_vertical_distance() {
+        for vd in [
+            VerticalDistance::Agl(1500),
+            VerticalDistance::Altitude(6400),
+            VerticalDistance::Fl(95),
+            VerticalDistance::Gnd,
+            VerticalDistance::Msl(2500),
+            VerticalDistance::Unlimited,
+        ] {
+            let s = OpenAirVerticalDistance(vd).to_string();
+            assert_eq!(s.parse(), Ok(OpenAirVerticalDistance(vd)), "{s}");
+        }
+    }
+
     #[test]
     fn parses_coordinate() {
         let north_west = "37:53:00 N 116:55:30 W".parse::<OpenAirCoordinate>();
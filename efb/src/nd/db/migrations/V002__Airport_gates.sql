@@ -0,0 +1,27 @@
+-- SPDX-License-Identifier: Apache-2.0
+-- Copyright 2026 Joe Pearson
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+
+-- Gates and parking positions of an airport.
+CREATE TABLE gates (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    airport_id      INTEGER NOT NULL
+                    REFERENCES airports(id) ON DELETE CASCADE,
+    ident           TEXT NOT NULL,
+    name            TEXT NOT NULL,
+    lat             REAL NOT NULL,
+    lon             REAL NOT NULL
+) STRICT;
+
+CREATE INDEX idx_gates_airport ON gates(airport_id);
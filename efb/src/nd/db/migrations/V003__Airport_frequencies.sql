@@ -0,0 +1,26 @@
+-- SPDX-License-Identifier: Apache-2.0
+-- Copyright 2026 Joe Pearson
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+
+-- Communication frequencies of an airport.
+CREATE TABLE frequencies (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    airport_id      INTEGER NOT NULL
+                    REFERENCES airports(id) ON DELETE CASCADE,
+    comm_type       TEXT NOT NULL,
+    frequency_khz   REAL NOT NULL,
+    callsign        TEXT NOT NULL
+) STRICT;
+
+CREATE INDEX idx_frequencies_airport ON frequencies(airport_id);
@@ -19,8 +19,10 @@ use rusqlite_migration::{Migrations, M};
 use crate::error::{Error, Result};
 
 const INITIAL: &str = include_str!("V001__Initial_navigation_data.sql");
+const AIRPORT_GATES: &str = include_str!("V002__Airport_gates.sql");
+const AIRPORT_FREQUENCIES: &str = include_str!("V003__Airport_frequencies.sql");
 
-pub(super) const SCHEMA_VERSION: u32 = 1;
+pub(super) const SCHEMA_VERSION: u32 = 3;
 
 /// Brings the database up to [`SCHEMA_VERSION`].
 ///
@@ -48,7 +50,11 @@ pub(super) fn migrate(conn: &mut Connection) -> Result<()> {
 fn migrations() -> Migrations<'static> {
     // Adding a future migration: append a new `M::up(...)`, bump
     // `SCHEMA_VERSION`, and drop the matching `XXX.sql` file in this module.
-    Migrations::new(vec![M::up(INITIAL)])
+    Migrations::new(vec![
+        M::up(INITIAL),
+        M::up(AIRPORT_GATES),
+        M::up(AIRPORT_FREQUENCIES),
+    ])
 }
 
 #[cfg(test)]
@@ -112,6 +112,8 @@ mod tests {
 SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
 SEURP EDDHEDGRW05    0106630500 N53371100E009580180                          151                                           124362502
 SEURP EDDHEDGRW23    0106632300 N53380900E009595876                          151                                           124362502
+SEURP EDDHEDBA12     0          N53374200E009591700                                               GATE A12                 123452401
+SEURP EDDHEDVATI0123125 V0      N53374900E009591762                                               HAMBURG INFORMATION      356472409
 "#;
 
     #[test]
@@ -143,6 +145,48 @@ SEURP EDDHEDGRW23    0106632300 N53380900E009595876                          151
         }
     }
 
+    #[test]
+    fn round_trip_preserves_gates() {
+        let mut conn = Connection::open_in_memory().unwrap();
+
+        let original =
+            NavigationData::try_from_arinc424(HAMBURG_A424).expect("ARINC 424 should parse");
+        original
+            .try_into_sqlite(&mut conn)
+            .expect("write should succeed");
+
+        let loaded = NavigationData::try_from_sqlite(&mut conn).expect("read should succeed");
+
+        match (original.find("EDDH"), loaded[0].find("EDDH")) {
+            (Some(crate::nd::NavAid::Airport(a)), Some(crate::nd::NavAid::Airport(b))) => {
+                assert_eq!(a.gates.len(), 1);
+                assert_eq!(a.gates, b.gates);
+            }
+            _ => panic!("expected airport"),
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_frequencies() {
+        let mut conn = Connection::open_in_memory().unwrap();
+
+        let original =
+            NavigationData::try_from_arinc424(HAMBURG_A424).expect("ARINC 424 should parse");
+        original
+            .try_into_sqlite(&mut conn)
+            .expect("write should succeed");
+
+        let loaded = NavigationData::try_from_sqlite(&mut conn).expect("read should succeed");
+
+        match (original.find("EDDH"), loaded[0].find("EDDH")) {
+            (Some(crate::nd::NavAid::Airport(a)), Some(crate::nd::NavAid::Airport(b))) => {
+                assert_eq!(a.frequencies.len(), 1);
+                assert_eq!(a.frequencies, b.frequencies);
+            }
+            _ => panic!("expected airport"),
+        }
+    }
+
     #[test]
     fn empty_database_returns_no_partitions() {
         let mut conn = Connection::open_in_memory().unwrap();
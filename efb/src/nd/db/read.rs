@@ -15,16 +15,17 @@
 
 use std::collections::HashMap;
 
-use geo::{Coord, LineString, Point, Polygon};
+use geo::{Coord, LineString, Polygon};
 use rusqlite::{params, Connection};
 
 use crate::core::MagneticVariation;
 use crate::error::{Error, Result};
-use crate::measurements::{Angle, Length};
+use crate::geom::{coord_from_lat_lon, point_from_lat_lon};
+use crate::measurements::{Angle, Frequency, Length};
 use crate::nd::{
-    AiracCycle, Airport, Airspace, AirspaceClassification, AirspaceType, LocationIndicator,
-    NavigationData, NavigationDataBuilder, Region, Runway, RunwaySurface, SourceFormat, Waypoint,
-    WaypointUsage,
+    AiracCycle, Airport, Airspace, AirspaceClassification, AirspaceType, CommunicationFrequency,
+    CommunicationType, Gate, LocationIndicator, NavigationData, NavigationDataBuilder, Region,
+    Runway, RunwaySurface, SourceFormat, Waypoint, WaypointUsage,
 };
 
 use super::encoding::vd_from_row;
@@ -71,6 +72,8 @@ fn load_airports(
     builder: &mut NavigationDataBuilder,
 ) -> Result<()> {
     let runways_by_airport = load_runways_by_airport(conn, partition_id)?;
+    let mut gates_by_airport = load_gates_by_airport(conn, partition_id)?;
+    let mut frequencies_by_airport = load_frequencies_by_airport(conn, partition_id)?;
 
     let mut stmt = conn.prepare(
         "SELECT id, icao_ident, iata_designator, name, lat, lon, \
@@ -105,10 +108,12 @@ fn load_airports(
             icao_ident,
             iata_designator,
             name,
-            coordinate: Point::new(lon, lat),
+            coordinate: point_from_lat_lon(lat, lon),
             mag_var,
             elevation,
             runways,
+            gates: gates_by_airport.remove(&id).unwrap_or_default(),
+            frequencies: frequencies_by_airport.remove(&id).unwrap_or_default(),
             location,
             cycle,
         });
@@ -168,6 +173,67 @@ fn load_runways_by_airport(
     Ok(by_airport)
 }
 
+/// Loads every gate for the partition in one query and groups them by
+/// `airport_id`.
+fn load_gates_by_airport(conn: &Connection, partition_id: &str) -> Result<HashMap<i64, Vec<Gate>>> {
+    let mut stmt = conn.prepare(
+        "SELECT g.airport_id, g.ident, g.name, g.lat, g.lon \
+         FROM gates g \
+         JOIN airports a ON a.id = g.airport_id \
+         WHERE a.partition_id = ?1",
+    )?;
+
+    let mut rows = stmt.query(params![partition_id])?;
+    let mut by_airport: HashMap<i64, Vec<Gate>> = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let airport_id: i64 = row.get(0)?;
+        let ident: String = row.get(1)?;
+        let name: String = row.get(2)?;
+        let lat: f64 = row.get(3)?;
+        let lon: f64 = row.get(4)?;
+
+        by_airport.entry(airport_id).or_default().push(Gate {
+            ident,
+            name,
+            coordinate: point_from_lat_lon(lat, lon),
+        });
+    }
+    Ok(by_airport)
+}
+
+/// Loads every communication frequency for the partition in one query and
+/// groups them by `airport_id`.
+fn load_frequencies_by_airport(
+    conn: &Connection,
+    partition_id: &str,
+) -> Result<HashMap<i64, Vec<CommunicationFrequency>>> {
+    let mut stmt = conn.prepare(
+        "SELECT f.airport_id, f.comm_type, f.frequency_khz, f.callsign \
+         FROM frequencies f \
+         JOIN airports a ON a.id = f.airport_id \
+         WHERE a.partition_id = ?1",
+    )?;
+
+    let mut rows = stmt.query(params![partition_id])?;
+    let mut by_airport: HashMap<i64, Vec<CommunicationFrequency>> = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let airport_id: i64 = row.get(0)?;
+        let comm_type: CommunicationType = row.get(1)?;
+        let frequency_khz: f64 = row.get(2)?;
+        let callsign: String = row.get(3)?;
+
+        by_airport
+            .entry(airport_id)
+            .or_default()
+            .push(CommunicationFrequency {
+                comm_type,
+                frequency: Frequency::khz(frequency_khz as f32),
+                callsign,
+            });
+    }
+    Ok(by_airport)
+}
+
 fn load_waypoints(
     conn: &Connection,
     partition_id: &str,
@@ -195,7 +261,7 @@ fn load_waypoints(
             fix_ident,
             desc,
             usage,
-            coordinate: Point::new(lon, lat),
+            coordinate: point_from_lat_lon(lat, lon),
             mag_var,
             region,
             location,
@@ -243,14 +309,14 @@ fn load_airspaces(
         let rings = rings_by_airspace.remove(&id).unwrap_or_default();
         let polygon = polygon_from_rings(id, rings)?;
 
-        builder.add_airspace(Airspace {
+        builder.add_airspace(Airspace::new(
             name,
             airspace_type,
             classification,
             ceiling,
             floor,
             polygon,
-        });
+        ));
     }
 
     Ok(())
@@ -283,7 +349,7 @@ fn load_airspace_vertices(
         if rings.len() <= idx {
             rings.resize_with(idx + 1, Vec::new);
         }
-        rings[idx].push(Coord { x: lon, y: lat });
+        rings[idx].push(coord_from_lat_lon(lat, lon));
     }
     Ok(by_airspace)
 }
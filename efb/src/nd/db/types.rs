@@ -17,8 +17,8 @@ use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput,
 
 use crate::core::MagneticVariation;
 use crate::nd::{
-    AiracCycle, AirspaceClassification, AirspaceType, LocationIndicator, Region, RunwaySurface,
-    SourceFormat, WaypointUsage,
+    AiracCycle, AirspaceClassification, AirspaceType, CommunicationType, LocationIndicator, Region,
+    RunwaySurface, SourceFormat, WaypointUsage,
 };
 
 impl ToSql for AirspaceType {
@@ -108,6 +108,42 @@ impl FromSql for WaypointUsage {
     }
 }
 
+// Other communication types are stored with their upper case ARINC 424 code
+// and can't collide with the lower case names of the known types.
+impl ToSql for CommunicationType {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(match self {
+            Self::Approach => b"approach",
+            Self::Atis => b"atis",
+            Self::ClearanceDelivery => b"clearance_delivery",
+            Self::Departure => b"departure",
+            Self::Ground => b"ground",
+            Self::Information => b"information",
+            Self::Radio => b"radio",
+            Self::Tower => b"tower",
+            Self::Unicom => b"unicom",
+            Self::Other(code) => code.as_bytes(),
+        })))
+    }
+}
+
+impl FromSql for CommunicationType {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Ok(match value.as_str()? {
+            "approach" => Self::Approach,
+            "atis" => Self::Atis,
+            "clearance_delivery" => Self::ClearanceDelivery,
+            "departure" => Self::Departure,
+            "ground" => Self::Ground,
+            "information" => Self::Information,
+            "radio" => Self::Radio,
+            "tower" => Self::Tower,
+            "unicom" => Self::Unicom,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
 impl ToSql for RunwaySurface {
     fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
         Ok(ToSqlOutput::Borrowed(ValueRef::Text(match self {
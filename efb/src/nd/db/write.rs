@@ -17,8 +17,10 @@ use geo::BoundingRect;
 use rusqlite::{params, Connection, Statement, Transaction};
 
 use crate::error::{Error, Result};
-use crate::measurements::AngleUnit;
-use crate::nd::{Airport, Airspace, NavigationData, Runway, Waypoint};
+use crate::measurements::{AngleUnit, FrequencyUnit};
+use crate::nd::{
+    Airport, Airspace, CommunicationFrequency, Gate, NavigationData, Runway, Waypoint,
+};
 
 use super::encoding::{vd_kind, vd_value};
 
@@ -47,6 +49,8 @@ struct PreparedWrites<'t> {
     partition: Statement<'t>,
     airport: Statement<'t>,
     runway: Statement<'t>,
+    gate: Statement<'t>,
+    frequency: Statement<'t>,
     waypoint: Statement<'t>,
     airspace: Statement<'t>,
     airspace_vertex: Statement<'t>,
@@ -73,6 +77,14 @@ impl<'t> PreparedWrites<'t> {
                   elev_kind, elev_value) \
                  VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             )?,
+            gate: tx.prepare(
+                "INSERT INTO gates (airport_id, ident, name, lat, lon) \
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )?,
+            frequency: tx.prepare(
+                "INSERT INTO frequencies (airport_id, comm_type, frequency_khz, callsign) \
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?,
             waypoint: tx.prepare(
                 "INSERT OR REPLACE INTO waypoints \
                  (partition_id, fix_ident, description, usage, lat, lon, \
@@ -110,6 +122,12 @@ fn write_one(stmts: &mut PreparedWrites<'_>, nd: &NavigationData) -> Result<()>
         for runway in &airport.runways {
             write_runway(&mut stmts.runway, airport_id, runway)?;
         }
+        for gate in &airport.gates {
+            write_gate(&mut stmts.gate, airport_id, gate)?;
+        }
+        for frequency in &airport.frequencies {
+            write_frequency(&mut stmts.frequency, airport_id, frequency)?;
+        }
     }
     for waypoint in nd
         .waypoints
@@ -164,6 +182,31 @@ fn write_runway(stmt: &mut Statement<'_>, airport_id: i64, r: &Runway) -> Result
     Ok(())
 }
 
+fn write_gate(stmt: &mut Statement<'_>, airport_id: i64, g: &Gate) -> Result<()> {
+    stmt.execute(params![
+        airport_id,
+        &g.ident,
+        &g.name,
+        g.coordinate.y(),
+        g.coordinate.x(),
+    ])?;
+    Ok(())
+}
+
+fn write_frequency(
+    stmt: &mut Statement<'_>,
+    airport_id: i64,
+    f: &CommunicationFrequency,
+) -> Result<()> {
+    stmt.execute(params![
+        airport_id,
+        &f.comm_type,
+        *f.frequency.convert_to(FrequencyUnit::Kilohertz).value() as f64,
+        &f.callsign,
+    ])?;
+    Ok(())
+}
+
 fn write_waypoint(stmt: &mut Statement<'_>, partition_id: &str, w: &Waypoint) -> Result<()> {
     stmt.execute(params![
         partition_id,
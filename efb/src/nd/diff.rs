@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use geo::{Distance, Geodesic};
+
+use super::*;
+
+/// Distance within which a moved fix is considered unchanged.
+pub const DIFF_TOLERANCE_M: f32 = 10.0;
+
+/// Identifies a fix across two sets of navigation data.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum FixKey {
+    Airport(String),
+    Waypoint(String, Region, Option<LocationIndicator>),
+}
+
+impl From<&NavAid> for FixKey {
+    fn from(navaid: &NavAid) -> Self {
+        match navaid {
+            NavAid::Airport(arpt) => Self::Airport(arpt.ident()),
+            NavAid::Waypoint(wp) => Self::Waypoint(wp.ident(), wp.region, wp.location),
+        }
+    }
+}
+
+/// The differences between two sets of navigation data.
+///
+/// Airports and waypoints are matched by their identifier. Waypoints are
+/// matched within their ICAO location and terminal area, since the same
+/// identifier is used by waypoints in different regions.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct NavDataDiff {
+    added: Vec<NavAid>,
+    removed: Vec<NavAid>,
+    changed: Vec<(NavAid, NavAid)>,
+}
+
+impl NavDataDiff {
+    /// Fixes that are only in the other navigation data.
+    pub fn added(&self) -> &[NavAid] {
+        &self.added
+    }
+
+    /// Fixes that are missing in the other navigation data.
+    pub fn removed(&self) -> &[NavAid] {
+        &self.removed
+    }
+
+    /// Fixes that moved by more than [`DIFF_TOLERANCE_M`] as pairs of the old
+    /// and new fix.
+    pub fn changed(&self) -> &[(NavAid, NavAid)] {
+        &self.changed
+    }
+
+    /// Returns `true` if nothing was added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl NavigationData {
+    /// Returns the fixes that were added, removed or changed in the `other`
+    /// navigation data.
+    ///
+    /// This is useful to show what changed with a new AIRAC cycle. All lists of
+    /// the diff are sorted by identifier.
+    pub fn diff(&self, other: &Self) -> NavDataDiff {
+        let mut old: HashMap<FixKey, NavAid> =
+            self.fixes().map(|fix| (FixKey::from(&fix), fix)).collect();
+
+        let mut diff = NavDataDiff::default();
+
+        for new in other.fixes() {
+            match old.remove(&FixKey::from(&new)) {
+                Some(old) => {
+                    let moved = Geodesic.distance(old.coordinate(), new.coordinate());
+
+                    if moved > DIFF_TOLERANCE_M as f64 {
+                        diff.changed.push((old, new));
+                    }
+                }
+                None => diff.added.push(new),
+            }
+        }
+
+        diff.removed = old.into_values().collect();
+
+        diff.added.sort_by_key(|fix| fix.ident());
+        diff.removed.sort_by_key(|fix| fix.ident());
+        diff.changed.sort_by_key(|(fix, _)| fix.ident());
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nd::fixtures::{airport, waypoint};
+
+    fn nd(airports: Vec<Airport>) -> NavigationData {
+        let mut builder = NavigationData::builder();
+        airports
+            .into_iter()
+            .for_each(|arpt| builder.add_airport(arpt));
+        builder.build()
+    }
+
+    fn enroute_waypoint(ident: &str, location: &str, coordinate: Point<f64>) -> Waypoint {
+        Waypoint {
+            location: LocationIndicator::new(location).ok(),
+            ..waypoint(ident, coordinate)
+        }
+    }
+
+    #[test]
+    fn diff_categorizes_fixes() {
+        let old = nd(vec![
+            airport("EDDH", Point::new(9.99, 53.63)),
+            airport("EDHF", Point::new(9.58, 53.99)),
+            airport("EDHL", Point::new(10.70, 53.81)),
+        ]);
+        let new = nd(vec![
+            airport("EDDH", Point::new(9.99, 53.63)),
+            // moved by about 1 km
+            airport("EDHF", Point::new(9.58, 54.0)),
+            airport("EDDL", Point::new(6.77, 51.29)),
+        ]);
+
+        let diff = old.diff(&new);
+
+        let idents = |fixes: &[NavAid]| fixes.iter().map(Fix::ident).collect::<Vec<_>>();
+        assert_eq!(idents(diff.added()), vec!["EDDL"]);
+        assert_eq!(idents(diff.removed()), vec!["EDHL"]);
+        assert_eq!(diff.changed().len(), 1);
+        assert_eq!(diff.changed()[0].1.ident(), "EDHF");
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn diff_matches_waypoints_within_their_location() {
+        let nd = |waypoints: Vec<Waypoint>| {
+            let mut builder = NavigationData::builder();
+            waypoints
+                .into_iter()
+                .for_each(|wp| builder.add_waypoint(wp));
+            builder.build()
+        };
+
+        let old = nd(vec![
+            enroute_waypoint("ALPHA", "ED", Point::new(10.0, 53.0)),
+            enroute_waypoint("ALPHA", "LF", Point::new(2.0, 47.0)),
+        ]);
+        let new = nd(vec![
+            enroute_waypoint("ALPHA", "ED", Point::new(10.0, 53.0)),
+            // moved by about 1 km
+            enroute_waypoint("ALPHA", "LF", Point::new(2.0, 47.01)),
+        ]);
+
+        let diff = old.diff(&new);
+
+        assert!(diff.added().is_empty());
+        assert!(diff.removed().is_empty());
+        assert_eq!(diff.changed().len(), 1);
+        assert_eq!(diff.changed()[0].1.coordinate(), Point::new(2.0, 47.01));
+    }
+}
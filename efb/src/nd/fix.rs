@@ -20,6 +20,14 @@ use geo::Point;
 pub trait Fix: Sized {
     fn ident(&self) -> String;
 
+    /// Returns a label to display the fix.
+    ///
+    /// The label defaults to the ident, but fixes with a name extend it, e.g.
+    /// `EDDH — HAMBURG`.
+    fn label(&self) -> String {
+        self.ident()
+    }
+
     fn coordinate(&self) -> Point<f64>;
 
     fn mag_var(&self) -> MagneticVariation {
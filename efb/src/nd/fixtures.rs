@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal navigation data records for tests.
+//!
+//! Each builder returns a record with neutral defaults. Tests change the
+//! fields they depend on with the struct update syntax, e.g.
+//! `Airspace { floor: VerticalDistance::Gnd, ..airspace("CTR", polygon) }`.
+
+use geo::{Point, Polygon};
+
+use crate::measurements::{Angle, Length};
+use crate::VerticalDistance;
+
+use super::{
+    Airport, Airspace, AirspaceClassification, AirspaceType, Runway, RunwaySurface, Waypoint,
+};
+
+/// Returns an airport at ground level without runways, gates or frequencies.
+pub(crate) fn airport(ident: &str, coordinate: Point<f64>) -> Airport {
+    Airport {
+        icao_ident: ident.to_string(),
+        iata_designator: String::new(),
+        name: ident.to_string(),
+        coordinate,
+        mag_var: None,
+        elevation: VerticalDistance::Gnd,
+        runways: Vec::new(),
+        gates: Vec::new(),
+        frequencies: Vec::new(),
+        location: None,
+        cycle: None,
+    }
+}
+
+/// Returns a level asphalt runway at ground level without any length.
+pub(crate) fn runway(designator: &str) -> Runway {
+    Runway {
+        designator: designator.to_string(),
+        bearing: Angle::t(0.0),
+        length: Length::m(0.0),
+        tora: Length::m(0.0),
+        toda: Length::m(0.0),
+        lda: Length::m(0.0),
+        surface: RunwaySurface::Asphalt,
+        slope: 0.0,
+        elev: VerticalDistance::Gnd,
+    }
+}
+
+/// Returns a class D control area from 1500 ft MSL to FL 65.
+pub(crate) fn airspace(name: &str, polygon: Polygon<f64>) -> Airspace {
+    Airspace::new(
+        name.to_string(),
+        AirspaceType::CTA,
+        Some(AirspaceClassification::D),
+        VerticalDistance::Fl(65),
+        VerticalDistance::Msl(1500),
+        polygon,
+    )
+}
+
+/// Returns an enroute waypoint without description.
+pub(crate) fn waypoint(ident: &str, coordinate: Point<f64>) -> Waypoint {
+    Waypoint::new(ident.to_string(), String::new(), coordinate)
+}
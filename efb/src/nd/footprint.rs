@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl NavigationData {
+    /// Returns the approximate memory footprint of the navigation data in
+    /// bytes.
+    ///
+    /// The footprint includes the airports with their runways, gates and
+    /// frequencies, the waypoints, the vertices of the airspace polygons, the
+    /// spatial indices and all partitions. Allocator overhead and spare
+    /// capacity of the collections are not counted. Use it to decide which
+    /// data to load, e.g. on an embedded target.
+    pub fn approx_size_bytes(&self) -> usize {
+        let airports: usize = self
+            .airports
+            .iter()
+            .map(|arpt| rc_size::<Airport>() + airport_heap_size(arpt))
+            .sum();
+
+        let waypoints: usize = self
+            .waypoints
+            .iter()
+            .map(|wp| rc_size::<Waypoint>() + waypoint_heap_size(wp))
+            .sum();
+
+        let terminal_waypoints: usize = self
+            .terminal_waypoints
+            .iter()
+            .map(|(airport, wps)| {
+                size_of::<(String, Vec<Shared<Waypoint>>)>()
+                    + airport.len()
+                    + wps
+                        .iter()
+                        .map(|wp| rc_size::<Waypoint>() + waypoint_heap_size(wp))
+                        .sum::<usize>()
+            })
+            .sum();
+
+        let airspaces: usize = self
+            .airspaces
+            .iter()
+            .map(|airspace| rc_size::<Airspace>() + airspace_heap_size(airspace))
+            .sum();
+
+        let partitions: usize = self
+            .partitions
+            .values()
+            .map(|partition| size_of::<u64>() + partition.approx_size_bytes())
+            .sum();
+
+        size_of::<Self>()
+            + airports
+            + waypoints
+            + terminal_waypoints
+            + airspaces
+            + self.airspace_index.approx_size_bytes()
+            + self.navaid_index.approx_size_bytes()
+            + self.locations.len() * size_of::<LocationIndicator>()
+            + self.errors.len() * size_of::<Error>()
+            + partitions
+    }
+}
+
+/// Returns the size of a `Shared<T>`'s pointer and its allocation with the
+/// reference counts.
+fn rc_size<T>() -> usize {
+    size_of::<Shared<T>>() + 2 * size_of::<usize>() + size_of::<T>()
+}
+
+fn airport_heap_size(arpt: &Airport) -> usize {
+    let runways: usize = arpt
+        .runways
+        .iter()
+        .map(|rwy| size_of::<Runway>() + rwy.designator.len())
+        .sum();
+
+    let gates: usize = arpt
+        .gates
+        .iter()
+        .map(|gate| size_of::<Gate>() + gate.ident.len() + gate.name.len())
+        .sum();
+
+    let frequencies: usize = arpt
+        .frequencies
+        .iter()
+        .map(|freq| size_of::<CommunicationFrequency>() + freq.callsign.len())
+        .sum();
+
+    arpt.icao_ident.len()
+        + arpt.iata_designator.len()
+        + arpt.name.len()
+        + runways
+        + gates
+        + frequencies
+}
+
+fn waypoint_heap_size(wp: &Waypoint) -> usize {
+    wp.fix_ident.len() + wp.desc.len()
+}
+
+fn airspace_heap_size(airspace: &Airspace) -> usize {
+    let vertices = airspace.polygon.exterior().0.len()
+        + airspace
+            .polygon
+            .interiors()
+            .iter()
+            .map(|ring| ring.0.len())
+            .sum::<usize>();
+
+    airspace.name.len()
+        + vertices * size_of::<Coord<f64>>()
+        + airspace.raw_boundary.as_ref().map_or(0, |boundary| {
+            boundary.len() * size_of::<BoundaryRecordSummary>()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CTR: &str = r#"AC D
+AN CTR HAMBURG
+AH 2500msl
+AL GND
+DP 53:42:00 N 9:48:00 E
+DP 53:42:00 N 10:12:00 E
+DP 53:33:00 N 10:12:00 E
+DP 53:33:00 N 9:48:00 E
+DP 53:42:00 N 9:48:00 E
+"#;
+
+    const TMA: &str = r#"AC D
+AN TMA BREMEN A
+AH FL 65
+AL 1500msl
+DP 53:06:04 N 8:58:30 E
+DP 53:06:10 N 9:04:45 E
+DP 52:58:13 N 9:05:04 E
+DP 52:58:08 N 8:58:56 E
+DP 53:06:04 N 8:58:30 E
+"#;
+
+    #[test]
+    fn larger_data_has_larger_footprint() {
+        let small = NavigationData::try_from_openair(CTR).expect("OpenAir should parse");
+        let large = NavigationData::try_from_openair(&format!("{CTR}\n{TMA}"))
+            .expect("OpenAir should parse");
+
+        assert!(NavigationData::new().approx_size_bytes() < small.approx_size_bytes());
+        assert!(small.approx_size_bytes() < large.approx_size_bytes());
+
+        let mut merged = small.clone();
+        merged.append(NavigationData::try_from_openair(TMA).expect("OpenAir should parse"));
+        assert!(small.approx_size_bytes() < merged.approx_size_bytes());
+    }
+}
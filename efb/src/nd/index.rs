@@ -15,7 +15,7 @@
 
 //! Spatial indexing for efficient airspace and navaid queries.
 
-use std::rc::Rc;
+use std::collections::HashSet;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -24,7 +24,8 @@ use geo::{Distance, Geodesic, Point};
 use rstar::primitives::{GeomWithData, Rectangle};
 use rstar::{RTree, RTreeObject, AABB};
 
-use super::{Airport, Airspace, NavAid, Waypoint};
+use super::{Airport, Airspace, NavAid, Shared, Waypoint};
+use crate::geom::{split_envelope, unwrap_polygon, wrap_longitude};
 use crate::measurements::{Length, LengthUnit};
 
 /// Approximate conversion factor: 1 nautical mile ≈ 1/60 degree.
@@ -34,19 +35,25 @@ const NM_TO_DEG: f64 = 1.0 / 60.0;
 ///
 /// The index stores bounding boxes of airspaces, allowing quick filtering
 /// of candidates before performing precise polygon containment checks.
+/// Airspaces crossing the antimeridian are indexed with one bounding box
+/// east and one west of the antimeridian.
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AirspaceIndex {
-    tree: RTree<GeomWithData<Rectangle<Point<f64>>, Rc<Airspace>>>,
+    tree: RTree<GeomWithData<Rectangle<Point<f64>>, Shared<Airspace>>>,
 }
 
 impl AirspaceIndex {
     /// Creates an index from an iterator of airspaces.
-    pub fn new<'a>(airspaces: impl Iterator<Item = &'a Rc<Airspace>>) -> Self {
+    pub fn new<'a>(airspaces: impl Iterator<Item = &'a Shared<Airspace>>) -> Self {
         let entries = airspaces
-            .map(|a| {
-                let rect = Rectangle::from_aabb(a.polygon.envelope());
-                GeomWithData::new(rect, Rc::clone(a))
+            .flat_map(|a| {
+                let reference = a.polygon.exterior().0.first().map_or(0.0, |c| c.x);
+                let envelope = unwrap_polygon(&a.polygon, reference).envelope();
+
+                split_envelope(&envelope).into_iter().map(|envelope| {
+                    GeomWithData::new(Rectangle::from_aabb(envelope), Shared::clone(a))
+                })
             })
             .collect();
 
@@ -56,21 +63,35 @@ impl AirspaceIndex {
     }
 
     /// Returns airspaces whose bounding boxes contain the point.
-    pub fn candidates_at(&self, lon: f64, lat: f64) -> impl Iterator<Item = &Rc<Airspace>> + '_ {
-        let point_envelope = AABB::from_point(Point::new(lon, lat));
-        self.tree
-            .locate_in_envelope_intersecting(&point_envelope)
-            .map(|entry| &entry.data)
+    pub fn candidates_at(
+        &self,
+        lon: f64,
+        lat: f64,
+    ) -> impl Iterator<Item = &Shared<Airspace>> + '_ {
+        let point_envelope = AABB::from_point(Point::new(wrap_longitude(lon), lat));
+        self.candidates_intersecting(&point_envelope)
     }
 
     /// Returns airspaces whose bounding boxes intersect the given envelope.
+    ///
+    /// The envelope's longitudes may exceed -180°..180° if it reaches across
+    /// the antimeridian. Each airspace is returned only once.
     pub fn candidates_intersecting(
         &self,
         envelope: &AABB<Point<f64>>,
-    ) -> impl Iterator<Item = &Rc<Airspace>> + '_ {
-        self.tree
-            .locate_in_envelope_intersecting(envelope)
+    ) -> impl Iterator<Item = &Shared<Airspace>> + '_ {
+        let mut seen = HashSet::new();
+
+        split_envelope(envelope)
+            .into_iter()
+            .flat_map(|envelope| self.tree.locate_in_envelope_intersecting(&envelope))
             .map(|entry| &entry.data)
+            .filter(move |airspace| seen.insert(Shared::as_ptr(airspace)))
+    }
+
+    /// Returns the approximate heap usage of the index entries in bytes.
+    pub fn approx_size_bytes(&self) -> usize {
+        self.tree.size() * size_of::<GeomWithData<Rectangle<Point<f64>>, Shared<Airspace>>>()
     }
 }
 
@@ -87,22 +108,22 @@ pub struct NavAidIndex {
 impl NavAidIndex {
     /// Creates an index from iterators of airports and waypoints.
     pub fn new<'a>(
-        airports: impl Iterator<Item = &'a Rc<Airport>>,
-        waypoints: impl Iterator<Item = &'a Rc<Waypoint>>,
+        airports: impl Iterator<Item = &'a Shared<Airport>>,
+        waypoints: impl Iterator<Item = &'a Shared<Waypoint>>,
     ) -> Self {
         let mut entries = Vec::new();
 
         for airport in airports {
             entries.push(GeomWithData::new(
                 airport.coordinate,
-                NavAid::Airport(Rc::clone(airport)),
+                NavAid::Airport(Shared::clone(airport)),
             ));
         }
 
         for waypoint in waypoints {
             entries.push(GeomWithData::new(
                 waypoint.coordinate,
-                NavAid::Waypoint(Rc::clone(waypoint)),
+                NavAid::Waypoint(Shared::clone(waypoint)),
             ));
         }
 
@@ -121,81 +142,77 @@ impl NavAidIndex {
         coord: &Point<f64>,
         radius: Length,
     ) -> impl Iterator<Item = &NavAid> {
-        let radius_nm = *radius.convert_to(LengthUnit::NauticalMiles).value() as f64;
-        let radius_deg = radius_nm * NM_TO_DEG;
-
-        // Create bounding box around the point
-        // Adjust longitude expansion for latitude (degrees are smaller near poles)
-        let lat_rad = coord.y().to_radians();
-        let lon_expansion = if lat_rad.cos().abs() > 0.01 {
-            radius_deg / lat_rad.cos()
-        } else {
-            radius_deg * 100.0 // Near poles, use large expansion
-        };
-
-        let envelope = AABB::from_corners(
-            Point::new(coord.x() - lon_expansion, coord.y() - radius_deg),
-            Point::new(coord.x() + lon_expansion, coord.y() + radius_deg),
-        );
-
         let center = *coord;
         let radius_m = radius.to_si() as f64;
 
-        self.tree
-            .locate_in_envelope_intersecting(&envelope)
+        split_envelope(&radius_envelope(coord, radius))
+            .into_iter()
+            .flat_map(|envelope| self.tree.locate_in_envelope_intersecting(&envelope))
             .filter(move |entry| Geodesic.distance(center, *entry.geom()) <= radius_m)
             .map(|entry| &entry.data)
     }
+
+    /// Returns the navaid nearest to a coordinate within the given radius.
+    pub fn nearest_within(&self, coord: &Point<f64>, radius: Length) -> Option<&NavAid> {
+        let center = *coord;
+        let radius_m = radius.to_si() as f64;
+
+        split_envelope(&radius_envelope(coord, radius))
+            .into_iter()
+            .flat_map(|envelope| self.tree.locate_in_envelope_intersecting(&envelope))
+            .map(|entry| (Geodesic.distance(center, *entry.geom()), &entry.data))
+            .filter(|(dist, _)| *dist <= radius_m)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, navaid)| navaid)
+    }
+
+    /// Returns the approximate heap usage of the index entries in bytes.
+    pub fn approx_size_bytes(&self) -> usize {
+        self.tree.size() * size_of::<GeomWithData<Point<f64>, NavAid>>()
+    }
+}
+
+/// Returns an approximate degree-based bounding box of the radius around a
+/// coordinate.
+fn radius_envelope(coord: &Point<f64>, radius: Length) -> AABB<Point<f64>> {
+    let radius_nm = *radius.convert_to(LengthUnit::NauticalMiles).value() as f64;
+    let radius_deg = radius_nm * NM_TO_DEG;
+
+    // Adjust longitude expansion for latitude (degrees are smaller near poles)
+    let lat_rad = coord.y().to_radians();
+    let lon_expansion = if lat_rad.cos().abs() > 0.01 {
+        radius_deg / lat_rad.cos()
+    } else {
+        radius_deg * 100.0 // Near poles, use large expansion
+    };
+
+    AABB::from_corners(
+        Point::new(coord.x() - lon_expansion, coord.y() - radius_deg),
+        Point::new(coord.x() + lon_expansion, coord.y() + radius_deg),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::nd::{AirspaceClassification, AirspaceType};
-    use crate::VerticalDistance;
+    use crate::geom::{coords_from_lat_lon_pairs, point_from_lat_lon};
+    use crate::nd::fixtures;
 
-    fn test_airspace(name: &str, coords: &[(f64, f64)]) -> Rc<Airspace> {
-        let exterior: Vec<geo::Coord<f64>> = coords
-            .iter()
-            .map(|&(lat, lon)| geo::Coord { x: lon, y: lat })
-            .collect();
+    fn test_airspace(name: &str, coords: &[(f64, f64)]) -> Shared<Airspace> {
+        let exterior = coords_from_lat_lon_pairs(coords);
 
-        Rc::new(Airspace {
-            name: name.to_string(),
-            airspace_type: AirspaceType::CTA,
-            classification: Some(AirspaceClassification::D),
-            ceiling: VerticalDistance::Fl(65),
-            floor: VerticalDistance::Msl(1500),
-            polygon: geo::Polygon::new(geo::LineString::from(exterior), vec![]),
-        })
+        Shared::new(fixtures::airspace(
+            name,
+            geo::Polygon::new(geo::LineString::from(exterior), vec![]),
+        ))
     }
 
-    fn test_airport(ident: &str, lat: f64, lon: f64) -> Rc<Airport> {
-        Rc::new(Airport {
-            icao_ident: ident.to_string(),
-            iata_designator: String::new(),
-            name: ident.to_string(),
-            coordinate: Point::new(lon, lat),
-            mag_var: None,
-            elevation: VerticalDistance::Gnd,
-            runways: vec![],
-            location: None,
-            cycle: None,
-        })
+    fn test_airport(ident: &str, lat: f64, lon: f64) -> Shared<Airport> {
+        Shared::new(fixtures::airport(ident, point_from_lat_lon(lat, lon)))
     }
 
-    fn test_waypoint(ident: &str, lat: f64, lon: f64) -> Rc<Waypoint> {
-        use crate::nd::waypoint::{Region, WaypointUsage};
-        Rc::new(Waypoint {
-            fix_ident: ident.to_string(),
-            desc: String::new(),
-            usage: WaypointUsage::Unknown,
-            coordinate: Point::new(lon, lat),
-            mag_var: None,
-            region: Region::Enroute,
-            location: None,
-            cycle: None,
-        })
+    fn test_waypoint(ident: &str, lat: f64, lon: f64) -> Shared<Waypoint> {
+        Shared::new(fixtures::waypoint(ident, point_from_lat_lon(lat, lon)))
     }
 
     #[test]
@@ -206,7 +223,7 @@ mod tests {
         //  53.5    |        x        |  x = query point (9.5, 53.5)
         //          |                 |
         //  53.0    +--------+--------+
-        let airspaces = vec![test_airspace(
+        let airspaces = [test_airspace(
             "Test",
             &[
                 (53.0, 9.0),
@@ -221,7 +238,7 @@ mod tests {
 
         let candidates: Vec<_> = index.candidates_at(9.5, 53.5).collect();
         assert_eq!(candidates.len(), 1);
-        assert!(Rc::ptr_eq(candidates[0], &airspaces[0]));
+        assert!(Shared::ptr_eq(candidates[0], &airspaces[0]));
     }
 
     #[test]
@@ -231,7 +248,7 @@ mod tests {
         //              |               |
         //  53.0        +---------------+
         //  52.0  x                        x = query point (8.0, 52.0)
-        let airspaces = vec![test_airspace(
+        let airspaces = [test_airspace(
             "Test",
             &[
                 (53.0, 9.0),
@@ -248,6 +265,33 @@ mod tests {
         assert!(candidates.is_empty());
     }
 
+    #[test]
+    fn index_finds_airspace_crossing_antimeridian() {
+        //        179.5E      180      179.5W
+        //  -16.0   +----------+----------+
+        //          |    x     |     x    |  x = query points (179.8, -179.8)
+        //  -18.0   +----------+----------+
+        let airspaces = [test_airspace(
+            "Test",
+            &[
+                (-18.0, 179.5),
+                (-18.0, -179.5),
+                (-16.0, -179.5),
+                (-16.0, 179.5),
+                (-18.0, 179.5),
+            ],
+        )];
+
+        let index = AirspaceIndex::new(airspaces.iter());
+
+        assert_eq!(index.candidates_at(179.8, -17.0).count(), 1);
+        assert_eq!(index.candidates_at(-179.8, -17.0).count(), 1);
+        assert_eq!(index.candidates_at(0.0, -17.0).count(), 0);
+
+        let envelope = AABB::from_corners(Point::new(179.0, -18.0), Point::new(181.0, -16.0));
+        assert_eq!(index.candidates_intersecting(&envelope).count(), 1);
+    }
+
     #[test]
     fn point_index_finds_airports_within_radius() {
         //           9.99          10.70
@@ -255,11 +299,11 @@ mod tests {
         //            .---.
         //  53.63    (EDDH )   10 NM radius finds only EDDH
         //            '---'    50 NM radius finds both
-        let airports = vec![
+        let airports = [
             test_airport("EDDH", 53.63, 9.99),  // Hamburg
             test_airport("EDHL", 53.81, 10.70), // Luebeck (~35 NM from Hamburg)
         ];
-        let waypoints: Vec<Rc<Waypoint>> = vec![];
+        let waypoints: Vec<Shared<Waypoint>> = vec![];
 
         let index = NavAidIndex::new(airports.iter(), waypoints.iter());
 
@@ -280,8 +324,8 @@ mod tests {
         //  53.6          .---WP2-.
         //  53.55        ( center  )  10 NM radius
         //  53.5          '-WP1---'
-        let airports: Vec<Rc<Airport>> = vec![];
-        let waypoints = vec![
+        let airports: Vec<Shared<Airport>> = vec![];
+        let waypoints = [
             test_waypoint("WP1", 53.5, 9.5),
             test_waypoint("WP2", 53.6, 9.6),
             test_waypoint("WP3", 54.5, 10.5),
@@ -304,8 +348,8 @@ mod tests {
         //  53.63          .--EDDH--.
         //  53.62         ( center   )  5 NM radius
         //  53.60          '-DHN1---'
-        let airports = vec![test_airport("EDDH", 53.63, 9.99)];
-        let waypoints = vec![test_waypoint("DHN1", 53.60, 9.95)];
+        let airports = [test_airport("EDDH", 53.63, 9.99)];
+        let waypoints = [test_waypoint("DHN1", 53.60, 9.95)];
 
         let index = NavAidIndex::new(airports.iter(), waypoints.iter());
 
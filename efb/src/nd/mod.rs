@@ -17,47 +17,77 @@
 
 use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::rc::Rc;
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc as SharedPointer;
+#[cfg(feature = "sync")]
+use std::sync::Arc as SharedPointer;
 
 use log::{debug, trace, warn};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use geo::{Contains, Point};
+use geo::{Bearing, Contains, Coord, Distance, Geodesic, Point, Rect};
 use rstar::AABB;
 
 use crate::error::Error;
-use crate::measurements::Length;
-use crate::MagneticVariation;
+use crate::geom::{midpoint, unwrap_polygon};
+use crate::measurements::{Angle, Length, LengthUnit, Pressure, Speed};
+use crate::route::VerticalProfile;
+use crate::{MagneticVariation, VerticalDistance, Wind};
 
 mod airac_cycle;
 mod airport;
 mod airspace;
 mod builder;
+mod communication;
 mod convert;
+mod diff;
 mod fix;
+mod footprint;
+mod gate;
 mod index;
 mod location;
 mod navaid;
 mod runway;
+mod validation;
 mod waypoint;
 
 #[cfg(feature = "sqlite")]
 pub mod db;
 
+#[cfg(test)]
+pub(crate) mod fixtures;
+
 pub use airac_cycle::{AiracCycle, CycleValidity};
 pub use airport::Airport;
-pub use airspace::{Airspace, AirspaceClassification, AirspaceType};
+pub use airspace::{
+    Airspace, AirspaceClassification, AirspaceType, BoundaryPath, BoundaryRecordSummary,
+};
+pub use communication::{CommunicationFrequency, CommunicationType};
+pub use convert::Arinc424Options;
+pub use diff::{NavDataDiff, DIFF_TOLERANCE_M};
 pub use fix::Fix;
+pub use gate::Gate;
 pub use location::LocationIndicator;
 pub use navaid::NavAid;
 pub use runway::*;
+pub use validation::ValidationIssue;
 pub use waypoint::*;
 
 pub(crate) use builder::NavigationDataBuilder;
 pub(crate) use index::{AirspaceIndex, NavAidIndex};
 
+/// Initial search radius of the nearest airports.
+const NEAREST_AIRPORTS_RADIUS_NM: f32 = 25.0;
+
+/// Half of the earth's circumference beyond which any point is within reach.
+const HALF_EARTH_CIRCUMFERENCE_NM: f32 = 10_800.0;
+
+/// Height above an airport's elevation at which a glide should arrive, e.g. to
+/// join the traffic pattern.
+pub const GLIDE_ARRIVAL_HEIGHT_FT: f32 = 1000.0;
+
 /// The file format from which navigation data was parsed.
 #[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -69,7 +99,24 @@ pub enum SourceFormat {
     OpenAir,
 }
 
-type TerminalWaypoints = HashMap<String, Vec<Rc<Waypoint>>>;
+/// The pointer through which the records of the navigation data are shared,
+/// e.g. an airport between the navigation data and a route.
+///
+/// It is an [`Rc`](std::rc::Rc) by default. With the `sync` feature it is an
+/// [`Arc`](std::sync::Arc), which makes the navigation data and routes `Send`
+/// and `Sync`, e.g. to compute a profile on a worker thread.
+///
+/// # Compatibility
+///
+/// The `sync` feature isn't additive. Once any crate in the dependency graph
+/// enables it, code that names `Rc` for the shared records, e.g. a
+/// [`FixResolver`] returning `Rc::new(waypoint)`, stops compiling. Name
+/// `Shared` instead to compile with and without the feature.
+///
+/// [`FixResolver`]: crate::route::FixResolver
+pub type Shared<T> = SharedPointer<T>;
+
+type TerminalWaypoints = HashMap<String, Vec<Shared<Waypoint>>>;
 
 /// Results from a spatial query at a given point.
 ///
@@ -78,7 +125,7 @@ type TerminalWaypoints = HashMap<String, Vec<Rc<Waypoint>>>;
 #[derive(Clone, Debug, Default)]
 pub struct Nearby {
     /// Airspaces that contain the query point.
-    pub airspaces: Vec<Rc<Airspace>>,
+    pub airspaces: Vec<Shared<Airspace>>,
     /// Navaids within the search radius.
     pub navaids: Vec<NavAid>,
 }
@@ -98,13 +145,13 @@ impl Nearby {
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NavigationData {
-    airports: Vec<Rc<Airport>>,
-    airspaces: Vec<Rc<Airspace>>,
+    airports: Vec<Shared<Airport>>,
+    airspaces: Vec<Shared<Airspace>>,
     #[cfg_attr(feature = "serde", serde(skip))]
     airspace_index: AirspaceIndex,
     #[cfg_attr(feature = "serde", serde(skip))]
     navaid_index: NavAidIndex,
-    waypoints: Vec<Rc<Waypoint>>,
+    waypoints: Vec<Shared<Waypoint>>,
     terminal_waypoints: TerminalWaypoints,
     locations: Vec<LocationIndicator>,
     cycle: Option<AiracCycle>,
@@ -132,6 +179,18 @@ impl NavigationData {
         self.cycle.as_ref()
     }
 
+    /// Sets the AIRAC cycle of the navigation data.
+    ///
+    /// The cycle replaces the cycle derived from the loaded records. Use it to
+    /// stamp the known cycle on data whose source doesn't carry one, e.g.
+    /// [OpenAir].
+    ///
+    /// [OpenAir]: SourceFormat::OpenAir
+    pub fn with_cycle(mut self, cycle: AiracCycle) -> Self {
+        self.cycle = Some(cycle);
+        self
+    }
+
     /// Returns the [format] from which the navigation data was created.
     ///
     /// Returns `None` if the navigation data was created from multiple sources.
@@ -151,6 +210,9 @@ impl NavigationData {
     }
 
     /// Returns the identifier of the navigation data.
+    ///
+    /// The identifier is a hash of the source data. Thus, loading the same
+    /// data twice results in the same identifier.
     pub fn partition_id(&self) -> u64 {
         self.partition_id
     }
@@ -179,12 +241,7 @@ impl NavigationData {
     /// ```
     pub fn at(&self, point: &Point<f64>, radius: Length) -> Nearby {
         // Find airspaces containing the point
-        let airspaces: Vec<_> = self
-            .airspace_index
-            .candidates_at(point.x(), point.y())
-            .filter(|airspace| airspace.polygon.contains(point))
-            .cloned()
-            .collect();
+        let airspaces: Vec<_> = self.airspaces_at(point).cloned().collect();
 
         // Find navaids within radius
         let navaids: Vec<_> = self
@@ -196,18 +253,134 @@ impl NavigationData {
         Nearby { airspaces, navaids }
     }
 
+    /// Returns the airspaces of the `classes` that contain the point.
+    ///
+    /// Airspaces without a classification, e.g. restricted areas, are never
+    /// returned. Like with [`at`](Self::at), vertical bounds are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::nd::{AirspaceClassification::*, NavigationData};
+    /// # use geo::Point;
+    /// # fn controlled(nd: &NavigationData) {
+    /// let position = Point::new(9.99, 53.63); // (lon, lat)
+    ///
+    /// for airspace in nd.at_filtered(&position, &[A, B, C, D, E]) {
+    ///     println!("Controlled: {}", airspace.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn at_filtered(
+        &self,
+        point: &Point<f64>,
+        classes: &[AirspaceClassification],
+    ) -> Vec<&Airspace> {
+        self.airspaces_at(point)
+            .filter(|airspace| {
+                airspace
+                    .classification
+                    .is_some_and(|class| classes.contains(&class))
+            })
+            .map(Shared::as_ref)
+            .collect()
+    }
+
+    /// Returns the airspaces whose polygon contains the point.
+    fn airspaces_at(&self, point: &Point<f64>) -> impl Iterator<Item = &Shared<Airspace>> {
+        let point = *point;
+        self.airspace_index
+            .candidates_at(point.x(), point.y())
+            .filter(move |airspace| unwrap_polygon(&airspace.polygon, point.x()).contains(&point))
+    }
+
     /// Returns candidate airspaces whose bounding boxes intersect the given
     /// envelope.
     pub(crate) fn candidate_airspaces_for_envelope(
         &self,
         envelope: &AABB<Point<f64>>,
-    ) -> Vec<Rc<Airspace>> {
+    ) -> Vec<Shared<Airspace>> {
         self.airspace_index
             .candidates_intersecting(envelope)
             .cloned()
             .collect()
     }
 
+    /// Returns all airports and waypoints as navaids.
+    ///
+    /// The waypoints include enroute and terminal waypoints. Use this e.g. to
+    /// export all points or to build an own spatial index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::prelude::*;
+    /// # fn export(nd: &NavigationData) {
+    /// for fix in nd.fixes() {
+    ///     println!("{} at {:?}", fix.ident(), fix.coordinate());
+    /// }
+    /// # }
+    /// ```
+    pub fn fixes(&self) -> impl Iterator<Item = NavAid> + '_ {
+        let terminal_waypoints = std::iter::once(&self.terminal_waypoints)
+            .chain(
+                self.partitions
+                    .values()
+                    .map(|partition| &partition.terminal_waypoints),
+            )
+            .flat_map(|terminal_waypoints| terminal_waypoints.values().flatten());
+
+        self.airports()
+            .map(|arpt| NavAid::Airport(Shared::clone(arpt)))
+            .chain(
+                self.waypoints()
+                    .chain(terminal_waypoints)
+                    .map(|wp| NavAid::Waypoint(Shared::clone(wp))),
+            )
+    }
+
+    /// Returns all runways with the airport they belong to, e.g. to export a
+    /// runway database.
+    pub fn runways(&self) -> impl Iterator<Item = (&Airport, &Runway)> {
+        self.airports()
+            .flat_map(|arpt| arpt.runways.iter().map(move |rwy| (arpt.as_ref(), rwy)))
+    }
+
+    /// Returns the airspaces of all partitions, e.g. to render them on a map.
+    pub fn all_airspaces(&self) -> impl Iterator<Item = &Airspace> {
+        self.airspaces().map(Shared::as_ref)
+    }
+
+    /// Returns the bounding box of all fixes and airspaces, e.g. to zoom a map
+    /// to the loaded data.
+    ///
+    /// The longitudes of the box are within -180°..180°. Thus, data on both
+    /// sides of the antimeridian results in a box that spans the globe.
+    ///
+    /// Returns `None` if there is no fix or airspace.
+    pub fn bounds(&self) -> Option<Rect<f64>> {
+        let fixes = self.fixes().map(|fix| fix.coordinate().0);
+        let airspaces = self
+            .airspaces()
+            .flat_map(|airspace| airspace.polygon.exterior().coords().copied());
+
+        fixes.chain(airspaces).fold(None, |bounds, c| {
+            Some(match bounds {
+                None => Rect::new(c, c),
+                Some(bounds) => Rect::new(
+                    Coord {
+                        x: bounds.min().x.min(c.x),
+                        y: bounds.min().y.min(c.y),
+                    },
+                    Coord {
+                        x: bounds.max().x.max(c.x),
+                        y: bounds.max().y.max(c.y),
+                    },
+                ),
+            })
+        })
+    }
+
     /// Searches for a navigation aid by identifier.
     ///
     /// Searches waypoints first, then airports. Returns the first match found.
@@ -230,11 +403,11 @@ impl NavigationData {
         let result = self
             .waypoints()
             .find(|&wp| wp.ident() == ident)
-            .map(|wp| NavAid::Waypoint(Rc::clone(wp)))
+            .map(|wp| NavAid::Waypoint(Shared::clone(wp)))
             .or(self
                 .airports()
                 .find(|&arpt| arpt.ident() == ident)
-                .map(|arpt| NavAid::Airport(Rc::clone(arpt))));
+                .map(|arpt| NavAid::Airport(Shared::clone(arpt))));
 
         match &result {
             Some(navaid) => trace!("found navaid for ident {:?}: {}", ident, navaid.ident()),
@@ -244,6 +417,273 @@ impl NavigationData {
         result
     }
 
+    /// Searches for the navaid nearest to the coordinate.
+    ///
+    /// Returns `None` if no airport or waypoint is within the `tolerance`. Use
+    /// this to associate coordinates, e.g. of an imported flight plan, with
+    /// known navaids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::prelude::*;
+    /// # use geo::Point;
+    /// # fn search(nd: &NavigationData) {
+    /// let coord = Point::new(9.99, 53.63); // (lon, lat)
+    ///
+    /// if let Some(navaid) = nd.find_at(&coord, Length::m(100.0)) {
+    ///     println!("Found: {}", navaid.ident());
+    /// }
+    /// # }
+    /// ```
+    pub fn find_at(&self, coord: &Point<f64>, tolerance: Length) -> Option<NavAid> {
+        let result = self.navaid_index.nearest_within(coord, tolerance).cloned();
+
+        match &result {
+            Some(navaid) => trace!("found navaid at {:?}: {}", coord, navaid.ident()),
+            None => trace!("no navaid found at {:?}", coord),
+        }
+
+        result
+    }
+
+    /// Searches for an airspace by name, e.g. `ED-R146`.
+    ///
+    /// The name is compared case-insensitive and surrounding whitespace is
+    /// ignored. Returns the first match of all partitions.
+    pub fn airspace_by_name(&self, name: &str) -> Option<&Airspace> {
+        let name = name.trim().to_lowercase();
+        self.airspaces()
+            .find(|airspace| airspace.name.trim().to_lowercase() == name)
+            .map(Shared::as_ref)
+    }
+
+    /// Returns the `n` airports nearest to the point with their distance sorted
+    /// ascending.
+    ///
+    /// With [`Some`] minimum runway length, only airports with a runway whose
+    /// landing distance available (LDA) is at least that long are returned.
+    /// Use this to find an airport to land at, e.g. in an emergency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::prelude::*;
+    /// # use geo::Point;
+    /// # fn nearest(nd: &NavigationData) {
+    /// let position = Point::new(9.99, 53.63); // (lon, lat)
+    ///
+    /// for (arpt, dist) in nd.nearest_airports(&position, 3, Some(Length::m(600.0))) {
+    ///     println!("{} in {:.1}", arpt.ident(), dist);
+    /// }
+    /// # }
+    /// ```
+    pub fn nearest_airports(
+        &self,
+        point: &Point<f64>,
+        n: usize,
+        min_runway: Option<Length>,
+    ) -> Vec<(Shared<Airport>, Length)> {
+        let qualifies = |arpt: &Airport| {
+            min_runway.is_none_or(|min| arpt.runways.iter().any(|rwy| rwy.lda >= min))
+        };
+
+        // widen the search until enough airports are found or the radius spans
+        // the entire globe
+        let mut radius_nm = NEAREST_AIRPORTS_RADIUS_NM;
+
+        loop {
+            let mut airports: Vec<_> = self
+                .navaid_index
+                .within_radius(point, Length::nm(radius_nm))
+                .filter_map(|navaid| match navaid {
+                    NavAid::Airport(arpt) if qualifies(arpt) => Some(arpt),
+                    _ => None,
+                })
+                .map(|arpt| {
+                    let dist = Length::m(Geodesic.distance(*point, arpt.coordinate) as f32)
+                        .convert_to(LengthUnit::NauticalMiles);
+                    (Shared::clone(arpt), dist)
+                })
+                .collect();
+
+            if airports.len() >= n || radius_nm >= HALF_EARTH_CIRCUMFERENCE_NM {
+                airports.sort_by(|(_, a), (_, b)| a.to_si().total_cmp(&b.to_si()));
+                airports.truncate(n);
+                trace!("found {} airport(s) nearest to {:?}", airports.len(), point);
+                return airports;
+            }
+
+            radius_nm *= 2.0;
+        }
+    }
+
+    /// Returns the airports that are reachable in a glide from the point,
+    /// sorted by their distance.
+    ///
+    /// The glide starts at the `altitude` above mean sea level and ends
+    /// [`GLIDE_ARRIVAL_HEIGHT_FT`] above the airport's elevation. The still
+    /// air range given by the `glide_ratio` is corrected for the ground speed
+    /// along the direct track to each airport flown at the `glide_speed` with
+    /// the `wind`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use efb::prelude::*;
+    /// # use efb::Wind;
+    /// # use geo::Point;
+    /// # fn glide(nd: &NavigationData) -> Result<(), Error> {
+    /// let position = Point::new(9.99, 53.63); // (lon, lat)
+    /// let wind = Wind::from_str("29020KT")?;
+    ///
+    /// for arpt in nd.reachable_airports(
+    ///     &position,
+    ///     VerticalDistance::Altitude(4500),
+    ///     9.0,
+    ///     Speed::kt(65.0),
+    ///     wind,
+    /// ) {
+    ///     println!("{} is within glide range", arpt.ident());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reachable_airports(
+        &self,
+        point: &Point<f64>,
+        altitude: VerticalDistance,
+        glide_ratio: f32,
+        glide_speed: Speed,
+        wind: Wind,
+    ) -> Vec<Shared<Airport>> {
+        let to_msl = |vd: &VerticalDistance| {
+            vd.to_msl(Pressure::STD, Length::ft(0.0))
+                .map(|alt| alt.to_si())
+        };
+
+        let Some(altitude) = to_msl(&altitude) else {
+            return Vec::new();
+        };
+
+        let tas = glide_speed.to_si();
+        let arrival_height = Length::ft(GLIDE_ARRIVAL_HEIGHT_FT).to_si();
+
+        // the farthest glide is with the wind straight from behind
+        let max_range = Length::m(altitude * glide_ratio * (tas + wind.speed.to_si()) / tas);
+
+        let mut airports: Vec<_> = self
+            .navaid_index
+            .within_radius(point, max_range)
+            .filter_map(|navaid| match navaid {
+                NavAid::Airport(arpt) => Some(arpt),
+                _ => None,
+            })
+            .filter_map(|arpt| {
+                let height = altitude - to_msl(&arpt.elevation)? - arrival_height;
+                let bearing = Angle::t(Geodesic.bearing(*point, arpt.coordinate) as f32);
+                let dist = Geodesic.distance(*point, arpt.coordinate) as f32;
+
+                // ground speed along the track correcting for the crosswind
+                let crosswind = wind.crosswind(&bearing).to_si();
+                let gs =
+                    (tas * tas - crosswind * crosswind).sqrt() - wind.headwind(&bearing).to_si();
+
+                let range = height * glide_ratio * gs / tas;
+                (height > 0.0 && gs > 0.0 && dist <= range).then(|| (Shared::clone(arpt), dist))
+            })
+            .collect();
+
+        airports.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        trace!("{} airport(s) reachable from {:?}", airports.len(), point);
+
+        airports.into_iter().map(|(arpt, _)| arpt).collect()
+    }
+
+    /// Suggests a navaid to route via to avoid airspaces of some classes.
+    ///
+    /// The navaids within a circle around the midpoint of the direct route
+    /// from `from` to `to` are tested as intermediate fix. The navaid whose
+    /// route penetrates the least distance of `avoid`ed airspaces is returned,
+    /// where ties are broken by the shorter route. Airspaces are avoided
+    /// regardless of their vertical limits.
+    ///
+    /// Returns `None` if the direct route doesn't penetrate any avoided
+    /// airspace or if no navaid reduces the penetration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::nd::{AirspaceClassification, NavAid, NavigationData};
+    /// # fn avoid(nd: &NavigationData, from: &NavAid, to: &NavAid) {
+    /// use efb::nd::Fix;
+    ///
+    /// let avoid = [AirspaceClassification::C, AirspaceClassification::D];
+    ///
+    /// if let Some(via) = nd.suggest_avoidance(from, to, &avoid) {
+    ///     println!("Route via {}", via.ident());
+    /// }
+    /// # }
+    /// ```
+    pub fn suggest_avoidance(
+        &self,
+        from: &NavAid,
+        to: &NavAid,
+        avoid: &[AirspaceClassification],
+    ) -> Option<NavAid> {
+        let (start, end) = (from.coordinate(), to.coordinate());
+
+        // the distance flown through avoided airspaces along the points
+        let penetration = |points: &[Point<f64>]| -> f32 {
+            VerticalProfile::intersections_along(points, self)
+                .iter()
+                .filter(|i| {
+                    i.airspace()
+                        .classification
+                        .is_some_and(|c| avoid.contains(&c))
+                })
+                .map(|i| i.length().to_si())
+                .sum()
+        };
+
+        let direct = penetration(&[start, end]);
+        if direct <= 0.0 {
+            trace!(
+                "direct route {} -> {} avoids airspaces",
+                from.ident(),
+                to.ident()
+            );
+            return None;
+        }
+
+        let radius = Length::m(Geodesic.distance(start, end) as f32 / 2.0);
+
+        let (penetration, _, via) = self
+            .navaid_index
+            .within_radius(&midpoint(start, end), radius)
+            .filter(|navaid| *navaid != from && *navaid != to)
+            .map(|navaid| {
+                let via = navaid.coordinate();
+                let dist = Geodesic.distance(start, via) + Geodesic.distance(via, end);
+                (penetration(&[start, via, end]), dist, navaid)
+            })
+            .min_by(|(a, a_dist, _), (b, b_dist, _)| {
+                a.total_cmp(b).then(a_dist.total_cmp(b_dist))
+            })?;
+
+        debug!(
+            "suggested {} to avoid airspaces from {} to {} ({:.0} m instead of {:.0} m)",
+            via.ident(),
+            from.ident(),
+            to.ident(),
+            penetration,
+            direct
+        );
+
+        (penetration < direct).then(|| via.clone())
+    }
+
     /// Searches for a waypoint within a terminal area.
     ///
     /// # Examples
@@ -261,7 +701,7 @@ impl NavigationData {
         let result = self
             .terminal_waypoints(airport_ident)
             .find(|&wp| wp.fix_ident == fix_ident)
-            .map(|wp| NavAid::Waypoint(Rc::clone(wp)));
+            .map(|wp| NavAid::Waypoint(Shared::clone(wp)));
 
         match &result {
             Some(_) => trace!("found terminal waypoint {} at {}", fix_ident, airport_ident),
@@ -321,6 +761,52 @@ impl NavigationData {
         }
     }
 
+    /// Removes duplicate airspaces.
+    ///
+    /// Navigation data loaded from multiple sources may contain the same
+    /// airspace more than once, e.g. a CTR provided by two data providers.
+    /// Airspaces with the same name, type and classification whose polygons
+    /// overlap almost entirely are merged, keeping only the first one. The
+    /// airspaces of this navigation data are kept over those of appended
+    /// partitions.
+    pub fn dedup_airspaces(&mut self) {
+        let mut kept: HashMap<String, Vec<Shared<Airspace>>> = HashMap::new();
+        let mut removed = 0;
+
+        let mut dedup = |airspaces: &mut Vec<Shared<Airspace>>| {
+            airspaces.retain(|airspace| {
+                let same_name = kept.entry(airspace.name.clone()).or_default();
+
+                if same_name.iter().any(|other| other.is_duplicate(airspace)) {
+                    trace!("removing duplicate airspace {}", airspace.name);
+                    removed += 1;
+                    false
+                } else {
+                    same_name.push(Shared::clone(airspace));
+                    true
+                }
+            });
+        };
+
+        dedup(&mut self.airspaces);
+
+        // visit the partitions in a stable order
+        let mut ids: Vec<u64> = self.partitions.keys().copied().collect();
+        ids.sort();
+
+        for id in ids {
+            if let Some(partition) = self.partitions.get_mut(&id) {
+                dedup(&mut partition.airspaces);
+            }
+        }
+
+        debug!("removed {} duplicate airspace(s)", removed);
+
+        if removed > 0 {
+            self.reindex();
+        }
+    }
+
     /// Indexes the navigation data partitions.
     fn reindex(&mut self) {
         self.airspace_index = AirspaceIndex::new(self.airspaces());
@@ -356,7 +842,7 @@ impl NavigationData {
         &self.errors
     }
 
-    pub(crate) fn airports(&self) -> impl Iterator<Item = &Rc<Airport>> {
+    pub(crate) fn airports(&self) -> impl Iterator<Item = &Shared<Airport>> {
         self.airports.iter().chain(
             self.partitions
                 .values()
@@ -364,7 +850,7 @@ impl NavigationData {
         )
     }
 
-    pub(crate) fn airspaces(&self) -> impl Iterator<Item = &Rc<Airspace>> {
+    pub(crate) fn airspaces(&self) -> impl Iterator<Item = &Shared<Airspace>> {
         self.airspaces.iter().chain(
             self.partitions
                 .values()
@@ -372,7 +858,7 @@ impl NavigationData {
         )
     }
 
-    pub(crate) fn waypoints(&self) -> impl Iterator<Item = &Rc<Waypoint>> {
+    pub(crate) fn waypoints(&self) -> impl Iterator<Item = &Shared<Waypoint>> {
         self.waypoints.iter().chain(
             self.partitions
                 .values()
@@ -383,7 +869,7 @@ impl NavigationData {
     pub(crate) fn terminal_waypoints<'a>(
         &'a self,
         ident: &'a str,
-    ) -> impl Iterator<Item = &'a Rc<Waypoint>> + 'a {
+    ) -> impl Iterator<Item = &'a Shared<Waypoint>> + 'a {
         self.terminal_waypoints
             .get(ident)
             .into_iter()
@@ -399,7 +885,9 @@ impl NavigationData {
 
 #[cfg(test)]
 mod tests {
-    use crate::VerticalDistance;
+    use std::str::FromStr;
+
+    use crate::geom::point_from_lat_lon;
 
     use super::*;
 
@@ -409,69 +897,266 @@ mod tests {
         let inside = coord!(53.03759, 9.00533);
         let outside = coord!(53.04892, 8.90907);
 
-        builder.add_airspace(Airspace {
-            name: String::from("TMA BREMEN A"),
-            airspace_type: AirspaceType::CTA,
-            classification: Some(AirspaceClassification::D),
-            ceiling: VerticalDistance::Fl(65),
-            floor: VerticalDistance::Msl(1500),
-            polygon: polygon![
+        builder.add_airspace(fixtures::airspace(
+            "TMA BREMEN A",
+            polygon![
                 (53.10111, 8.974999),
                 (53.102776, 9.079166),
                 (52.97028, 9.084444),
                 (52.96889, 8.982222),
                 (53.10111, 8.974999)
             ],
-        });
+        ));
 
         let nd = builder.build();
         let nearby_inside = nd.at(&inside, Length::nm(1.0));
         let nearby_outside = nd.at(&outside, Length::nm(1.0));
 
-        assert_eq!(nearby_inside.airspaces, vec![Rc::clone(&nd.airspaces[0])]);
+        assert_eq!(
+            nearby_inside.airspaces,
+            vec![Shared::clone(&nd.airspaces[0])]
+        );
         assert!(nearby_outside.airspaces.is_empty());
     }
 
     #[test]
-    fn navaids_within_radius() {
+    fn airspaces_at_point_filtered_by_class() {
+        let bremen = polygon![
+            (53.10111, 8.974999),
+            (53.102776, 9.079166),
+            (52.97028, 9.084444),
+            (52.96889, 8.982222),
+            (53.10111, 8.974999)
+        ];
+        let airspace = |name: &str, classification| Airspace {
+            classification,
+            ..fixtures::airspace(name, bremen.clone())
+        };
+
+        let mut builder = NavigationData::builder();
+        builder.add_airspace(airspace("TMA BREMEN A", Some(AirspaceClassification::D)));
+        builder.add_airspace(airspace("BREMEN G", Some(AirspaceClassification::G)));
+        builder.add_airspace(airspace("ED-R146", None));
+        let nd = builder.build();
+
+        use AirspaceClassification::*;
+        let controlled = nd.at_filtered(&coord!(53.03759, 9.00533), &[A, B, C, D, E]);
+        assert_eq!(
+            nd.at(&coord!(53.03759, 9.00533), Length::nm(1.0))
+                .airspaces
+                .len(),
+            3
+        );
+        assert_eq!(controlled.len(), 1);
+        assert_eq!(controlled[0].name, "TMA BREMEN A");
+        assert!(nd
+            .at_filtered(&coord!(53.04892, 8.90907), &[A, B, C, D, E])
+            .is_empty());
+    }
+
+    #[test]
+    fn dedup_overlapping_airspaces() {
+        let mut a424 = NavigationData::builder().with_source(b"A424");
+        a424.add_airspace(fixtures::airspace(
+            "CTR HAMBURG",
+            polygon![
+                (53.70, 9.80),
+                (53.70, 10.20),
+                (53.55, 10.20),
+                (53.55, 9.80),
+                (53.70, 9.80)
+            ],
+        ));
+
+        // the same CTR from another provider with slightly different vertices
+        let mut openair = NavigationData::builder().with_source(b"OpenAir");
+        openair.add_airspace(fixtures::airspace(
+            "CTR HAMBURG",
+            polygon![
+                (53.70, 9.80),
+                (53.70, 10.201),
+                (53.55, 10.201),
+                (53.55, 9.80),
+                (53.70, 9.80)
+            ],
+        ));
+
+        let mut nd = NavigationData::new();
+        nd.concat(vec![a424.build(), openair.build()]);
+        assert_eq!(nd.airspaces().count(), 2);
+
+        nd.dedup_airspaces();
+        assert_eq!(nd.airspaces().count(), 1);
+        assert_eq!(
+            nd.at(&coord!(53.63, 9.99), Length::nm(1.0)).airspaces.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn airspace_by_name_ignores_case() {
+        let restricted = r#"AC R
+AN ED-R146
+AH FL 100
+AL GND
+DP 53:06:04 N 8:58:30 E
+DP 53:06:10 N 9:04:45 E
+DP 52:58:13 N 9:05:04 E
+DP 53:06:04 N 8:58:30 E
+"#;
+        let tma = r#"AC D
+AN TMA BREMEN A
+AH FL 65
+AL 1500msl
+DP 53:06:04 N 8:58:30 E
+DP 53:06:10 N 9:04:45 E
+DP 52:58:08 N 8:58:56 E
+DP 53:06:04 N 8:58:30 E
+"#;
+
+        let mut nd = NavigationData::try_from_openair(restricted).expect("OpenAir should parse");
+        nd.append(NavigationData::try_from_openair(tma).expect("OpenAir should parse"));
+
+        let airspace = nd
+            .airspace_by_name("ed-r146")
+            .expect("airspace should be found");
+        assert_eq!(airspace.name, "ED-R146");
+        assert_eq!(airspace.airspace_type, AirspaceType::Restricted);
+        assert_eq!(
+            nd.airspace_by_name("tma bremen a").map(|a| &a.name),
+            Some(&String::from("TMA BREMEN A"))
+        );
+        assert_eq!(nd.airspace_by_name("ED-R147"), None);
+    }
+
+    #[test]
+    fn airspace_by_name_ignores_unicode_case() {
+        let polygon = polygon![(53.70, 9.80), (53.70, 10.20), (53.55, 10.20), (53.70, 9.80)];
+
+        let mut builder = NavigationData::builder();
+        builder.add_airspace(fixtures::airspace("CTR LÜBECK", polygon));
+        let nd = builder.build();
+
+        assert_eq!(
+            nd.airspace_by_name("ctr lübeck").map(|a| &a.name),
+            Some(&String::from("CTR LÜBECK"))
+        );
+    }
+
+    #[test]
+    fn all_airspaces_of_all_partitions() {
+        let polygon = polygon![(53.70, 9.80), (53.70, 10.20), (53.55, 10.20), (53.70, 9.80)];
+
+        let mut hamburg = NavigationData::builder();
+        hamburg.add_airspace(fixtures::airspace("CTR HAMBURG", polygon.clone()));
+        let mut bremen = NavigationData::builder();
+        bremen.add_airspace(fixtures::airspace("CTR BREMEN", polygon.clone()));
+        bremen.add_airspace(fixtures::airspace("TMA BREMEN A", polygon));
+
+        let mut nd = hamburg.build();
+        nd.append(bremen.build());
+
+        let names: Vec<&str> = nd.all_airspaces().map(|a| a.name.as_str()).collect();
+        assert_eq!(names.len(), 3);
+        assert!(names.contains(&"CTR HAMBURG"));
+        assert!(names.contains(&"TMA BREMEN A"));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn shared_across_threads() {
+        let records = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+"#;
+
+        let nd =
+            Shared::new(NavigationData::try_from_arinc424(records).expect("records should load"));
+        let worker = {
+            let nd = Shared::clone(&nd);
+            std::thread::spawn(move || nd.find("EDDH").map(|arpt| arpt.ident()))
+        };
+
+        let ident = worker.join().expect("worker should not panic");
+        assert_eq!(ident.as_deref(), Some("EDDH"));
+    }
+
+    #[test]
+    fn runways_of_all_partitions() {
+        let hamburg = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW15    0120271530 N53391203E009583255                          053                                           124372502
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+"#;
+        let lubeck = br#"
+SEURP EDHLEDA        0        N N53481800E010430400E002000055                   P    MWGE    LUBECK-BLANKENSEE             385832513
+SEURP EDHLEDGRW07    0068960720 N53480876E010421519                          197                                           141222513
+"#;
+
+        let mut nd = NavigationData::try_from_arinc424(hamburg).expect("records should be valid");
+        nd.append(NavigationData::try_from_arinc424(lubeck).expect("records should be valid"));
+
+        let runways: Vec<(String, String)> = nd
+            .runways()
+            .map(|(arpt, rwy)| (arpt.ident(), rwy.designator.clone()))
+            .collect();
+
+        assert_eq!(
+            runways.len(),
+            nd.airports().map(|arpt| arpt.runways.len()).sum::<usize>()
+        );
+        assert!(runways.contains(&("EDHL".to_string(), "07".to_string())));
+        assert_eq!(runways.len(), 3);
+    }
+
+    #[test]
+    fn explicit_cycle_takes_precedence() {
         let mut builder = NavigationData::builder();
 
-        // Add an airport
         builder.add_airport(Airport {
-            icao_ident: "EDDH".to_string(),
-            iata_designator: "HAM".to_string(),
-            name: "Hamburg".to_string(),
-            coordinate: Point::new(9.99, 53.63), // (lon, lat)
-            mag_var: None,
-            elevation: VerticalDistance::Gnd,
-            runways: vec![],
-            location: None,
-            cycle: None,
+            cycle: Some(AiracCycle::new(24, 9)),
+            ..fixtures::airport("EDDH", Point::new(9.99, 53.63)) // (lon, lat)
         });
 
+        let nd = builder.build().with_cycle(AiracCycle::new(25, 9));
+        let cycle = nd.cycle().expect("cycle should be set");
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 9, 18).expect("date should be valid");
+
+        assert_eq!(cycle, &AiracCycle::new(25, 9));
+        assert_eq!(cycle.valid_for_date(date), Some(CycleValidity::Valid));
+    }
+
+    #[test]
+    fn find_navaid_at_coordinate() {
+        let mut builder = NavigationData::builder();
+
+        builder.add_airport(fixtures::airport("EDDH", Point::new(9.99, 53.63))); // (lon, lat)
+
+        let nd = builder.build();
+
+        // a few meters north of the airport
+        let near = Point::new(9.99, 53.63005);
+        let far = Point::new(10.5, 54.5);
+
+        assert!(matches!(
+            nd.find_at(&near, Length::m(50.0)),
+            Some(NavAid::Airport(arpt)) if arpt.icao_ident == "EDDH"
+        ));
+        assert_eq!(nd.find_at(&far, Length::m(50.0)), None);
+    }
+
+    #[test]
+    fn navaids_within_radius() {
+        let mut builder = NavigationData::builder();
+
+        // Add an airport
+        builder.add_airport(fixtures::airport("EDDH", Point::new(9.99, 53.63))); // (lon, lat)
+
         // Add a waypoint nearby
-        builder.add_waypoint(Waypoint {
-            fix_ident: "DHN1".to_string(),
-            desc: "Delta November 1".to_string(),
-            usage: WaypointUsage::VFROnly,
-            coordinate: Point::new(9.95, 53.60), // (lon, lat)
-            mag_var: None,
-            region: Region::Enroute,
-            location: None,
-            cycle: None,
-        });
+        builder.add_waypoint(fixtures::waypoint("DHN1", Point::new(9.95, 53.60))); // (lon, lat)
 
         // Add a waypoint far away
-        builder.add_waypoint(Waypoint {
-            fix_ident: "FAR1".to_string(),
-            desc: "Far Away".to_string(),
-            usage: WaypointUsage::Unknown,
-            coordinate: Point::new(10.5, 54.5), // (lon, lat)
-            mag_var: None,
-            region: Region::Enroute,
-            location: None,
-            cycle: None,
-        });
+        builder.add_waypoint(fixtures::waypoint("FAR1", Point::new(10.5, 54.5))); // (lon, lat)
 
         let nd = builder.build();
         let center = Point::new(9.97, 53.62); // (lon, lat)
@@ -484,4 +1169,115 @@ mod tests {
         let nearby = nd.at(&center, Length::nm(100.0));
         assert_eq!(nearby.navaids.len(), 3);
     }
+
+    #[test]
+    fn suggest_avoidance_routes_around_airspace() {
+        let waypoint = |ident: &str, lat: f64, lon: f64| {
+            fixtures::waypoint(ident, point_from_lat_lon(lat, lon))
+        };
+
+        //          9.0E    9.4E  9.5E  9.6E    10.0E
+        //  53.7N                NORTH
+        //  53.6N           +-----------+
+        //  53.5N   WEST ---|--CENTER---|--- EAST
+        //  53.4N           +-----------+
+        let mut builder = NavigationData::builder();
+        builder.add_waypoint(waypoint("WEST", 53.5, 9.0));
+        builder.add_waypoint(waypoint("EAST", 53.5, 10.0));
+        builder.add_waypoint(waypoint("NORTH", 53.7, 9.5));
+        builder.add_waypoint(waypoint("CENTER", 53.5, 9.5));
+        builder.add_airspace(fixtures::airspace(
+            "CTR",
+            polygon![
+                (53.4, 9.4),
+                (53.6, 9.4),
+                (53.6, 9.6),
+                (53.4, 9.6),
+                (53.4, 9.4)
+            ],
+        ));
+
+        let nd = builder.build();
+        let from = nd.find("WEST").expect("WEST should exist");
+        let to = nd.find("EAST").expect("EAST should exist");
+
+        let via = nd.suggest_avoidance(&from, &to, &[AirspaceClassification::D]);
+        assert_eq!(via.map(|navaid| navaid.ident()), Some("NORTH".to_string()));
+
+        // the direct route doesn't penetrate any class C airspace
+        assert_eq!(
+            nd.suggest_avoidance(&from, &to, &[AirspaceClassification::C]),
+            None
+        );
+    }
+
+    #[test]
+    fn nearest_airports_with_runway_filter() {
+        let airport = |ident: &str, lat: f64, lon: f64| {
+            fixtures::airport(ident, point_from_lat_lon(lat, lon))
+        };
+        let runway = |length: Length| Runway {
+            length,
+            tora: length,
+            toda: length,
+            lda: length,
+            ..fixtures::runway("09")
+        };
+
+        let mut builder = NavigationData::builder();
+        builder.add_airport(airport("NEAR", 53.6, 10.0));
+        builder.add_runway("NEAR".to_string(), runway(Length::m(400.0)));
+        builder.add_airport(airport("MID", 53.8, 10.0));
+        builder.add_runway("MID".to_string(), runway(Length::m(1200.0)));
+        builder.add_airport(airport("FAR", 55.0, 10.0));
+        builder.add_runway("FAR".to_string(), runway(Length::m(3000.0)));
+
+        let nd = builder.build();
+        let position = point_from_lat_lon(53.5, 10.0);
+        let idents = |airports: Vec<(Shared<Airport>, Length)>| -> Vec<String> {
+            airports.iter().map(|(arpt, _)| arpt.ident()).collect()
+        };
+
+        let nearest = nd.nearest_airports(&position, 2, None);
+        assert_eq!(idents(nearest.clone()), vec!["NEAR", "MID"]);
+        assert!((nearest[0].1.value() - 6.0).abs() < 0.1);
+
+        // the short runway of NEAR is excluded and FAR is found by widening
+        // the search
+        let nearest = nd.nearest_airports(&position, 2, Some(Length::m(800.0)));
+        assert_eq!(idents(nearest), vec!["MID", "FAR"]);
+    }
+
+    #[test]
+    fn reachable_airports_grow_with_altitude() {
+        let airport =
+            |ident: &str, lat: f64| fixtures::airport(ident, point_from_lat_lon(lat, 10.0));
+
+        // NEAR is 2 NM and FAR 8 NM north of the position
+        let mut builder = NavigationData::builder();
+        builder.add_airport(airport("NEAR", 53.5 + 2.0 / 60.0));
+        builder.add_airport(airport("FAR", 53.5 + 8.0 / 60.0));
+
+        let nd = builder.build();
+        let position = point_from_lat_lon(53.5, 10.0);
+        let reachable = |altitude: u16, wind: &str| -> Vec<String> {
+            nd.reachable_airports(
+                &position,
+                VerticalDistance::Altitude(altitude),
+                10.0,
+                Speed::kt(70.0),
+                Wind::from_str(wind).expect("wind should be valid"),
+            )
+            .iter()
+            .map(|arpt| arpt.ident())
+            .collect()
+        };
+
+        // 2000 ft above the arrival height glide 3.3 NM
+        assert_eq!(reachable(3000, "00000KT"), vec!["NEAR"]);
+        // 5000 ft above the arrival height glide 8.2 NM
+        assert_eq!(reachable(6000, "00000KT"), vec!["NEAR", "FAR"]);
+        // a headwind of 20 kt reduces the range to 5.9 NM
+        assert_eq!(reachable(6000, "36020KT"), vec!["NEAR"]);
+    }
 }
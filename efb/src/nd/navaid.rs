@@ -14,7 +14,6 @@
 // limitations under the License.
 
 use std::fmt;
-use std::rc::Rc;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -26,13 +25,14 @@ use super::AiracCycle;
 use super::Airport;
 use super::Fix;
 use super::LocationIndicator;
+use super::Shared;
 use super::Waypoint;
 
 #[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NavAid {
-    Airport(Rc<Airport>),
-    Waypoint(Rc<Waypoint>),
+    Airport(Shared<Airport>),
+    Waypoint(Shared<Waypoint>),
 }
 
 impl NavAid {
@@ -59,6 +59,13 @@ impl Fix for NavAid {
         }
     }
 
+    fn label(&self) -> String {
+        match self {
+            Self::Airport(arpt) => arpt.label(),
+            Self::Waypoint(wp) => wp.label(),
+        }
+    }
+
     fn coordinate(&self) -> Point<f64> {
         match self {
             Self::Airport(arpt) => arpt.coordinate(),
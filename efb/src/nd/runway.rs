@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -95,6 +95,56 @@ pub struct Runway {
     pub elev: VerticalDistance,
 }
 
+impl Runway {
+    /// Returns `true` if the runway has the `designator`.
+    ///
+    /// The designators are compared case-insensitive and with a zero-padded
+    /// number, thus `7l` matches the runway `07L`.
+    pub fn matches_designator(&self, designator: &str) -> bool {
+        normalize_designator(&self.designator) == normalize_designator(designator)
+    }
+
+    /// Returns `true` if the runway is a parallel runway with the number of the
+    /// `designator`, e.g. `07L` for the designator `07`.
+    pub fn is_parallel_to(&self, designator: &str) -> bool {
+        normalize_designator(&self.designator)
+            .strip_prefix(&normalize_designator(designator))
+            .is_some_and(|suffix| matches!(suffix, "L" | "C" | "R"))
+    }
+
+    /// Returns the designator of the opposite end of the runway, e.g. `27R`
+    /// for the runway `09L`.
+    ///
+    /// Returns `None` if the designator has no runway number between 1 and
+    /// 36.
+    pub fn reciprocal_designator(&self) -> Option<String> {
+        let designator = normalize_designator(&self.designator);
+        let (number, suffix) = designator.split_at_checked(designator.len().min(2))?;
+        let number: u8 = number.parse().ok().filter(|n| (1..=36).contains(n))?;
+
+        let suffix = match suffix {
+            "L" => "R",
+            "R" => "L",
+            "C" | "" => suffix,
+            _ => return None,
+        };
+
+        Some(format!("{:02}{suffix}", (number + 17) % 36 + 1))
+    }
+}
+
+/// Returns the uppercase designator with a two-digit runway number.
+fn normalize_designator(designator: &str) -> String {
+    let designator = designator.trim().to_ascii_uppercase();
+    let digits = designator.chars().take_while(char::is_ascii_digit).count();
+
+    if digits == 1 {
+        format!("0{designator}")
+    } else {
+        designator
+    }
+}
+
 impl Hash for Runway {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.designator.hash(state);
@@ -114,3 +164,29 @@ impl fmt::Display for Runway {
         write!(f, "{}", self.designator)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::nd::fixtures::runway;
+
+    #[test]
+    fn reciprocal_designator() {
+        assert_eq!(
+            runway("09L").reciprocal_designator().as_deref(),
+            Some("27R")
+        );
+        assert_eq!(
+            runway("27R").reciprocal_designator().as_deref(),
+            Some("09L")
+        );
+        assert_eq!(
+            runway("18C").reciprocal_designator().as_deref(),
+            Some("36C")
+        );
+        assert_eq!(runway("36").reciprocal_designator().as_deref(), Some("18"));
+        assert_eq!(runway("5").reciprocal_designator().as_deref(), Some("23"));
+        assert_eq!(runway("37").reciprocal_designator(), None);
+        assert_eq!(runway("H1").reciprocal_designator(), None);
+        assert_eq!(runway("Aé").reciprocal_designator(), None);
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use super::*;
+use crate::measurements::AngleUnit;
+
+/// Deviation of a runway's magnetic bearing from its number beyond which the
+/// bearing is considered wrong.
+const RUNWAY_BEARING_TOLERANCE_DEG: f32 = 30.0;
+
+/// A data-quality issue found by [`NavigationData::validate`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum ValidationIssue {
+    /// The airport has no runway.
+    AirportWithoutRunways { airport: String },
+    /// The magnetic bearing of the runway doesn't match its number, e.g.
+    /// because the bearing is missing in the source.
+    RunwayBearingMismatch {
+        airport: String,
+        runway: String,
+        bearing: Angle,
+    },
+    /// The fix is at 0°N 0°E, which is usually a missing coordinate.
+    FixAtNullIsland { ident: String },
+    /// The airspace's boundary has less than 3 distinct vertices and doesn't
+    /// enclose an area.
+    DegenerateAirspace { name: String, vertices: usize },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AirportWithoutRunways { airport } => {
+                write!(f, "airport {airport} has no runways")
+            }
+            Self::RunwayBearingMismatch {
+                airport,
+                runway,
+                bearing,
+            } => write!(
+                f,
+                "runway {runway} of {airport} has a bearing of {bearing:.0}"
+            ),
+            Self::FixAtNullIsland { ident } => write!(f, "fix {ident} is at 0°N 0°E"),
+            Self::DegenerateAirspace { name, vertices } => {
+                write!(f, "airspace {name} has only {vertices} vertices")
+            }
+        }
+    }
+}
+
+impl NavigationData {
+    /// Scans the loaded data for issues like airports without runways or
+    /// degenerate airspaces.
+    ///
+    /// Unlike the [errors], the issues don't prevent the data from being
+    /// loaded but may indicate bad data of the provider. Check them before
+    /// planning with new data.
+    ///
+    /// [errors]: NavigationData::errors
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for arpt in self.airports() {
+            if arpt.runways.is_empty() {
+                issues.push(ValidationIssue::AirportWithoutRunways {
+                    airport: arpt.ident(),
+                });
+            }
+        }
+
+        for (arpt, rwy) in self.runways() {
+            if !bearing_matches_number(rwy) {
+                issues.push(ValidationIssue::RunwayBearingMismatch {
+                    airport: arpt.ident(),
+                    runway: rwy.designator.clone(),
+                    bearing: rwy.bearing,
+                });
+            }
+        }
+
+        for fix in self.fixes() {
+            if fix.coordinate() == Point::new(0.0, 0.0) {
+                issues.push(ValidationIssue::FixAtNullIsland { ident: fix.ident() });
+            }
+        }
+
+        for airspace in self.airspaces() {
+            // the ring is closed, thus the last coordinate repeats the first
+            let vertices = airspace.polygon.exterior().0.len().saturating_sub(1);
+
+            if vertices < 3 {
+                issues.push(ValidationIssue::DegenerateAirspace {
+                    name: airspace.name.clone(),
+                    vertices,
+                });
+            }
+        }
+
+        if !issues.is_empty() {
+            warn!("navigation data has {} issue(s)", issues.len());
+        }
+
+        issues
+    }
+}
+
+/// Returns `true` if the runway's magnetic bearing is within the tolerance of
+/// its number or if it can't be checked.
+fn bearing_matches_number(rwy: &Runway) -> bool {
+    let number: String = rwy
+        .designator
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+
+    match (number.parse::<f32>(), rwy.bearing.unit()) {
+        (Ok(number), AngleUnit::MagneticNorth) => {
+            let deviation = (rwy.bearing.value() - number * 10.0).rem_euclid(360.0);
+            deviation.min(360.0 - deviation) <= RUNWAY_BEARING_TOLERANCE_DEG
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degenerate_airspace_is_reported() {
+        let records = r#"AC D
+AN CTR LINE
+AH 2500msl
+AL GND
+DP 53:36:00 N 9:58:00 E
+DP 53:40:00 N 10:02:00 E
+"#;
+
+        let nd = NavigationData::try_from_openair(records).expect("OpenAir should parse");
+
+        assert_eq!(
+            nd.validate(),
+            vec![ValidationIssue::DegenerateAirspace {
+                name: "CTR LINE".to_string(),
+                vertices: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn runway_issues_are_reported() {
+        let records = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+SEURP EDDHEDGRW15    0120273330 N53391203E009583255                          053                                           124372502
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+"#;
+
+        let nd = NavigationData::try_from_arinc424(records).expect("records should be valid");
+
+        assert_eq!(
+            nd.validate(),
+            vec![
+                ValidationIssue::AirportWithoutRunways {
+                    airport: "EDHF".to_string()
+                },
+                // the bearing of the opposite runway
+                ValidationIssue::RunwayBearingMismatch {
+                    airport: "EDDH".to_string(),
+                    runway: "15".to_string(),
+                    bearing: Angle::m(333.0),
+                },
+            ]
+        );
+    }
+}
@@ -55,6 +55,21 @@ pub struct Waypoint {
 }
 
 impl Waypoint {
+    /// Creates an enroute waypoint, e.g. a user waypoint that isn't part of
+    /// the navigation data.
+    pub fn new(ident: String, desc: String, coordinate: Point<f64>) -> Self {
+        Self {
+            fix_ident: ident,
+            desc,
+            usage: WaypointUsage::Unknown,
+            coordinate,
+            mag_var: None,
+            region: Region::Enroute,
+            location: None,
+            cycle: None,
+        }
+    }
+
     /// The terminal area of the waypoint.
     ///
     /// Returns `None` if the waypoint is not within a terminal area.
@@ -73,7 +88,21 @@ impl Fix for Waypoint {
         self.fix_ident.clone()
     }
 
+    fn label(&self) -> String {
+        if self.desc.is_empty() {
+            self.ident()
+        } else {
+            format!("{} — {}", self.ident(), self.desc)
+        }
+    }
+
     fn coordinate(&self) -> Point<f64> {
         self.coordinate
     }
+
+    /// Returns the magnetic variation of the navigation data or the one
+    /// computed by the World Magnetic Model if the data provides none.
+    fn mag_var(&self) -> MagneticVariation {
+        self.mag_var.unwrap_or_else(|| self.coordinate.into())
+    }
 }
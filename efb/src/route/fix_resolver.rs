@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::nd::NavAid;
+
+/// Resolves fix idents of a route that aren't part of the navigation data,
+/// e.g. user waypoints from a personal database.
+///
+/// Resolvers are consulted in order before the navigation data when a route is
+/// [decoded], so a resolver can also override a fix of the navigation data.
+///
+/// # Examples
+///
+/// ```
+/// # use geo::Point;
+/// # use efb::nd::{NavAid, NavigationData, Shared, Waypoint};
+/// # use efb::route::{FixResolver, Route};
+/// struct UserWaypoints;
+///
+/// impl FixResolver for UserWaypoints {
+///     fn resolve(&self, ident: &str) -> Option<NavAid> {
+///         (ident == "HOME").then(|| {
+///             NavAid::Waypoint(Shared::new(Waypoint::new(
+///                 "HOME".to_string(),
+///                 "My home field".to_string(),
+///                 Point::new(9.99, 53.63),
+///             )))
+///         })
+///     }
+/// }
+///
+/// # fn decode(nd: &NavigationData) -> Result<(), efb::error::Error> {
+/// let mut route = Route::new();
+/// route.decode_with_resolvers("EDDH HOME", nd, &[&UserWaypoints])?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [decoded]: crate::route::Route::decode_with_resolvers
+pub trait FixResolver {
+    /// Returns the fix identified by `ident` or `None` if it's unknown to the
+    /// resolver.
+    fn resolve(&self, ident: &str) -> Option<NavAid>;
+}
@@ -13,15 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use log::trace;
+use log::{trace, warn};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use geo::{Bearing, Distance, Geodesic};
+use geo::{Bearing, Geodesic, Point};
 
 use crate::fp::LegPerformance;
-use crate::measurements::{Angle, AngleUnit, Duration, Length, LengthUnit, Speed};
+use crate::geom;
+use crate::measurements::{
+    Angle, AngleUnit, Duration, Length, LengthUnit, Pressure, Speed, SpeedUnit, Temperature,
+};
 use crate::nd::{Fix, NavAid};
 use crate::{Fuel, VerticalDistance, Wind};
 
@@ -176,6 +179,31 @@ impl Leg {
         )
     }
 
+    /// Returns the leg with the `wind` applied.
+    pub(super) fn with_wind(&self, wind: Wind) -> Leg {
+        Leg::new(
+            self.from.clone(),
+            self.to.clone(),
+            self.climb_descent,
+            self.level,
+            self.tas,
+            Some(wind),
+        )
+    }
+
+    /// Returns the leg flown back from its end to its start in cruise, e.g.
+    /// to return to the origin.
+    pub(super) fn reversed(&self) -> Leg {
+        Leg::new(
+            self.to.clone(),
+            self.from.clone(),
+            ClimbDescentAlongLeg::default(),
+            self.level,
+            self.tas,
+            self.wind,
+        )
+    }
+
     fn new(
         from: NavAid,
         to: NavAid,
@@ -192,19 +220,39 @@ impl Leg {
         let bearing = Angle::t(bearing_deg as f32);
         let mc = bearing + from.mag_var();
 
-        let distance_m = Geodesic.distance(from_coord, to_coord);
-        let dist = Length::m(distance_m as f32).convert_to(LengthUnit::NauticalMiles);
+        let dist =
+            geom::geodesic_distance(from_coord, to_coord).convert_to(LengthUnit::NauticalMiles);
 
-        let (gs, wca) = {
-            match (tas, wind) {
-                (Some(tas), Some(wind)) => {
-                    let wca = wind_correction_angle(&wind, &tas, &bearing);
-                    let gs = ground_speed(&tas, &wind, &wca, &bearing);
+        // a Mach number is flown at the TAS of the ISA temperature at the level,
+        // which remains unresolved without a level
+        let tas = tas.and_then(|tas| {
+            if *tas.unit() != SpeedUnit::Mach {
+                return Some(tas);
+            }
 
-                    (Some(gs), Some(wca))
+            match level.and_then(|level| level.to_msl(Pressure::STD, Length::ft(0.0))) {
+                Some(altitude) => Some(tas.to_tas(Temperature::isa(altitude))),
+                None => {
+                    warn!(
+                        "leg {} -> {}: no TAS of {tas} without a level",
+                        from.ident(),
+                        to.ident()
+                    );
+                    None
                 }
-                _ => (None, None),
             }
+        });
+
+        // without a wind the air is assumed to be calm
+        let (gs, wca) = match tas {
+            Some(tas) => {
+                let wind = wind.unwrap_or_default();
+                let wca = wind_correction_angle(&wind, &tas, &bearing);
+                let gs = ground_speed(&tas, &wind, &wca, &bearing);
+
+                (Some(gs), Some(wca))
+            }
+            None => (None, None),
         };
 
         let heading = wca.map(|wca| bearing + wca);
@@ -249,6 +297,32 @@ impl Leg {
         &self.to
     }
 
+    /// Returns `true` if both legs have the same fixes, levels, TAS and wind.
+    ///
+    /// Unlike `==`, the values computed from those inputs, like the heading or
+    /// ground speed, are not compared. Thus, float noise in computed values
+    /// doesn't make structurally equal legs unequal.
+    pub fn same_path(&self, other: &Leg) -> bool {
+        self.from == other.from
+            && self.to == other.to
+            && self.climb_descent == other.climb_descent
+            && self.level == other.level
+            && self.tas == other.tas
+            && self.wind == other.wind
+    }
+
+    /// The point halfway along the leg, e.g. to place a label or to sample the
+    /// wind.
+    pub fn midpoint(&self) -> Point<f64> {
+        geom::midpoint(self.from.coordinate(), self.to.coordinate())
+    }
+
+    /// The point at a `fraction` of the leg, where 0 is the start and 1 the end
+    /// of the leg.
+    pub fn intermediate(&self, fraction: f64) -> Point<f64> {
+        geom::intermediate_point(self.from.coordinate(), self.to.coordinate(), fraction)
+    }
+
     /// The level of the leg.
     pub fn level(&self) -> Option<&VerticalDistance> {
         self.level.as_ref()
@@ -284,11 +358,21 @@ impl Leg {
         self.mh.as_ref()
     }
 
-    /// The bearing between the two points.
+    /// The initial true bearing from the start towards the end of the leg.
     pub fn bearing(&self) -> &Angle {
         &self.bearing
     }
 
+    /// The final true bearing on arrival at the end of the leg.
+    ///
+    /// On a geodesic, the course changes along the way unless the leg follows
+    /// a meridian or the equator. Thus, the final bearing differs from the
+    /// initial [bearing](Self::bearing) especially on long legs.
+    pub fn final_bearing(&self) -> Angle {
+        let back = Geodesic.bearing(self.to.coordinate(), self.from.coordinate());
+        Angle::t(back as f32 + 180.0)
+    }
+
     /// The magnetic course taking the magnetic variation from the starting
     /// point into consideration.
     pub fn mc(&self) -> &Angle {
@@ -311,8 +395,11 @@ impl Leg {
         self.wca.as_ref()
     }
 
-    // TODO add test to verify calculation
     /// The estimated time enroute the leg.
+    ///
+    /// The time is the distance flown at the [ground speed](Self::gs). It is
+    /// `None` only if the leg has no TAS. A leg without wind is flown in calm
+    /// air.
     pub fn ete(&self) -> Option<&Duration> {
         self.ete.as_ref()
     }
@@ -432,8 +519,131 @@ fn ground_speed(tas: &Speed, wind: &Wind, wca: &Angle, bearing: &Angle) -> Speed
 mod tests {
     use std::str::FromStr;
 
+    use crate::nd::{fixtures, Shared};
+
     use super::*;
 
+    fn airport(ident: &str, lat: f64, lon: f64) -> NavAid {
+        NavAid::Airport(Shared::new(fixtures::airport(ident, Point::new(lon, lat))))
+    }
+
+    #[test]
+    fn same_path_ignores_computed_values() {
+        let leg = Leg::new(
+            airport("EDDH", 53.63, 9.99),
+            airport("EDHF", 53.99, 9.58),
+            ClimbDescentAlongLeg::default(),
+            Some(VerticalDistance::Altitude(2500)),
+            Some(Speed::kt(107.0)),
+            Some(Wind::from_str("29020KT").unwrap()),
+        );
+
+        let mut other = leg.clone();
+        other.gs = other.gs.map(|gs| gs + Speed::kt(0.0001));
+
+        assert_ne!(leg, other);
+        assert!(leg.same_path(&other));
+        assert!(!leg.same_path(&leg.divert(airport("EDHL", 53.81, 10.70))));
+    }
+
+    #[test]
+    fn mach_is_flown_at_tas_of_level() {
+        let leg = Leg::new(
+            airport("EDDH", 53.63, 9.99),
+            airport("EDHF", 53.99, 9.58),
+            ClimbDescentAlongLeg::default(),
+            Some(VerticalDistance::Fl(350)),
+            Some(Speed::mach(0.82)),
+            None,
+        );
+
+        let tas = leg.tas().expect("leg should have a TAS");
+        assert!((tas.value() - 472.7).abs() < 0.5, "{tas}");
+        assert_eq!(leg.gs(), Some(tas));
+    }
+
+    #[test]
+    fn mach_without_level_is_unresolved() {
+        for level in [None, Some(VerticalDistance::Unlimited)] {
+            let leg = Leg::new(
+                airport("EDDH", 53.63, 9.99),
+                airport("EDHF", 53.99, 9.58),
+                ClimbDescentAlongLeg::default(),
+                level,
+                Some(Speed::mach(0.82)),
+                None,
+            );
+
+            assert_eq!(leg.tas(), None);
+            assert_eq!(leg.gs(), None);
+        }
+    }
+
+    #[test]
+    fn bearings_along_equator_are_constant() {
+        let leg = Leg::new(
+            airport("AAAA", 0.0, 0.0),
+            airport("BBBB", 0.0, 90.0),
+            ClimbDescentAlongLeg::default(),
+            None,
+            None,
+            None,
+        );
+
+        // a quarter of the WGS84 equator
+        assert!((leg.dist().value() - 5409.7).abs() < 0.1, "{}", leg.dist());
+        assert!((leg.bearing().value() - 90.0).abs() < 0.01);
+        assert!((leg.final_bearing().value() - 90.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn final_bearing_along_parallel_mirrors_initial_bearing() {
+        let leg = Leg::new(
+            airport("AAAA", 50.0, -30.0),
+            airport("BBBB", 50.0, 30.0),
+            ClimbDescentAlongLeg::default(),
+            None,
+            None,
+            None,
+        );
+
+        // the geodesic is symmetric about the meridian halfway between the
+        // points, so it starts north of east and ends as far south of east
+        let initial = *leg.bearing().value();
+        let arrival = *leg.final_bearing().value();
+        assert!(initial < 90.0 && arrival > 90.0);
+        assert!(
+            (initial + arrival - 180.0).abs() < 0.01,
+            "{initial} {arrival}"
+        );
+    }
+
+    #[test]
+    fn ete_without_wind_is_flown_at_tas() {
+        let leg = Leg::new(
+            airport("EDDH", 53.63, 9.99),
+            airport("EDHF", 53.99, 9.58),
+            ClimbDescentAlongLeg::default(),
+            None,
+            Some(Speed::kt(100.0)),
+            None,
+        );
+
+        assert_eq!(leg.gs(), Some(&Speed::kt(100.0)));
+        assert_eq!(leg.ete(), Some(&(*leg.dist() / Speed::kt(100.0))));
+
+        let leg = Leg::new(
+            airport("EDDH", 53.63, 9.99),
+            airport("EDHF", 53.99, 9.58),
+            ClimbDescentAlongLeg::default(),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(leg.ete(), None);
+    }
+
     #[test]
     fn wind_correction_angle_left() {
         let wca = wind_correction_angle(
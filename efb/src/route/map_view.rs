@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::f64::consts::PI;
+
+use geo::{Point, Rect};
+
+use crate::geom::wrap_longitude;
+
+/// The size of a tile of a slippy map in pixels.
+const TILE_SIZE: f64 = 256.0;
+
+/// The highest zoom level of common tile servers.
+const MAX_ZOOM: u8 = 18;
+
+/// The latitude up to which the Web Mercator projection is defined.
+const MAX_LATITUDE: f64 = 85.051_128_78;
+
+/// The center and zoom level of a Web Mercator map, e.g. a slippy map.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MapView {
+    /// The center of the map.
+    pub center: Point<f64>,
+    /// The zoom level where the world is 256 × 2^zoom pixels wide.
+    pub zoom: u8,
+}
+
+impl MapView {
+    /// Returns the view with the highest zoom level that fits the `bounds`
+    /// into a map of `width` × `height` pixels.
+    ///
+    /// The longitudes of the bounds may be unwrapped beyond 180°. Bounds
+    /// without extent, e.g. of a single point, are shown at the highest zoom
+    /// level.
+    pub(super) fn fit(bounds: Rect<f64>, width: u32, height: u32) -> Self {
+        let (min, max) = (bounds.min(), bounds.max());
+
+        // fractions of the world covered by the bounds
+        let x = (max.x - min.x) / 360.0;
+        let y = (mercator_y(max.y) - mercator_y(min.y)) / (2.0 * PI);
+
+        let zoom_x = (width as f64 / TILE_SIZE / x).log2();
+        let zoom_y = (height as f64 / TILE_SIZE / y).log2();
+        let zoom = zoom_x.min(zoom_y).floor().clamp(0.0, MAX_ZOOM as f64) as u8;
+
+        let center_y = (mercator_y(max.y) + mercator_y(min.y)) / 2.0;
+        let center = Point::new(
+            wrap_longitude((min.x + max.x) / 2.0),
+            center_y.sinh().atan().to_degrees(),
+        );
+
+        Self { center, zoom }
+    }
+}
+
+/// Returns the Web Mercator `y` of the latitude in radians of the projection.
+fn mercator_y(lat: f64) -> f64 {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    (PI / 4.0 + lat / 2.0).tan().ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::Coord;
+
+    use super::*;
+
+    fn rect(min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> Rect<f64> {
+        Rect::new(
+            Coord {
+                x: min_lon,
+                y: min_lat,
+            },
+            Coord {
+                x: max_lon,
+                y: max_lat,
+            },
+        )
+    }
+
+    #[test]
+    fn small_route_is_zoomed_in_further() {
+        // EDDH to EDHF
+        let local = MapView::fit(rect(9.58, 53.63, 9.99, 53.99), 800, 600);
+        // Lisbon to Moscow
+        let continental = MapView::fit(rect(-9.14, 38.72, 37.62, 55.76), 800, 600);
+
+        assert!(local.zoom > continental.zoom);
+        assert_eq!(local.zoom, 10);
+        assert_eq!(continental.zoom, 4);
+        assert!((local.center.x() - 9.785).abs() < 1e-9);
+    }
+
+    #[test]
+    fn point_is_shown_at_highest_zoom() {
+        // an unwrapped longitude beyond the antimeridian
+        let view = MapView::fit(rect(180.5, -17.0, 180.5, -17.0), 800, 600);
+
+        assert_eq!(view.zoom, MAX_ZOOM);
+        assert!((view.center.x() + 179.5).abs() < 1e-9);
+        assert!((view.center.y() + 17.0).abs() < 1e-9);
+    }
+}
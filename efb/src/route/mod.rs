@@ -14,28 +14,40 @@
 // limitations under the License.
 
 use std::fmt;
-use std::rc::Rc;
 
+use geo::{BoundingRect, LineString, Point, Rect};
 use log::{debug, trace, warn};
 
 use crate::error::Error;
-use crate::fp::{ClimbDescentPerformance, LegPerformance};
-use crate::measurements::Speed;
+use crate::fp::{ClimbDescentPerformance, LegPerformance, Performance};
+use crate::geom::unwrap_line_string;
+use crate::measurements::{Length, LengthUnit, Mass, Speed, VolumeUnit};
 use crate::nd::*;
-use crate::VerticalDistance;
+use crate::{FuelFlow, VerticalDistance};
 
 mod accumulator;
+mod fix_resolver;
 mod leg;
 mod leg_fuel;
+mod map_view;
 mod profile;
+mod route_string;
 mod token;
+mod wind_model;
 
 pub use accumulator::TotalsToLeg;
+pub use fix_resolver::FixResolver;
 pub use leg::Leg;
 pub use leg_fuel::LegFuel;
-pub use profile::{AirspaceIntersection, VerticalPoint, VerticalProfile};
+pub use map_view::MapView;
+pub use profile::{
+    AirspaceIntersection, ClearanceWarning, VerticalPoint, VerticalProfile,
+    CLEARANCE_SAMPLE_INTERVAL_NM,
+};
+pub use route_string::RouteString;
 use token::Tokens;
 pub use token::{Token, TokenKind};
+pub use wind_model::{BandedWindModel, WindModel};
 
 /// A route that goes from an origin to a destination.
 ///
@@ -74,11 +86,12 @@ pub struct Route {
     legs: Vec<Leg>,
     speed: Option<Speed>,
     level: Option<VerticalDistance>,
-    origin: Option<Rc<Airport>>,
+    origin: Option<Shared<Airport>>,
     takeoff_rwy: Option<Runway>,
-    destination: Option<Rc<Airport>>,
+    destination: Option<Shared<Airport>>,
     landing_rwy: Option<Runway>,
     alternate: Option<NavAid>,
+    warnings: Vec<Error>,
 }
 
 impl Route {
@@ -88,10 +101,33 @@ impl Route {
 
     /// Decodes a `route` that is composed of a space separated list of fix
     /// idents read from the navigation data `nd`.
+    ///
+    /// A fix that repeats the previous fix is dropped, since it would add a
+    /// leg without distance. A [`RepeatedFix`] is recorded in the [warnings]
+    /// instead.
+    ///
+    /// [`RepeatedFix`]: Error::RepeatedFix
+    /// [warnings]: Route::warnings
     pub fn decode(&mut self, route: &str, nd: &NavigationData) -> Result<(), Error> {
+        self.decode_with_resolvers(route, nd, &[])
+    }
+
+    /// Decodes a `route` like [`decode`] but consults the `resolvers` in order
+    /// before the navigation data `nd` to find a fix.
+    ///
+    /// This allows to use fixes like user waypoints in the route without adding
+    /// them to the navigation data.
+    ///
+    /// [`decode`]: Route::decode
+    pub fn decode_with_resolvers(
+        &mut self,
+        route: &str,
+        nd: &NavigationData,
+        resolvers: &[&dyn FixResolver],
+    ) -> Result<(), Error> {
         debug!("route decode: {:?}", route);
         self.clear();
-        self.tokens = Tokens::new(route, nd);
+        self.tokens = Tokens::new(route, nd, resolvers);
 
         // the builder keeps track of level changes etc
         let mut builder = Leg::builder();
@@ -123,14 +159,10 @@ impl Route {
                 }
 
                 TokenKind::Airport { arpt, rwy } => {
-                    let navaid = NavAid::Airport(Rc::clone(arpt));
+                    let navaid = NavAid::Airport(Shared::clone(arpt));
 
                     // Track for leg building
-                    if from.is_none() {
-                        from = Some(navaid.clone());
-                    } else if to.is_none() {
-                        to = Some(navaid.clone());
-                    }
+                    track_fix(&mut self.warnings, &mut from, &mut to, &navaid);
 
                     if self.origin.is_none() {
                         // First airport = origin with optional takeoff runway
@@ -139,7 +171,7 @@ impl Route {
                             arpt.ident(),
                             rwy.as_ref().map(|r| &r.designator)
                         );
-                        self.origin = Some(Rc::clone(arpt));
+                        self.origin = Some(Shared::clone(arpt));
                         self.takeoff_rwy = rwy.clone();
                     } else if Some(i) == destination_idx {
                         // Last airport = destination with optional landing runway
@@ -148,7 +180,7 @@ impl Route {
                             arpt.ident(),
                             rwy.as_ref().map(|r| &r.designator)
                         );
-                        self.destination = Some(Rc::clone(arpt));
+                        self.destination = Some(Shared::clone(arpt));
                         self.landing_rwy = rwy.clone();
                         builder.destination(&navaid);
                     }
@@ -156,11 +188,7 @@ impl Route {
 
                 TokenKind::NavAid(navaid) => {
                     // Non-airport navaids (waypoints, VOR, NDB, etc.)
-                    if from.is_none() {
-                        from = Some(navaid.clone());
-                    } else if to.is_none() {
-                        to = Some(navaid.clone());
-                    }
+                    track_fix(&mut self.warnings, &mut from, &mut to, navaid);
                 }
 
                 TokenKind::Err(err) => {
@@ -187,11 +215,42 @@ impl Route {
         Ok(())
     }
 
+    /// Decodes a `route` like [`decode`] but requires the route to be
+    /// complete.
+    ///
+    /// While a route is entered, it is valid to have only an origin or no
+    /// airport at all. In strict mode, an [`IncompleteRoute`] error is returned
+    /// if the decoded route lacks an origin or destination airport. The first
+    /// [warning] is returned as error too. The route is decoded nonetheless.
+    ///
+    /// [`decode`]: Route::decode
+    /// [`IncompleteRoute`]: Error::IncompleteRoute
+    /// [warning]: Route::warnings
+    pub fn decode_strict(&mut self, route: &str, nd: &NavigationData) -> Result<(), Error> {
+        self.decode(route, nd)?;
+
+        if let Some(warning) = self.warnings.first() {
+            return Err(warning.clone());
+        }
+
+        if self.origin.is_none() || self.destination.is_none() {
+            warn!("route {:?} is missing origin or destination", route);
+            return Err(Error::IncompleteRoute);
+        }
+
+        Ok(())
+    }
+
     /// Returns the tokens used to build the route.
     pub fn tokens(&self) -> &[Token] {
         self.tokens.tokens()
     }
 
+    /// Returns the issues found while decoding that didn't stop the decoding.
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
     /// Clears the route elements, legs and alternate.
     pub fn clear(&mut self) {
         self.tokens.clear();
@@ -201,6 +260,7 @@ impl Route {
         self.destination.take();
         self.landing_rwy.take();
         self.alternate.take();
+        self.warnings.clear();
     }
 
     /// Returns the legs of the route.
@@ -208,6 +268,27 @@ impl Route {
         &self.legs
     }
 
+    /// Returns the number of legs of the route.
+    pub fn num_legs(&self) -> usize {
+        self.legs.len()
+    }
+
+    /// Returns the number of fixes of the route.
+    ///
+    /// The legs of a route are contiguous, thus a route has one fix more than
+    /// legs unless it has no legs at all.
+    pub fn num_fixes(&self) -> usize {
+        match self.legs.len() {
+            0 => 0,
+            n => n + 1,
+        }
+    }
+
+    /// Returns `true` if the route has no legs.
+    pub fn is_empty(&self) -> bool {
+        self.legs.is_empty()
+    }
+
     /// Sets the cruise speed and level.
     ///
     /// The cruise speed or level is remove from the route by setting it to
@@ -244,9 +325,19 @@ impl Route {
             .map(|final_leg| final_leg.divert(alternate))
     }
 
+    /// Returns the totals of the leg to the [alternate].
+    ///
+    /// The fuel is only computed with [`Some`] performance. Returns `None` if
+    /// no alternate is set or if the route is empty.
+    ///
+    /// [alternate]: Self::alternate
+    pub fn alternate_totals(&self, perf: Option<&LegPerformance>) -> Option<TotalsToLeg> {
+        self.alternate().map(|leg| TotalsToLeg::new(&leg, perf))
+    }
+
     /// Returns the origin airport if one is defined in the route.
-    pub fn origin(&self) -> Option<Rc<Airport>> {
-        self.origin.as_ref().map(Rc::clone)
+    pub fn origin(&self) -> Option<Shared<Airport>> {
+        self.origin.as_ref().map(Shared::clone)
     }
 
     /// Returns the takeoff runway if a defined in the route.
@@ -255,8 +346,8 @@ impl Route {
     }
 
     /// Returns  the destination airport if one is defined in the route.
-    pub fn destination(&self) -> Option<Rc<Airport>> {
-        self.destination.as_ref().map(Rc::clone)
+    pub fn destination(&self) -> Option<Shared<Airport>> {
+        self.destination.as_ref().map(Shared::clone)
     }
 
     /// Returns the landing runway if a defined in the route.
@@ -308,11 +399,156 @@ impl Route {
             })
     }
 
+    /// Applies the wind of the `model` to the legs of the route.
+    ///
+    /// The wind of each leg is taken from the model at the leg's midpoint and
+    /// level. Legs without a level or without a wind in the model keep their
+    /// wind. Decoding the route again resets the winds to the ones of the route
+    /// elements.
+    pub fn apply_wind_model(&mut self, model: &dyn WindModel) {
+        for leg in self.legs.iter_mut() {
+            let wind = leg
+                .level()
+                .and_then(|level| model.wind_at(leg.midpoint(), *level));
+
+            if let Some(wind) = wind {
+                trace!(
+                    "applying wind {} to leg {} -> {}",
+                    wind,
+                    leg.from(),
+                    leg.to()
+                );
+                *leg = leg.with_wind(wind);
+            }
+        }
+    }
+
+    /// Returns the bounding box of the route's fixes, e.g. to zoom a map to
+    /// the route.
+    ///
+    /// The longitudes are unwrapped from the route's start. Thus, the box of a
+    /// route crossing the antimeridian may reach beyond 180°.
+    ///
+    /// Returns `None` if the route has no legs.
+    pub fn bounds(&self) -> Option<Rect<f64>> {
+        let fixes: LineString<f64> = self
+            .legs
+            .first()
+            .map(|leg| leg.from().coordinate())
+            .into_iter()
+            .chain(self.legs.iter().map(|leg| leg.to().coordinate()))
+            .collect();
+
+        let start = fixes.0.first()?.x;
+        unwrap_line_string(&fixes, start).bounding_rect()
+    }
+
+    /// Returns the center and zoom level of a Web Mercator map of `width` ×
+    /// `height` pixels that fits the route's [bounds].
+    ///
+    /// Returns `None` if the route has no legs.
+    ///
+    /// [bounds]: Self::bounds
+    pub fn map_view(&self, width: u32, height: u32) -> Option<MapView> {
+        self.bounds()
+            .map(|bounds| MapView::fit(bounds, width, height))
+    }
+
+    /// Returns the cumulative distance from the route start to the end of each
+    /// leg.
+    ///
+    /// This is a lighter alternative to [`accumulate_legs`] if only the
+    /// distances are needed, e.g. to annotate a map.
+    ///
+    /// [`accumulate_legs`]: Self::accumulate_legs
+    pub fn cumulative_distances(&self) -> Vec<Length> {
+        self.legs
+            .iter()
+            .scan(None, |total: &mut Option<Length>, leg| {
+                let dist = total.map_or(*leg.dist(), |total| total + *leg.dist());
+                *total = Some(dist);
+                Some(dist)
+            })
+            .collect()
+    }
+
+    /// Returns the index of the leg and the point at a `distance` along the
+    /// route, e.g. to place a progress marker on a map.
+    ///
+    /// The point is interpolated along the geodesic of the leg. A distance at
+    /// the end of a leg is on that leg. Returns `None` if the distance is
+    /// negative or beyond the end of the route.
+    pub fn position_at(&self, distance: Length) -> Option<(usize, Point<f64>)> {
+        if distance.to_si() < 0.0 {
+            return None;
+        }
+
+        let mut start = Length::nm(0.0);
+
+        for (i, leg) in self.legs.iter().enumerate() {
+            let end = start + *leg.dist();
+
+            if distance <= end {
+                let fraction = match leg.dist().to_si() {
+                    0.0 => 0.0,
+                    dist => (distance - start).to_si() / dist,
+                };
+
+                return Some((i, leg.intermediate(fraction as f64)));
+            }
+
+            start = end;
+        }
+
+        None
+    }
+
     /// Returns the totals of the entire route.
     pub fn totals(&self, perf: Option<&LegPerformance>) -> Option<TotalsToLeg> {
         self.accumulate_legs(perf).last()
     }
 
+    /// Returns a one-line summary of the route, e.g. for a logbook entry.
+    ///
+    /// The summary contains the first and last fix, the number of legs and the
+    /// total distance. The ETE and the fuel are appended if they are known for
+    /// all legs. The fuel is only accumulated with [`Some`] performance.
+    ///
+    /// ```text
+    /// EDDH→EDHL, 2 legs, 48 NM, 0:22, 14 L
+    /// ```
+    ///
+    /// Returns an empty string if the route has no legs.
+    pub fn summary(&self, perf: Option<&LegPerformance>) -> String {
+        let (Some(first), Some(last), Some(totals)) =
+            (self.legs.first(), self.legs.last(), self.totals(perf))
+        else {
+            return String::new();
+        };
+
+        let mut summary = format!(
+            "{}→{}, {} {}, {:.0}",
+            first.from().ident(),
+            last.to().ident(),
+            self.legs.len(),
+            if self.legs.len() == 1 { "leg" } else { "legs" },
+            totals.dist().convert_to(LengthUnit::NauticalMiles),
+        );
+
+        if let Some(ete) = totals.ete() {
+            // the hours of the duration wrap at a day, which long flights exceed
+            let ete = ete.round();
+            summary.push_str(&format!(", {}:{:02}", ete.to_si() / 3600, ete.minutes()));
+        }
+
+        if let Some(fuel) = totals.fuel() {
+            let volume = fuel.total().volume().convert_to(VolumeUnit::Liter);
+            summary.push_str(&format!(", {volume:.0}"));
+        }
+
+        summary
+    }
+
     /// Returns the vertical profile showing all airspace intersections along
     /// this route.
     ///
@@ -343,6 +579,130 @@ impl Route {
     ) -> VerticalProfile {
         VerticalProfile::new(self, nd, climb, descent)
     }
+
+    /// Returns the distance before a fix at which to begin the descent to
+    /// reach the `target` level at the fix.
+    ///
+    /// The descent starts at the level of the leg to the fix identified by
+    /// `ident`. The distance is the ground distance covered while descending
+    /// with the `descent` performance, corrected by the headwind on that leg.
+    ///
+    /// Returns `None` if the fix isn't on the route, the leg has no level or
+    /// the leg's level isn't above the `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::prelude::*;
+    /// # use efb::route::Route;
+    /// # fn tod(route: Route, descent: ClimbDescentPerformance) {
+    /// // be at 3500 ft by N1
+    /// let target = VerticalDistance::Altitude(3500);
+    ///
+    /// if let Some(dist) = route.top_of_descent("N1", &target, &descent) {
+    ///     println!("begin descent {dist:.1} before N1");
+    /// }
+    /// # }
+    /// ```
+    pub fn top_of_descent(
+        &self,
+        ident: &str,
+        target: &VerticalDistance,
+        descent: &ClimbDescentPerformance,
+    ) -> Option<Length> {
+        let leg = self.legs.iter().find(|leg| leg.to().ident() == ident)?;
+        let level = leg.level()?;
+
+        let result = descent.between(target, level)?;
+        let result = match leg.headwind() {
+            Some(headwind) => result.with_wind(headwind),
+            None => result,
+        };
+
+        Some(result.horizontal_distance)
+    }
+
+    /// Returns the point of no return (PNR) and its distance from the route
+    /// start.
+    ///
+    /// The PNR is the furthest point along the route from which the aircraft
+    /// can still return to the start with the `usable` fuel, keeping the
+    /// `reserve`. Up to the PNR, the fuel to continue and the fuel to return
+    /// consume the fuel available above the reserve. Each leg is flown out and
+    /// back in cruise at its level with the fuel flow of the `perf`, and the
+    /// wind of the leg is a tailwind on the way back if it's a headwind on the
+    /// way out.
+    ///
+    /// Returns `None` if the fuel allows to return from the end of the route,
+    /// if the `usable` fuel doesn't exceed the `reserve` or if a leg has no
+    /// level or ground speed.
+    pub fn point_of_no_return(
+        &self,
+        usable: Mass,
+        reserve: Mass,
+        perf: &Performance,
+    ) -> Option<(Point<f64>, Length)> {
+        let mut available = (usable - reserve).to_si();
+        if available <= 0.0 {
+            return None;
+        }
+
+        let mut dist = Length::nm(0.0);
+
+        for leg in &self.legs {
+            let FuelFlow::PerHour(ff) = perf.ff(leg.level()?);
+            let out = leg.gs()?.to_si();
+            let back = leg.reversed().gs()?.to_si();
+
+            if out <= 0.0 || back <= 0.0 {
+                return None;
+            }
+
+            // fuel in kg burnt per meter flown out and back
+            let burn = ff.mass.to_si() / 3600.0 * (1.0 / out + 1.0 / back);
+            let required = burn * leg.dist().to_si();
+
+            if required >= available {
+                let fraction = available / required;
+                trace!(
+                    "PNR at {:.2} of leg {} -> {}",
+                    fraction,
+                    leg.from(),
+                    leg.to()
+                );
+
+                return Some((
+                    leg.intermediate(fraction as f64),
+                    dist + *leg.dist() * fraction,
+                ));
+            }
+
+            available -= required;
+            dist = dist + *leg.dist();
+        }
+
+        None
+    }
+}
+
+/// Sets the `navaid` as the `from` or `to` fix of the next leg.
+///
+/// A `navaid` that repeats the `from` fix is dropped with a warning.
+fn track_fix(
+    warnings: &mut Vec<Error>,
+    from: &mut Option<NavAid>,
+    to: &mut Option<NavAid>,
+    navaid: &NavAid,
+) {
+    match from {
+        None => *from = Some(navaid.clone()),
+        Some(prev) if prev == navaid => {
+            warn!("dropping repeated fix {}", navaid.ident());
+            warnings.push(Error::RepeatedFix(navaid.ident()));
+        }
+        Some(_) if to.is_none() => *to = Some(navaid.clone()),
+        Some(_) => (),
+    }
 }
 
 impl fmt::Display for Route {
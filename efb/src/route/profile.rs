@@ -13,24 +13,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::rc::Rc;
-
-use geo::{
-    Contains, Distance, Geodesic, Intersects, LineIntersection, LineLocatePoint, LineString, Point,
-};
-use log::trace;
+use geo::{Contains, Intersects, LineIntersection, LineLocatePoint, LineString, Point};
+use log::{trace, warn};
 use rstar::RTreeObject;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::fp::ClimbDescentPerformance;
-use crate::measurements::{Length, LengthUnit, Speed};
-use crate::nd::{Airspace, Fix, NavAid, NavigationData};
+use crate::geom::{geodesic_distance, unwrap_line_string, unwrap_polygon, wrap_longitude};
+use crate::measurements::{Length, LengthUnit, Pressure, Speed};
+use crate::nd::{Airspace, Fix, NavAid, NavigationData, Shared};
 use crate::VerticalDistance;
 
 use super::{Leg, Route};
 
+/// Maximum distance between two terrain samples of a clearance check.
+pub const CLEARANCE_SAMPLE_INTERVAL_NM: f32 = 1.0;
+
 /// An intersection of a route with an airspace.
 ///
 /// Represents the segment where the route passes through an airspace,
@@ -38,11 +38,13 @@ use super::{Leg, Route};
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AirspaceIntersection {
-    airspace: Rc<Airspace>,
+    airspace: Shared<Airspace>,
     entry_distance: Length,
     exit_distance: Length,
     entry_point: Point<f64>,
     exit_point: Point<f64>,
+    route_length: Length,
+    ends_inside: bool,
 }
 
 impl AirspaceIntersection {
@@ -61,6 +63,28 @@ impl AirspaceIntersection {
         &self.exit_distance
     }
 
+    /// Returns the entry distance as fraction of the total route length.
+    ///
+    /// The fraction is between 0 at the route's start and 1 at its end.
+    pub fn entry_fraction(&self) -> f32 {
+        self.fraction(&self.entry_distance)
+    }
+
+    /// Returns the exit distance as fraction of the total route length.
+    ///
+    /// The fraction is between 0 at the route's start and 1 at its end.
+    pub fn exit_fraction(&self) -> f32 {
+        self.fraction(&self.exit_distance)
+    }
+
+    fn fraction(&self, distance: &Length) -> f32 {
+        if self.route_length.to_si() > 0.0 {
+            distance.to_si() / self.route_length.to_si()
+        } else {
+            0.0
+        }
+    }
+
     /// Returns the geographic coordinate where the route enters the airspace.
     pub fn entry_point(&self) -> &Point<f64> {
         &self.entry_point
@@ -81,12 +105,60 @@ impl AirspaceIntersection {
         &self.airspace.ceiling
     }
 
+    /// Returns `true` if the route ends inside the airspace, i.e. the exit
+    /// point is the end of the route rather than a boundary crossing.
+    pub fn ends_inside(&self) -> bool {
+        self.ends_inside
+    }
+
     /// Returns the length of the route segment within this airspace.
     pub fn length(&self) -> Length {
         self.exit_distance - self.entry_distance
     }
 }
 
+/// A point along the route where the level of the profile doesn't clear the
+/// terrain by the required margin.
+///
+/// See [`VerticalProfile::check_clearance`].
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClearanceWarning {
+    leg: usize,
+    point: Point<f64>,
+    distance: Length,
+    level: VerticalDistance,
+    terrain: Length,
+}
+
+impl ClearanceWarning {
+    /// Returns the index of the leg on which the clearance is insufficient.
+    pub fn leg(&self) -> usize {
+        self.leg
+    }
+
+    /// Returns the point with the least clearance on the leg.
+    pub fn point(&self) -> &Point<f64> {
+        &self.point
+    }
+
+    /// Returns the distance from route start to the point.
+    pub fn distance(&self) -> &Length {
+        &self.distance
+    }
+
+    /// Returns the level of the profile at the point, which is an altitude
+    /// within a climb or descent.
+    pub fn level(&self) -> &VerticalDistance {
+        &self.level
+    }
+
+    /// Returns the terrain elevation at the point.
+    pub fn terrain(&self) -> &Length {
+        &self.terrain
+    }
+}
+
 /// A point of interest on the vertical profile of a route.
 ///
 /// Represents a significant altitude event along the route, such as the
@@ -186,20 +258,40 @@ impl VerticalProfile {
             return Self::default();
         }
 
-        // Build a LineString from the route for intersection tests
-        let route_coords: Vec<geo::Coord<f64>> = std::iter::once(legs[0].from().coordinate())
+        let points: Vec<Point<f64>> = std::iter::once(legs[0].from().coordinate())
             .chain(legs.iter().map(|leg| leg.to().coordinate()))
-            .map(Into::into)
             .collect();
 
-        let route_line = LineString::new(route_coords);
+        let intersections = Self::intersections_along(&points, nd);
+        let profile = Self::compute_profile(route, climb, descent);
+
+        Self {
+            intersections,
+            profile,
+        }
+    }
+
+    /// Returns the airspaces intersected by the path through the `points`
+    /// sorted by their entry distance.
+    pub(crate) fn intersections_along(
+        points: &[Point<f64>],
+        nd: &NavigationData,
+    ) -> Vec<AirspaceIntersection> {
+        if points.len() < 2 {
+            return Vec::new();
+        }
+
+        // Build a LineString from the route for intersection tests. The
+        // longitudes are unwrapped to keep legs across the antimeridian short.
+        let route_coords: Vec<geo::Coord<f64>> = points.iter().map(|p| p.0).collect();
+
+        let route_start = route_coords[0].x;
+        let route_line = unwrap_line_string(&LineString::new(route_coords), route_start);
 
         // Compute per-segment geodesic lengths from the route
         let segment_lengths: Vec<Length> = route_line
             .lines()
-            .map(|line| {
-                Length::m(Geodesic.distance(Point::from(line.start), Point::from(line.end)) as f32)
-            })
+            .map(|line| geodesic_distance(Point::from(line.start), Point::from(line.end)))
             .collect();
         let total_length: Length = segment_lengths.iter().copied().sum();
 
@@ -212,13 +304,13 @@ impl VerticalProfile {
 
         for airspace in &candidates {
             // Check actual intersection
-            if !route_line.intersects(&airspace.polygon) {
+            if !route_line.intersects(unwrap_polygon(&airspace.polygon, route_start).as_ref()) {
                 continue;
             }
 
             // Compute entry/exit intersections (may produce multiple for re-entrant routes)
             intersections.extend(Self::compute_intersections(
-                Rc::clone(airspace),
+                Shared::clone(airspace),
                 &route_line,
                 &segment_lengths,
                 total_length,
@@ -232,32 +324,26 @@ impl VerticalProfile {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        let profile = Self::compute_profile(route, climb, descent);
-
-        Self {
-            intersections,
-            profile,
-        }
+        intersections
     }
 
     fn compute_intersections(
-        airspace: Rc<Airspace>,
+        airspace: Shared<Airspace>,
         route_line: &LineString<f64>,
         segment_lengths: &[Length],
         total_length: Length,
     ) -> Vec<AirspaceIntersection> {
-        let geo_polygon = &airspace.polygon;
         let coords: Vec<_> = route_line.coords().collect();
 
         if coords.is_empty() {
             return Vec::new();
         }
 
-        let first_inside = geo_polygon.contains(&Point::new(coords[0].x, coords[0].y));
-        let last_inside = geo_polygon.contains(&Point::new(
-            coords[coords.len() - 1].x,
-            coords[coords.len() - 1].y,
-        ));
+        let geo_polygon = unwrap_polygon(&airspace.polygon, coords[0].x);
+        let geo_polygon = geo_polygon.as_ref();
+
+        let first_inside = geo_polygon.contains(&Point::from(*coords[0]));
+        let last_inside = geo_polygon.contains(&Point::from(*coords[coords.len() - 1]));
 
         // Compute all boundary crossing points with their segment index
         let intersection_points = Self::compute_segment_intersections(route_line, geo_polygon);
@@ -299,14 +385,17 @@ impl VerticalProfile {
         while i + 1 < transitions.len() {
             let (entry_dist, entry_coord) = transitions[i];
             let (exit_dist, exit_coord) = transitions[i + 1];
+            let is_last = i + 2 == transitions.len();
 
             intersections.push(AirspaceIntersection {
-                airspace: Rc::clone(&airspace),
+                airspace: Shared::clone(&airspace),
                 // the leg distance is in NM too
                 entry_distance: entry_dist.convert_to(LengthUnit::NauticalMiles),
                 exit_distance: exit_dist.convert_to(LengthUnit::NauticalMiles),
-                entry_point: Point::new(entry_coord.x, entry_coord.y),
-                exit_point: Point::new(exit_coord.x, exit_coord.y),
+                entry_point: Point::new(wrap_longitude(entry_coord.x), entry_coord.y),
+                exit_point: Point::new(wrap_longitude(exit_coord.x), exit_coord.y),
+                route_length: total_length.convert_to(LengthUnit::NauticalMiles),
+                ends_inside: last_inside && is_last,
             });
 
             i += 2;
@@ -414,28 +503,33 @@ impl VerticalProfile {
             let mut overflow = false;
 
             // cd_to: transition begins at FROM fix, heading forward
-            if let (Some(level), Some(prev)) = (cd_to, cd_from) {
-                let is_climb = *level > prev;
-                let perf = if is_climb { climb_perf } else { descent_perf };
-
-                if let Some(dist) = perf.and_then(|p| transition_distance(p, &prev, level, leg)) {
-                    let level_of_dist = from_dist + dist;
-
-                    profile.push(if is_climb {
-                        VerticalPoint::TopOfClimb {
-                            level: *level,
-                            distance: level_of_dist,
-                        }
-                    } else {
-                        VerticalPoint::EndOfDescent {
-                            level: *level,
-                            distance: level_of_dist,
-                        }
-                    });
-
-                    overflow = level_of_dist > total_dist || level_of_dist >= total_route_dist;
+            if let Some(level) = cd_to {
+                if let Some(prev) = cd_from {
+                    let is_climb = *level > prev;
+                    let perf = if is_climb { climb_perf } else { descent_perf };
+
+                    if let Some(dist) = perf.and_then(|p| transition_distance(p, &prev, level, leg))
+                    {
+                        let level_of_dist = from_dist + dist;
+
+                        profile.push(if is_climb {
+                            VerticalPoint::TopOfClimb {
+                                level: *level,
+                                distance: level_of_dist,
+                            }
+                        } else {
+                            VerticalPoint::EndOfDescent {
+                                level: *level,
+                                distance: level_of_dist,
+                            }
+                        });
+
+                        overflow = level_of_dist > total_dist || level_of_dist >= total_route_dist;
+                    }
                 }
 
+                // the new level applies even if the transition from an unknown
+                // level can't be placed
                 prev_level = Some(*level);
             }
 
@@ -513,6 +607,106 @@ impl VerticalProfile {
         &self.intersections
     }
 
+    /// Returns the airspace intersections that the route doesn't exit, because
+    /// it ends inside the airspace.
+    ///
+    /// This is usually the case when landing at an airport within a control
+    /// zone.
+    pub fn open_ended_intersections(&self) -> impl Iterator<Item = &AirspaceIntersection> + '_ {
+        self.intersections
+            .iter()
+            .filter(|intersection| intersection.ends_inside)
+    }
+
+    /// Returns the airspaces that the route penetrates in the order they are
+    /// entered.
+    ///
+    /// Only airspaces that the route penetrates vertically are considered,
+    /// i.e. the levels of the profile from the entry to the exit reach into
+    /// the airspace between its floor and ceiling. An airspace is considered
+    /// penetrated if the profile has no level there. The levels and vertical
+    /// limits are compared like in [`max_crossed_ceiling`], so that limits
+    /// above ground can be compared to altitudes.
+    ///
+    /// The profile doesn't know the terrain below the airspace. Thus, a limit
+    /// above ground is resolved as if the ground was at sea level, which puts
+    /// it too low over elevated terrain, e.g. a floor of 1000 ft AGL over
+    /// 2000 ft terrain is taken as 1000 ft MSL. A warning is logged for each
+    /// airspace with such a limit.
+    ///
+    /// [`max_crossed_ceiling`]: Self::max_crossed_ceiling
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::nd::AirspaceClassification;
+    /// # use efb::route::VerticalProfile;
+    /// # fn brief(profile: &VerticalProfile) {
+    /// // the first airspace that requires a clearance
+    /// let controlled = profile.penetrations().find(|intersection| {
+    ///     intersection.airspace().classification != Some(AirspaceClassification::G)
+    /// });
+    /// # }
+    /// ```
+    pub fn penetrations(&self) -> impl Iterator<Item = &AirspaceIntersection> + '_ {
+        self.intersections
+            .iter()
+            .filter(|intersection| self.penetrates(intersection))
+    }
+
+    /// Returns the airspace that the route penetrates first.
+    ///
+    /// This is the first of the [`penetrations`](Self::penetrations). Use this
+    /// e.g. for a departure briefing to find the first airspace the route
+    /// enters.
+    pub fn first_penetration(&self) -> Option<&AirspaceIntersection> {
+        self.penetrations().next()
+    }
+
+    /// Returns `true` if the levels of the profile along the `intersection`
+    /// reach into the airspace or are unknown.
+    fn penetrates(&self, intersection: &AirspaceIntersection) -> bool {
+        // the level when entering the airspace and any level until the exit,
+        // where the level at the entry is held since the previous point or, if
+        // the route has no level before, until the next point
+        let entry_level = self
+            .profile
+            .iter()
+            .rev()
+            .filter(|point| point.distance() <= &intersection.entry_distance)
+            .find_map(VerticalPoint::level)
+            .or_else(|| {
+                self.profile
+                    .iter()
+                    .filter(|point| point.distance() > &intersection.entry_distance)
+                    .find_map(VerticalPoint::level)
+            });
+        let levels = entry_level.into_iter().chain(
+            self.profile
+                .iter()
+                .filter(|point| {
+                    point.distance() > &intersection.entry_distance
+                        && point.distance() < &intersection.exit_distance
+                })
+                .filter_map(VerticalPoint::level),
+        );
+
+        let range = levels.map(msl_si).fold(None, |range, level| match range {
+            None => Some((level, level)),
+            Some((min, max)) => Some((level.min(min), level.max(max))),
+        });
+
+        warn_if_above_ground(intersection.airspace(), intersection.floor());
+        warn_if_above_ground(intersection.airspace(), intersection.ceiling());
+
+        match range {
+            Some((min, max)) => {
+                min < msl_si(intersection.ceiling()) && max >= msl_si(intersection.floor())
+            }
+            None => true,
+        }
+    }
+
     /// Returns the maximum level along the route.
     ///
     /// If the route contains any level measured in [AGL] or [pressure altitude] are ignored.
@@ -536,6 +730,131 @@ impl VerticalProfile {
             .max_by(|a, b| a.cmp(b))
     }
 
+    /// Returns the highest ceiling of the airspaces that the route crosses
+    /// laterally, e.g. to brief the level needed to overfly all of them.
+    ///
+    /// The ceilings are compared as altitudes above mean sea level at the
+    /// standard pressure, with ground at sea level and an unlimited ceiling
+    /// being the highest. Thus, ceilings with different datums, like a
+    /// flight level and an altitude, can be compared. A ceiling above ground
+    /// is too low over elevated terrain and logs a warning.
+    pub fn max_crossed_ceiling(&self) -> Option<&VerticalDistance> {
+        self.intersections
+            .iter()
+            .inspect(|intersection| {
+                warn_if_above_ground(intersection.airspace(), intersection.ceiling())
+            })
+            .map(AirspaceIntersection::ceiling)
+            .max_by(|a, b| msl_si(a).total_cmp(&msl_si(b)))
+    }
+
+    /// Checks the terrain clearance of the profile along the route's legs.
+    ///
+    /// The `terrain` returns the elevation at a point and is sampled along
+    /// each leg at least every [`CLEARANCE_SAMPLE_INTERVAL_NM`]. At each sample
+    /// the level of the profile is interpolated between its points, so the
+    /// climbs and descents to the [TOC] and from the [TOD] are checked too.
+    /// Without climb or descent performance, the level changes evenly between
+    /// the fixes. A warning is returned for each leg on which the level is less
+    /// than the `margin` above the terrain, pointing at the sample with the
+    /// least clearance.
+    ///
+    /// The departure from and the arrival at an airport's elevation aren't
+    /// checked, since the aircraft is below the margin there by design.
+    /// Instead, the first and last level of the profile is held until the
+    /// route's origin and destination. Without levels, nothing is checked.
+    ///
+    /// [TOC]: VerticalPoint::TopOfClimb
+    /// [TOD]: VerticalPoint::TopOfDescent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use efb::measurements::Length;
+    /// # use efb::route::{Route, VerticalProfile};
+    /// # use geo::Point;
+    /// # fn check(route: &Route, profile: &VerticalProfile) {
+    /// // a terrain model that knows a single hill
+    /// let terrain = |point: &Point<f64>| {
+    ///     if (point.x() - 9.5).abs() < 0.1 {
+    ///         Length::ft(1800.0)
+    ///     } else {
+    ///         Length::ft(0.0)
+    ///     }
+    /// };
+    ///
+    /// for warning in profile.check_clearance(route, terrain, Length::ft(1000.0)) {
+    ///     println!("insufficient clearance on leg {}", warning.leg() + 1);
+    /// }
+    /// # }
+    /// ```
+    pub fn check_clearance<F>(
+        &self,
+        route: &Route,
+        terrain: F,
+        margin: Length,
+    ) -> Vec<ClearanceWarning>
+    where
+        F: Fn(&Point<f64>) -> Length,
+    {
+        // the levels of the profile without the airports' elevation
+        let mut levels: Vec<(Length, VerticalDistance)> = self
+            .profile
+            .iter()
+            .filter(|point| {
+                !matches!(
+                    point,
+                    VerticalPoint::NavAid {
+                        level: Some(level),
+                        navaid: NavAid::Airport(arpt),
+                        ..
+                    } if *level == arpt.elevation
+                )
+            })
+            .filter_map(|point| point.level().map(|level| (*point.distance(), *level)))
+            .collect();
+        levels.sort_by(|(a, _), (b, _)| a.to_si().total_cmp(&b.to_si()));
+
+        let mut warnings = Vec::new();
+        let mut leg_start = Length::nm(0.0);
+
+        for (i, leg) in route.legs().iter().enumerate() {
+            let dist = *leg.dist();
+            let nm = *dist.convert_to(LengthUnit::NauticalMiles).value();
+            let samples = (nm / CLEARANCE_SAMPLE_INTERVAL_NM).ceil().max(1.0) as usize;
+
+            let least_clearance = (0..=samples)
+                .filter_map(|sample| {
+                    let fraction = sample as f64 / samples as f64;
+                    let point = leg.intermediate(fraction);
+                    let distance = leg_start + dist * fraction as f32;
+                    let elevation = terrain(&point);
+
+                    let (level, altitude) = level_at(&levels, distance, elevation)?;
+                    let clearance = altitude - elevation.to_si();
+
+                    (clearance < margin.to_si())
+                        .then_some((clearance, point, distance, level, elevation))
+                })
+                .min_by(|(a, ..), (b, ..)| a.total_cmp(b));
+
+            if let Some((clearance, point, distance, level, elevation)) = least_clearance {
+                trace!("leg {} clears terrain by {:.0} m only", i, clearance);
+                warnings.push(ClearanceWarning {
+                    leg: i,
+                    point,
+                    distance,
+                    level,
+                    terrain: elevation,
+                });
+            }
+
+            leg_start = leg_start + dist;
+        }
+
+        warnings
+    }
+
     /// Returns the number of airspace intersections.
     pub fn len(&self) -> usize {
         self.intersections.len()
@@ -547,6 +866,46 @@ impl VerticalProfile {
     }
 }
 
+/// Returns the level of the profile at the `distance` and its altitude above
+/// mean sea level in SI over terrain of the `elevation`.
+///
+/// The level is interpolated between the surrounding `levels` and held before
+/// the first and after the last of them. An interpolated level is returned as
+/// altitude. Returns `None` if there are no levels or the level is unlimited.
+fn level_at(
+    levels: &[(Length, VerticalDistance)],
+    distance: Length,
+    elevation: Length,
+) -> Option<(VerticalDistance, f32)> {
+    let msl = |level: &VerticalDistance| {
+        level
+            .to_msl(Pressure::STD, elevation)
+            .map(|alt| alt.to_si())
+    };
+
+    let (prev, next) = match levels.iter().position(|(d, _)| *d >= distance) {
+        Some(0) => (&levels[0], &levels[0]),
+        Some(n) => (&levels[n - 1], &levels[n]),
+        None => {
+            let last = levels.last()?;
+            (last, last)
+        }
+    };
+
+    if prev.1 == next.1 || next.0 <= prev.0 {
+        return Some((next.1, msl(&next.1)?));
+    }
+
+    let fraction = (distance - prev.0) / (next.0 - prev.0);
+    let altitude = msl(&prev.1)? + (msl(&next.1)? - msl(&prev.1)?) * fraction;
+    let ft = Length::m(altitude.max(0.0)).convert_to(LengthUnit::Feet);
+
+    Some((
+        VerticalDistance::Altitude(ft.value().round() as u16),
+        altitude,
+    ))
+}
+
 /// Computes the horizontal distance required for a level transition,
 /// accounting for the leg's headwind.
 fn transition_distance(
@@ -580,40 +939,74 @@ fn geodesic_distance_to_intersection(
         .lines()
         .nth(seg_idx)
         .expect("valid segment index");
-    let point = Point::new(coord.x, coord.y);
+    let point = Point::from(*coord);
     let fraction = segment.line_locate_point(&point).unwrap_or(0.0) as f32;
 
     prior + segment_lengths[seg_idx] * fraction
 }
 
+/// Warns that the `limit` of the `airspace` is resolved with ground at sea
+/// level if it's above ground.
+fn warn_if_above_ground(airspace: &Airspace, limit: &VerticalDistance) {
+    if let VerticalDistance::Agl(_) = limit {
+        warn!(
+            "limit {limit} of airspace {} is resolved with ground at sea level",
+            airspace.name
+        );
+    }
+}
+
+/// Resolves a vertical distance to an altitude above mean sea level in SI at
+/// the standard pressure and with ground at sea level.
+///
+/// Heights above ground are too low over elevated terrain.
+///
+/// Unlike the order of vertical distances, this compares any datums with each
+/// other. An unlimited vertical distance is infinite.
+fn msl_si(vd: &VerticalDistance) -> f32 {
+    vd.to_msl(Pressure::STD, Length::ft(0.0))
+        .map_or(f32::INFINITY, |alt| alt.to_si())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::nd::{AirspaceClassification, AirspaceType};
+    use crate::geom::{coords_from_lat_lon_pairs, point_from_lat_lon};
+    use crate::nd::{fixtures, AirspaceClassification, NavigationDataBuilder, Waypoint};
 
-    fn test_airspace(name: &str, coords: &[(f64, f64)]) -> Rc<Airspace> {
-        let exterior: Vec<geo::Coord<f64>> = coords
-            .iter()
-            .map(|&(lat, lon)| geo::Coord { x: lon, y: lat })
-            .collect();
+    fn test_airspace(name: &str, coords: &[(f64, f64)]) -> Shared<Airspace> {
+        let exterior = coords_from_lat_lon_pairs(coords);
 
-        Rc::new(Airspace {
-            name: name.to_string(),
-            airspace_type: AirspaceType::CTA,
-            classification: Some(AirspaceClassification::D),
-            ceiling: VerticalDistance::Fl(65),
-            floor: VerticalDistance::Msl(1500),
-            polygon: geo::Polygon::new(geo::LineString::from(exterior), vec![]),
-        })
+        Shared::new(fixtures::airspace(
+            name,
+            geo::Polygon::new(geo::LineString::from(exterior), vec![]),
+        ))
+    }
+
+    /// Helper: a class D airspace from 1500 ft MSL to FL 65 that spans one
+    /// degree of latitude north of 53°N and 0.6° of longitude east of `west`.
+    fn test_box_airspace(name: &str, west: f64) -> Airspace {
+        Shared::unwrap_or_clone(test_airspace(
+            name,
+            &[
+                (53.0, west),
+                (53.0, west + 0.6),
+                (54.0, west + 0.6),
+                (54.0, west),
+                (53.0, west),
+            ],
+        ))
+    }
+
+    fn test_waypoint(ident: &str, lat: f64, lon: f64) -> Waypoint {
+        fixtures::waypoint(ident, point_from_lat_lon(lat, lon))
     }
 
     /// Helper: build segment_lengths and total_length for a route line.
     fn route_lengths(route_line: &LineString<f64>) -> (Vec<Length>, Length) {
         let segment_lengths: Vec<Length> = route_line
             .lines()
-            .map(|line| {
-                Length::m(Geodesic.distance(Point::from(line.start), Point::from(line.end)) as f32)
-            })
+            .map(|line| geodesic_distance(Point::from(line.start), Point::from(line.end)))
             .collect();
         let total_length: Length = segment_lengths.iter().copied().sum();
         (segment_lengths, total_length)
@@ -629,29 +1022,17 @@ mod tests {
 
     #[test]
     fn profile_finds_airspace_intersection() {
-        use crate::nd::NavigationDataBuilder;
-
         // Create an airspace from lat 53-54, lon 9-10
-        let airspace = Airspace {
-            name: "Test TMA".to_string(),
-            airspace_type: AirspaceType::CTA,
-            classification: Some(AirspaceClassification::D),
-            ceiling: VerticalDistance::Fl(65),
-            floor: VerticalDistance::Msl(1500),
-            polygon: {
-                let coords: Vec<geo::Coord<f64>> = [
-                    (53.0, 9.0),
-                    (53.0, 10.0),
-                    (54.0, 10.0),
-                    (54.0, 9.0),
-                    (53.0, 9.0),
-                ]
-                .iter()
-                .map(|&(lat, lon)| geo::Coord { x: lon, y: lat })
-                .collect();
-                geo::Polygon::new(geo::LineString::from(coords), vec![])
-            },
-        };
+        let airspace = Shared::unwrap_or_clone(test_airspace(
+            "Test TMA",
+            &[
+                (53.0, 9.0),
+                (53.0, 10.0),
+                (54.0, 10.0),
+                (54.0, 9.0),
+                (53.0, 9.0),
+            ],
+        ));
 
         let mut builder = NavigationDataBuilder::new();
         builder.add_airspace(airspace);
@@ -733,6 +1114,16 @@ mod tests {
             "Exit distance should be well past 0 nm, got {} nm",
             intersection.exit_distance().value()
         );
+
+        let profile = VerticalProfile {
+            intersections,
+            profile: Vec::new(),
+        };
+        assert_eq!(
+            profile.open_ended_intersections().count(),
+            0,
+            "Route exiting the CTR should not be open ended"
+        );
     }
 
     #[test]
@@ -799,6 +1190,16 @@ mod tests {
             "Exit should be at EDHL lat, got {}",
             intersection.exit_point().y()
         );
+
+        let profile = VerticalProfile {
+            intersections,
+            profile: Vec::new(),
+        };
+        assert_eq!(
+            profile.open_ended_intersections().count(),
+            1,
+            "Route ending inside the CTR should be open ended"
+        );
     }
 
     #[test]
@@ -890,6 +1291,18 @@ mod tests {
             intersection.exit_distance()
         );
 
+        // Fractions of the total route
+        assert!(
+            (intersection.entry_fraction() - entry_fraction as f32).abs() < 0.01,
+            "Entry fraction should be ~1/3, got {}",
+            intersection.entry_fraction()
+        );
+        assert!(
+            (intersection.exit_fraction() - exit_fraction as f32).abs() < 0.01,
+            "Exit fraction should be ~2/3, got {}",
+            intersection.exit_fraction()
+        );
+
         // Intersection length ~1/3 of total route
         let expected_length = expected_exit - expected_entry;
         assert!(
@@ -899,4 +1312,357 @@ mod tests {
             intersection.length()
         );
     }
+
+    #[test]
+    fn route_crossing_antimeridian_finds_airspace() {
+        //        179.5E      180     179.7W   179.5W
+        //  -16.9               +--------+
+        //                      |        |
+        //  -17.0  ALPHA -------+--------+---- BRAVO
+        //                      |        |
+        //  -17.1               +--------+
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", -17.0, 179.5));
+        builder.add_waypoint(test_waypoint("BRAVO", -17.0, -179.5));
+        builder.add_airspace(Shared::unwrap_or_clone(test_airspace(
+            "Antimeridian",
+            &[
+                (-17.1, 179.9),
+                (-17.1, -179.7),
+                (-16.9, -179.7),
+                (-16.9, 179.9),
+                (-17.1, 179.9),
+            ],
+        )));
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("ALPHA BRAVO", &nd)
+            .expect("route should decode");
+
+        // one degree of longitude at 17° south is ~57 NM
+        let dist = *route.totals(None).expect("route should have totals").dist();
+        assert!(dist < Length::nm(60.0), "route should be short, got {dist}");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        let intersections = profile.intersections();
+
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].airspace().name, "Antimeridian");
+        assert!(
+            (intersections[0].entry_point().x() - 179.9).abs() < 0.01,
+            "entry longitude should be ~179.9, got {}",
+            intersections[0].entry_point().x()
+        );
+        assert!(
+            (intersections[0].exit_point().x() + 179.7).abs() < 0.01,
+            "exit longitude should be ~-179.7, got {}",
+            intersections[0].exit_point().x()
+        );
+        assert!(intersections[0].length() < Length::nm(25.0));
+    }
+
+    #[test]
+    fn totals_match_profile_length() {
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("BRAVO", 53.9, 9.6));
+        builder.add_waypoint(test_waypoint("CHARLY", 53.6, 10.4));
+        // the route ends inside the airspace, thus, its exit is the route's end
+        builder.add_airspace(Shared::unwrap_or_clone(test_airspace(
+            "Enclosing",
+            &[
+                (53.0, 8.5),
+                (53.0, 11.0),
+                (54.5, 11.0),
+                (54.5, 8.5),
+                (53.0, 8.5),
+            ],
+        )));
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("ALPHA BRAVO CHARLY", &nd)
+            .expect("route should decode");
+
+        let total = *route.totals(None).expect("route should have totals").dist();
+        let sum: Length = route.legs().iter().map(|leg| *leg.dist()).sum();
+        assert_eq!(total, sum);
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        let exit = *profile.intersections()[0].exit_distance();
+        assert!(
+            (total - exit).value().abs() < 0.01,
+            "profile length {exit} should match route distance {total}"
+        );
+    }
+
+    #[test]
+    fn clearance_warning_for_terrain_mid_route() {
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("BRAVO", 53.5, 10.0));
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA BRAVO", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        let flat = |_: &Point<f64>| Length::ft(0.0);
+        let hill = |point: &Point<f64>| {
+            if (point.x() - 9.5).abs() < 0.05 {
+                Length::ft(2500.0)
+            } else {
+                Length::ft(0.0)
+            }
+        };
+
+        assert!(profile
+            .check_clearance(&route, flat, Length::ft(1000.0))
+            .is_empty());
+
+        let warnings = profile.check_clearance(&route, hill, Length::ft(1000.0));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].leg(), 0);
+        assert_eq!(warnings[0].level(), &VerticalDistance::Altitude(3000));
+        assert_eq!(warnings[0].terrain(), &Length::ft(2500.0));
+        assert!((warnings[0].point().x() - 9.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn clearance_warning_for_terrain_in_climb() {
+        use crate::fp::ClimbDescentPerformance;
+        use crate::measurements::{Mass, VerticalRate};
+        use crate::{Fuel, FuelFlow, FuelType};
+
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("BRAVO", 53.5, 10.0));
+        builder.add_waypoint(test_waypoint("CHARLY", 53.5, 11.0));
+        let nd = builder.build();
+
+        // climbs 7000 ft at 500 fpm and 80 kt, i.e. within 19 NM after BRAVO
+        let climb = ClimbDescentPerformance::from_fn(
+            |_| {
+                (
+                    Speed::kt(80.0),
+                    VerticalRate::fpm(500.0),
+                    FuelFlow::PerHour(Fuel::new(Mass::kg(20.0), FuelType::AvGas)),
+                )
+            },
+            VerticalDistance::Fl(150),
+        );
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA BRAVO F100 CHARLY", &nd)
+            .expect("route should decode");
+
+        // a ridge about 4 NM after BRAVO, that F100 clears but the climb not
+        let ridge = |point: &Point<f64>| {
+            if (point.x() - 10.1).abs() < 0.02 {
+                Length::ft(6000.0)
+            } else {
+                Length::ft(0.0)
+            }
+        };
+
+        let profile = VerticalProfile::new(&route, &nd, Some(&climb), None);
+        let warnings = profile.check_clearance(&route, ridge, Length::ft(1000.0));
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].leg(), 1);
+        assert!((warnings[0].point().x() - 10.1).abs() < 0.02);
+        assert!(matches!(
+            warnings[0].level(),
+            VerticalDistance::Altitude(ft) if (4000..5000).contains(ft)
+        ));
+    }
+
+    #[test]
+    fn first_penetration_is_nearest_airspace_at_route_level() {
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("DELTA", 53.5, 12.0));
+        // the route passes below the first airspace
+        builder.add_airspace(Airspace {
+            floor: VerticalDistance::Fl(50),
+            ..test_box_airspace("ABOVE", 9.2)
+        });
+        builder.add_airspace(test_box_airspace("NEAR", 10.2));
+        builder.add_airspace(test_box_airspace("FAR", 11.2));
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA DELTA", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        assert_eq!(profile.len(), 3);
+
+        let first = profile
+            .first_penetration()
+            .expect("route should penetrate an airspace");
+        assert_eq!(first.airspace().name, "NEAR");
+    }
+
+    #[test]
+    fn penetration_of_airspace_above_ground() {
+        // an airspace from AL 1000agl to FL65
+        let tmz = Airspace {
+            floor: VerticalDistance::Agl(1000),
+            ..test_box_airspace("TMZ", 9.2)
+        };
+
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("AAA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("BBB", 53.5, 10.0));
+        builder.add_airspace(tmz);
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 AAA BBB", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        let first = profile
+            .first_penetration()
+            .expect("route should penetrate the airspace");
+        assert_eq!(first.airspace().name, "TMZ");
+    }
+
+    #[test]
+    fn penetrations_are_in_entry_order() {
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("DELTA", 53.5, 12.0));
+        builder.add_airspace(Airspace {
+            classification: Some(AirspaceClassification::G),
+            ..test_box_airspace("GOLF", 9.2)
+        });
+        builder.add_airspace(test_box_airspace("DELTA", 10.2));
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA DELTA", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        let names: Vec<_> = profile
+            .penetrations()
+            .map(|intersection| intersection.airspace().name.as_str())
+            .collect();
+        assert_eq!(names, ["GOLF", "DELTA"]);
+
+        // the class G airspace doesn't hide the controlled one
+        let controlled = profile
+            .penetrations()
+            .find(|intersection| {
+                intersection.airspace().classification != Some(AirspaceClassification::G)
+            })
+            .expect("route should penetrate a controlled airspace");
+        assert_eq!(controlled.airspace().name, "DELTA");
+    }
+
+    #[test]
+    fn max_crossed_ceiling_resolves_datums() {
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("DELTA", 53.5, 12.0));
+        builder.add_airspace(Airspace {
+            ceiling: VerticalDistance::Fl(65),
+            ..test_box_airspace("TMA", 9.2)
+        });
+        builder.add_airspace(Airspace {
+            ceiling: VerticalDistance::Altitude(7000),
+            ..test_box_airspace("CTR", 10.2)
+        });
+        // an unlimited airspace aside of the route is ignored
+        builder.add_airspace(Airspace {
+            ceiling: VerticalDistance::Unlimited,
+            ..test_box_airspace("ASIDE", 14.0)
+        });
+        let nd = builder.build();
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA DELTA", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, None, None);
+        assert_eq!(profile.intersections().len(), 2);
+        assert_eq!(
+            profile.max_crossed_ceiling(),
+            Some(&VerticalDistance::Altitude(7000))
+        );
+    }
+
+    #[test]
+    fn level_changes_step_the_cruise() {
+        use crate::fp::ClimbDescentPerformance;
+        use crate::measurements::{Mass, VerticalRate};
+        use crate::{Fuel, FuelFlow, FuelType};
+
+        let mut builder = NavigationDataBuilder::new();
+        builder.add_waypoint(test_waypoint("ALPHA", 53.5, 9.0));
+        builder.add_waypoint(test_waypoint("BRAVO", 53.5, 10.0));
+        builder.add_waypoint(test_waypoint("CHARLY", 53.5, 11.0));
+        builder.add_waypoint(test_waypoint("DELTA", 53.5, 12.0));
+        let nd = builder.build();
+
+        let climb = ClimbDescentPerformance::from_fn(
+            |_| {
+                (
+                    Speed::kt(80.0),
+                    VerticalRate::fpm(500.0),
+                    FuelFlow::PerHour(Fuel::new(Mass::kg(20.0), FuelType::AvGas)),
+                )
+            },
+            VerticalDistance::Fl(150),
+        );
+
+        let mut route = Route::new();
+        route
+            .decode("N0100 A030 ALPHA BRAVO F100 CHARLY F140 DELTA", &nd)
+            .expect("route should decode");
+
+        let profile = VerticalProfile::new(&route, &nd, Some(&climb), None);
+
+        let cruise_levels: Vec<_> = profile
+            .profile()
+            .iter()
+            .filter_map(|point| match point {
+                VerticalPoint::TopOfClimb { level, .. } => Some(*level),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            cruise_levels,
+            [VerticalDistance::Fl(100), VerticalDistance::Fl(140)]
+        );
+
+        let fix_levels: Vec<_> = profile
+            .profile()
+            .iter()
+            .filter_map(|point| match point {
+                VerticalPoint::NavAid { level, .. } => Some(*level),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            fix_levels,
+            [
+                Some(VerticalDistance::Altitude(3000)),
+                Some(VerticalDistance::Fl(100)),
+                Some(VerticalDistance::Fl(140)),
+            ]
+        );
+    }
 }
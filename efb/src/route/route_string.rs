@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::Error;
+use crate::measurements::{AngleUnit, Speed, SpeedUnit};
+use crate::{VerticalDistance, Wind};
+
+/// Builder of a route string that can be decoded into a [`Route`].
+///
+/// Instead of concatenating a string, the route elements are added with their
+/// types and written in the canonical form of the route grammar, e.g. a speed
+/// of 107 kt becomes `N0107`.
+///
+/// # Examples
+///
+/// ```
+/// # use efb::error::Error;
+/// # use efb::measurements::Speed;
+/// # use efb::route::RouteString;
+/// # use efb::VerticalDistance;
+/// # fn main() -> Result<(), Error> {
+/// let route = RouteString::new()
+///     .speed(Speed::kt(107.0))
+///     .level(VerticalDistance::Altitude(2500))
+///     .fix("EDDH33")
+///     .fix("D")
+///     .direct()
+///     .fix("EDHL")
+///     .build()?;
+///
+/// assert_eq!(route, "N0107 A025 EDDH33 D DCT EDHL");
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Route`]: super::Route
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RouteString {
+    elements: Vec<Element>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Element {
+    Speed(Speed),
+    Level(VerticalDistance),
+    Wind(Wind),
+    Fix(String),
+    Direct,
+}
+
+impl RouteString {
+    /// Creates an empty route string.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the true airspeed for the subsequent legs.
+    pub fn speed(&mut self, speed: Speed) -> &mut Self {
+        self.elements.push(Element::Speed(speed));
+        self
+    }
+
+    /// Adds the cruise level for the subsequent legs.
+    pub fn level(&mut self, level: VerticalDistance) -> &mut Self {
+        self.elements.push(Element::Level(level));
+        self
+    }
+
+    /// Adds the wind for the subsequent legs.
+    pub fn wind(&mut self, wind: Wind) -> &mut Self {
+        self.elements.push(Element::Wind(wind));
+        self
+    }
+
+    /// Adds a fix by its ident.
+    ///
+    /// Airports can be followed by a runway designator e.g. `EDDH33`.
+    pub fn fix(&mut self, ident: &str) -> &mut Self {
+        self.elements.push(Element::Fix(ident.to_uppercase()));
+        self
+    }
+
+    /// Adds a direct routing to the next fix.
+    pub fn direct(&mut self) -> &mut Self {
+        self.elements.push(Element::Direct);
+        self
+    }
+
+    /// Builds the route string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ImplausibleValue`] if an element can't be expressed in
+    /// the route grammar. The speed must be below 10000 kt, the wind speed
+    /// below 100 kt and the level a flight level or altitude.
+    pub fn build(&self) -> Result<String, Error> {
+        self.elements
+            .iter()
+            .map(Element::to_route_element)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|elements| elements.join(" "))
+    }
+}
+
+impl Element {
+    fn to_route_element(&self) -> Result<String, Error> {
+        match self {
+            Self::Speed(speed) => {
+                let kt = speed.convert_to(SpeedUnit::Knots).value().round();
+
+                if (0.0..10000.0).contains(&kt) {
+                    Ok(format!("N{kt:04}"))
+                } else {
+                    Err(Error::ImplausibleValue)
+                }
+            }
+            Self::Level(level) => match level {
+                VerticalDistance::Fl(fl) if *fl < 1000 => Ok(format!("F{fl:03}")),
+                VerticalDistance::Altitude(ft) | VerticalDistance::Msl(ft) => {
+                    Ok(format!("A{:03}", (*ft as f32 / 100.0).round()))
+                }
+                _ => Err(Error::ImplausibleValue),
+            },
+            Self::Wind(wind) => {
+                let direction = wind
+                    .direction
+                    .convert_to(AngleUnit::TrueNorth)
+                    .value()
+                    .round()
+                    % 360.0;
+                let kt = wind.speed.convert_to(SpeedUnit::Knots).value().round();
+
+                if (0.0..100.0).contains(&kt) {
+                    Ok(format!("{direction:03}{kt:02}KT"))
+                } else {
+                    Err(Error::ImplausibleValue)
+                }
+            }
+            Self::Fix(ident) => Ok(ident.clone()),
+            Self::Direct => Ok(String::from("DCT")),
+        }
+    }
+}
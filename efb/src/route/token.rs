@@ -39,7 +39,6 @@
 
 use std::fmt;
 use std::ops::Range;
-use std::rc::Rc;
 
 use log::{debug, trace, warn};
 
@@ -51,6 +50,8 @@ use crate::measurements::Speed;
 use crate::nd::*;
 use crate::{VerticalDistance, Wind};
 
+use super::FixResolver;
+
 #[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Token {
@@ -87,7 +88,7 @@ pub enum TokenKind {
     Wind(Wind),
     /// Airport with optional runway specification.
     Airport {
-        arpt: Rc<Airport>,
+        arpt: Shared<Airport>,
         rwy: Option<Runway>,
     },
     /// Navigation aid (waypoint, VOR, NDB, etc.) - but NOT airports.
@@ -114,9 +115,9 @@ pub struct Tokens {
 }
 
 impl Tokens {
-    pub fn new(s: &str, nd: &NavigationData) -> Self {
+    pub fn new(s: &str, nd: &NavigationData, resolvers: &[&dyn FixResolver]) -> Self {
         debug!("tokenizing route string: {:?}", s);
-        let words = Lexer::lex(s, nd);
+        let words = Lexer::lex(s, nd, resolvers);
         debug!("lexer produced {} word(s)", words.len());
         let tokens = Self::tokenize(words, nd);
         debug!("tokenizer produced {} token(s)", tokens.len());
@@ -133,7 +134,7 @@ impl Tokens {
 
     fn tokenize(words: Vec<Word>, nd: &NavigationData) -> Vec<Token> {
         let mut tokens: Vec<Token> = Vec::new();
-        let mut terminal: Option<Rc<Airport>> = None;
+        let mut terminal: Option<Shared<Airport>> = None;
 
         for (i, word) in words.iter().enumerate() {
             let kind = match &word.kind {
@@ -149,12 +150,12 @@ impl Tokens {
 
                 WordKind::Airport { arpt, rwy } => {
                     // Each airport sets a new terminal scope
-                    terminal = Some(Rc::clone(arpt));
+                    terminal = Some(Shared::clone(arpt));
 
                     if i == 0 {
                         // First airport always gets added
                         TokenKind::Airport {
-                            arpt: Rc::clone(arpt),
+                            arpt: Shared::clone(arpt),
                             rwy: rwy.clone(),
                         }
                     } else {
@@ -174,7 +175,7 @@ impl Tokens {
                                 }),
                             ) => continue,
                             _ => TokenKind::Airport {
-                                arpt: Rc::clone(arpt),
+                                arpt: Shared::clone(arpt),
                                 rwy: rwy.clone(),
                             },
                         }
@@ -260,8 +261,8 @@ impl Tokens {
     }
 
     fn resolve_in_terminal_areas(
-        current: Option<&Rc<Airport>>,
-        next: Option<&Rc<Airport>>,
+        current: Option<&Shared<Airport>>,
+        next: Option<&Shared<Airport>>,
         ident: &str,
         nd: &NavigationData,
     ) -> (Option<NavAid>, Option<NavAid>) {
@@ -277,7 +278,7 @@ impl Tokens {
     }
 
     /// Looks ahead in the word stream to find the next airport.
-    fn lookahead_terminal_area(words: &[Word]) -> Option<Rc<Airport>> {
+    fn lookahead_terminal_area(words: &[Word]) -> Option<Shared<Airport>> {
         for word in words {
             match &word.kind {
                 WordKind::Airport { arpt, .. } => return Some(arpt.clone()),
@@ -292,18 +293,14 @@ impl Tokens {
 
 impl fmt::Display for Tokens {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut iter = self.tokens.iter();
-        if let Some(first) = iter.next() {
-            write!(f, "{}", first.raw)?;
-            let mut prev = first;
-            for token in iter {
-                if prev.raw.ends_with('@') {
-                    write!(f, "{}", token.raw)?;
-                } else {
-                    write!(f, " {}", token.raw)?;
-                }
-                prev = token;
+        let mut prev: Option<&Token> = None;
+        for token in &self.tokens {
+            // words of the same group like A022@N2 or N2/N0107A025 are adjacent
+            if prev.is_some_and(|prev| prev.range.end != token.range.start) {
+                write!(f, " ")?;
             }
+            write!(f, "{}", token.raw)?;
+            prev = Some(token);
         }
         Ok(())
     }
@@ -355,13 +352,13 @@ enum WordKind {
     LevelAt(VerticalDistance),
     Wind(Wind),
     Airport {
-        arpt: Rc<Airport>,
+        arpt: Shared<Airport>,
         rwy: Option<Runway>,
     },
     NavAid(NavAid),
     VFRWaypoint {
         ident: String,
-        wp: Option<Rc<Waypoint>>,
+        wp: Option<Shared<Waypoint>>,
     },
     Err(Error),
 }
@@ -369,31 +366,84 @@ enum WordKind {
 struct Lexer;
 
 impl Lexer {
-    fn lex(prompt: &str, nd: &NavigationData) -> Vec<Word> {
+    fn lex(prompt: &str, nd: &NavigationData, resolvers: &[&dyn FixResolver]) -> Vec<Word> {
         let upper = prompt.to_uppercase();
         let base = upper.as_ptr() as usize;
 
         upper
             // include the separator so we can figure out the @ relations
             .split_inclusive(&[' ', '@'])
-            .filter_map(|s| {
+            .flat_map(|s| {
                 let s = s.trim_end();
 
                 if s.is_empty() {
-                    return None;
+                    return Vec::new();
                 }
 
                 let start = s.as_ptr() as usize - base;
-                Some(Word {
+
+                if let Some(words) = Self::lex_change_at_fix(s, start, nd, resolvers) {
+                    return words;
+                }
+
+                vec![Word {
                     range: start..start + s.len(),
                     raw: s.to_string(),
-                    kind: Self::classify(s, nd),
-                })
+                    kind: Self::classify(s, nd, resolvers),
+                }]
             })
             .collect()
     }
 
-    fn classify(s: &str, nd: &NavigationData) -> WordKind {
+    /// Lexes a change of speed and level at a fix like `OSN/N0450F350` as the
+    /// fix followed by the speed and level, so the change applies from the fix.
+    fn lex_change_at_fix(
+        s: &str,
+        start: usize,
+        nd: &NavigationData,
+        resolvers: &[&dyn FixResolver],
+    ) -> Option<Vec<Word>> {
+        let (fix, change) = s.split_once('/')?;
+
+        if fix.is_empty() {
+            return None;
+        }
+
+        // Mach numbers have 3 figures, other speeds 4
+        let speed_len = if change.starts_with('M') { 4 } else { 5 };
+        let (speed_raw, level_raw) = change.split_at_checked(speed_len)?;
+        let speed = speed_raw.parse::<Speed>().ok()?;
+        let level = level_raw.parse::<VerticalDistance>().ok()?;
+        trace!(
+            "lexed {:?} as speed {} and level {} at fix",
+            s,
+            speed,
+            level
+        );
+
+        let speed_at = start + fix.len() + 1;
+        let level_at = speed_at + speed_len;
+
+        Some(vec![
+            Word {
+                range: start..speed_at,
+                raw: s[..=fix.len()].to_string(),
+                kind: Self::classify(fix, nd, resolvers),
+            },
+            Word {
+                range: speed_at..level_at,
+                raw: speed_raw.to_string(),
+                kind: WordKind::Speed(speed),
+            },
+            Word {
+                range: level_at..start + s.len(),
+                raw: level_raw.to_string(),
+                kind: WordKind::Level(level),
+            },
+        ])
+    }
+
+    fn classify(s: &str, nd: &NavigationData, resolvers: &[&dyn FixResolver]) -> WordKind {
         // Check for special keywords first
         if s == "DCT" {
             trace!("lexed {:?} as DCT (direct)", s);
@@ -408,8 +458,13 @@ impl Lexer {
             }
         };
 
-        // Try navaids or airports
-        if let Some(navaid) = nd.find(s) {
+        // Try navaids or airports of the resolvers first
+        let navaid = resolvers
+            .iter()
+            .find_map(|resolver| resolver.resolve(s))
+            .or_else(|| nd.find(s));
+
+        if let Some(navaid) = navaid {
             return match navaid {
                 NavAid::Waypoint(wp) if wp.usage == WaypointUsage::VFROnly => {
                     trace!("lexed {:?} as VFR waypoint", s);
@@ -451,7 +506,7 @@ impl Lexer {
                 let rwy = arpt
                     .runways
                     .iter()
-                    .find(|rwy| rwy.designator == rwy_designator)
+                    .find(|rwy| rwy.matches_designator(rwy_designator))
                     .cloned();
 
                 return match rwy {
@@ -465,6 +520,27 @@ impl Lexer {
                         WordKind::Airport { arpt, rwy }
                     }
                     None => {
+                        let candidates: Vec<String> = arpt
+                            .runways
+                            .iter()
+                            .filter(|rwy| rwy.is_parallel_to(rwy_designator))
+                            .map(|rwy| rwy.designator.clone())
+                            .collect();
+
+                        if candidates.len() > 1 {
+                            warn!(
+                                "ambiguous runway {:?} for airport {}: {:?}",
+                                rwy_designator,
+                                arpt.ident(),
+                                candidates
+                            );
+                            return WordKind::Err(Error::AmbiguousRunway {
+                                arpt: arpt.ident(),
+                                rwy: rwy_designator.to_string(),
+                                candidates,
+                            });
+                        }
+
                         warn!(
                             "unknown runway {:?} for airport {}",
                             rwy_designator,
@@ -499,7 +575,7 @@ mod tests {
     // - Hamburg     (EDDH) with VRP November 1 & 2
     // - Luebeck     (EDHL) with VRP Whiskey and in close proximity to EDDH
     // - Heringsdorf (EDAH) with VRP Whiskey too
-    const ARINC_424_RECORDS: &'static [u8] = br#"
+    const ARINC_424_RECORDS: &[u8] = br#"
 SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
 SEURPCEDDHED N1    ED0    V     N53482105E010015451                                 WGE           NOVEMBER1                359892409
 SEURPCEDDHED N2    ED0    V     N53405701E010000576                                 WGE           NOVEMBER2                359902409
@@ -522,7 +598,7 @@ SEURPCEDAHED W     ED0    V     N53505381E013552347
             }
         }
 
-        fn airport(&self, ident: &str) -> Rc<Airport> {
+        fn airport(&self, ident: &str) -> Shared<Airport> {
             match self.nd.find(ident) {
                 Some(NavAid::Airport(arpt)) => arpt,
                 _ => panic!("should find airport {ident}"),
@@ -540,7 +616,7 @@ SEURPCEDAHED W     ED0    V     N53505381E013552347
     #[test]
     fn lexes_words() {
         let data = TestData::new();
-        let words = Lexer::lex("N0107 A0250 EDDH D DCT EDHL07", &data.nd);
+        let words = Lexer::lex("N0107 A0250 EDDH D DCT EDHL07", &data.nd, &[]);
 
         let edhl = data.airport("EDHL");
         let rwy07 = edhl.runways.iter().find(|r| r.designator == "07").cloned();
@@ -591,12 +667,107 @@ SEURPCEDAHED W     ED0    V     N53505381E013552347
         );
     }
 
+    #[test]
+    fn lexes_speed_and_level_at_fix() {
+        let data = TestData::new();
+        let words = Lexer::lex("N2/N0450F350", &data.nd, &[]);
+
+        assert_eq!(
+            words,
+            vec![
+                Word {
+                    range: 0..3,
+                    raw: "N2/".to_string(),
+                    kind: WordKind::VFRWaypoint {
+                        ident: "N2".to_string(),
+                        wp: None
+                    },
+                },
+                Word {
+                    range: 3..8,
+                    raw: "N0450".to_string(),
+                    kind: WordKind::Speed(Speed::kt(450.0)),
+                },
+                Word {
+                    range: 8..12,
+                    raw: "F350".to_string(),
+                    kind: WordKind::Level(VerticalDistance::Fl(350)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_speed_and_level_at_fix() {
+        let data = TestData::new();
+
+        let prompt = "EDDH N2/N0450F350 N1";
+        let tokens = Tokens::new(prompt, &data.nd, &[]);
+
+        assert_eq!(
+            tokens
+                .tokens()
+                .iter()
+                .map(|token| token.kind.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                TokenKind::Airport {
+                    arpt: data.airport("EDDH"),
+                    rwy: None
+                },
+                TokenKind::NavAid(data.vrp("EDDH", "N2")),
+                TokenKind::Speed(Speed::kt(450.0)),
+                TokenKind::Level(VerticalDistance::Fl(350)),
+                TokenKind::NavAid(data.vrp("EDDH", "N1")),
+            ]
+        );
+        assert_eq!(tokens.to_string(), prompt);
+    }
+
+    #[test]
+    fn lexes_runway_without_leading_zero() {
+        let data = TestData::new();
+        let edhl = data.airport("EDHL");
+        let rwy07 = edhl.runways.iter().find(|r| r.designator == "07").cloned();
+
+        for prompt in ["EDHL7", "EDHL07"] {
+            let words = Lexer::lex(prompt, &data.nd, &[]);
+
+            assert_eq!(
+                words[0].kind,
+                WordKind::Airport {
+                    arpt: Shared::clone(&edhl),
+                    rwy: rwy07.clone()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn parallel_runways_without_suffix_are_ambiguous() {
+        let records = br#"
+SEURP EDHLEDA        0        N N53481800E010430400E002000055                   P    MWGE    LUBECK-BLANKENSEE             385832513
+SEURP EDHLEDGRW07L   0068960720 N53480876E010421519                          197                                           141222513
+SEURP EDHLEDGRW07R   0068960720 N53480876E010421519                          197                                           141222513
+"#;
+        let nd = NavigationData::try_from_arinc424(records).expect("records should be valid");
+
+        assert_eq!(
+            Lexer::lex("EDHL07", &nd, &[])[0].kind,
+            WordKind::Err(Error::AmbiguousRunway {
+                arpt: "EDHL".to_string(),
+                rwy: "07".to_string(),
+                candidates: vec!["07L".to_string(), "07R".to_string()],
+            })
+        );
+    }
+
     #[test]
     fn tokenizes_prompt() {
         let data = TestData::new();
 
         let prompt = "N0107 A0250 EDDH N2 N1 DCT EDHL W DCT W EDAH";
-        let tokens: Vec<TokenKind> = Tokens::new(prompt, &data.nd)
+        let tokens: Vec<TokenKind> = Tokens::new(prompt, &data.nd, &[])
             .into_iter()
             .map(|token| token.kind)
             .collect();
@@ -630,7 +801,7 @@ SEURPCEDAHED W     ED0    V     N53505381E013552347
         let data = TestData::new();
 
         let prompt = "EDDH N2 N1 W EDHL";
-        let tokens: Vec<TokenKind> = Tokens::new(prompt, &data.nd)
+        let tokens: Vec<TokenKind> = Tokens::new(prompt, &data.nd, &[])
             .into_iter()
             .map(|token| token.kind)
             .collect();
@@ -657,11 +828,13 @@ SEURPCEDAHED W     ED0    V     N53505381E013552347
     fn fails_tokenize_on_ambiguous_prompt() {
         let data = TestData::new();
         let prompt = "EDAH W W EDHL";
-        let err = Tokens::new(prompt, &data.nd)
+        let err = Tokens::new(prompt, &data.nd, &[])
             .into_iter()
-            .find(|token| match token.kind {
-                TokenKind::Err(Error::AmbiguousTerminalArea { .. }) => true,
-                _ => false,
+            .find(|token| {
+                matches!(
+                    token.kind,
+                    TokenKind::Err(Error::AmbiguousTerminalArea { .. })
+                )
             });
 
         assert!(err.is_some());
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use geo::Point;
+
+use crate::measurements::{Length, Pressure};
+use crate::{VerticalDistance, Wind};
+
+/// Provides the wind at a position and level, e.g. from a wind forecast.
+pub trait WindModel {
+    /// Returns the wind at the `coord` and `level`.
+    ///
+    /// Returns `None` if the model has no wind for the position or level.
+    fn wind_at(&self, coord: Point<f64>, level: VerticalDistance) -> Option<Wind>;
+}
+
+/// A wind model of forecast levels, e.g. FL050 and FL100.
+///
+/// Winds are forecast for a few standard levels. The model returns the wind of
+/// the band nearest to the requested level regardless of the position. A level
+/// halfway between two bands takes the wind of the lower band.
+///
+/// # Examples
+///
+/// ```
+/// # use std::str::FromStr;
+/// # use geo::Point;
+/// # use efb::route::{BandedWindModel, WindModel};
+/// # use efb::{VerticalDistance, Wind};
+/// # fn main() -> Result<(), efb::error::Error> {
+/// let model = BandedWindModel::new(vec![
+///     (VerticalDistance::Fl(50), Wind::from_str("27010KT")?),
+///     (VerticalDistance::Fl(100), Wind::from_str("29025KT")?),
+/// ]);
+///
+/// let wind = model.wind_at(Point::new(9.99, 53.63), VerticalDistance::Fl(90));
+/// assert_eq!(wind, Some(Wind::from_str("29025KT")?));
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BandedWindModel {
+    bands: Vec<(VerticalDistance, Wind)>,
+}
+
+impl BandedWindModel {
+    /// Creates a model from forecast `bands` of a level and the wind at that
+    /// level.
+    pub fn new(mut bands: Vec<(VerticalDistance, Wind)>) -> Self {
+        bands.sort_by(|(a, _), (b, _)| height(a).total_cmp(&height(b)));
+        Self { bands }
+    }
+
+    /// The forecast bands ordered from the lowest to the highest level.
+    pub fn bands(&self) -> &[(VerticalDistance, Wind)] {
+        &self.bands
+    }
+}
+
+impl WindModel for BandedWindModel {
+    /// Returns the wind of the band nearest to the `level`.
+    ///
+    /// Returns `None` if the model has no bands.
+    fn wind_at(&self, _coord: Point<f64>, level: VerticalDistance) -> Option<Wind> {
+        let level = height(&level);
+
+        self.bands
+            .iter()
+            .min_by(|(a, _), (b, _)| {
+                (height(a) - level)
+                    .abs()
+                    .total_cmp(&(height(b) - level).abs())
+            })
+            .map(|(_, wind)| *wind)
+    }
+}
+
+/// Returns the height of the level above mean sea level at standard pressure
+/// in meters.
+///
+/// An unlimited level is infinitely high.
+fn height(level: &VerticalDistance) -> f32 {
+    level
+        .to_msl(Pressure::STD, Length::ft(0.0))
+        .map_or(f32::INFINITY, |alt| alt.to_si())
+}
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2024 Joe Pearson
+// Copyright 2024, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -56,19 +56,19 @@ fn mb_matches_mass_and_fuel() {
     let mb = ac
         .mb(
             // On ramp we have a pilot in the front and a PAX in the back.
-            &vec![Mass::kg(80.0), Mass::kg(80.0)],
+            &[Mass::kg(80.0), Mass::kg(80.0)],
             // The PAX was a sky diver and jumped out during the flight.
-            &vec![Mass::kg(80.0), Mass::kg(0.0)],
+            &[Mass::kg(80.0), Mass::kg(0.0)],
             // We departed with 40 liter of Diesel distributed between the first
             // two tanks.
-            &vec![
+            &[
                 diesel!(Volume::l(20.0)),
                 diesel!(Volume::l(20.0)),
                 diesel!(Volume::l(0.0)),
             ],
             // Our PAX was so kind and did a pretty stunt by air refueling our
             // aircraft mid flight.
-            &vec![
+            &[
                 diesel!(Volume::l(40.0)),
                 diesel!(Volume::l(40.0)),
                 diesel!(Volume::l(10.0)),
@@ -121,7 +121,7 @@ fn mb_fuel_is_distributed_equally() {
     let _mb = ac
         .mb_from_const_mass_and_equally_distributed_fuel(
             // On ramp we have a pilot in the front and a PAX in the back.
-            &vec![Mass::kg(80.0), Mass::kg(80.0)],
+            &[Mass::kg(80.0), Mass::kg(80.0)],
             // We departed with 60 liter of Diesel distributed between all
             // tanks
             &diesel!(Volume::l(60.0)),
@@ -137,15 +137,15 @@ fn mb_for_exceeded_fuel_capacity_on_ramp() {
     let ac = aircraft();
 
     ac.mb(
-        &vec![Mass::kg(0.0), Mass::kg(0.0)],
-        &vec![Mass::kg(0.0), Mass::kg(0.0)],
+        &[Mass::kg(0.0), Mass::kg(0.0)],
+        &[Mass::kg(0.0), Mass::kg(0.0)],
         // Ooops... We have a type and tried to plan with 400 liter.
-        &vec![
+        &[
             diesel!(Volume::l(200.0)),
             diesel!(Volume::l(200.0)),
             diesel!(Volume::l(0.0)),
         ],
-        &vec![
+        &[
             diesel!(Volume::l(0.0)),
             diesel!(Volume::l(0.0)),
             diesel!(Volume::l(0.0)),
@@ -160,15 +160,15 @@ fn mb_for_exceeded_fuel_capacity_after_landing() {
     let ac = aircraft();
 
     ac.mb(
-        &vec![Mass::kg(0.0), Mass::kg(0.0)],
-        &vec![Mass::kg(0.0), Mass::kg(0.0)],
-        &vec![
+        &[Mass::kg(0.0), Mass::kg(0.0)],
+        &[Mass::kg(0.0), Mass::kg(0.0)],
+        &[
             diesel!(Volume::l(0.0)),
             diesel!(Volume::l(0.0)),
             diesel!(Volume::l(0.0)),
         ],
         // Ooops... We have a type and tried to land with 400 liter.
-        &vec![
+        &[
             diesel!(Volume::l(200.0)),
             diesel!(Volume::l(200.0)),
             diesel!(Volume::l(0.0)),
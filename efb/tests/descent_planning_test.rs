@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use efb::fp::ClimbDescentPerformance;
+use efb::measurements::{Length, LengthUnit, Mass, Speed, VerticalRate};
+use efb::nd::NavigationData;
+use efb::route::Route;
+use efb::{Fuel, FuelFlow, FuelType, VerticalDistance};
+
+const ARINC_424_RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURPCEDDHED N1    ED0    V     N53482105E010015451                                 WGE           NOVEMBER1                359892409
+SEURPCEDDHED N2    ED0    V     N53405701E010000576                                 WGE           NOVEMBER2                359902409
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+"#;
+
+fn route(prompt: &str) -> Route {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route.decode(prompt, &nd).expect("route should decode");
+    route
+}
+
+fn descent() -> ClimbDescentPerformance {
+    ClimbDescentPerformance::from_fn(
+        |_| {
+            (
+                Speed::kt(120.0),
+                VerticalRate::fpm(500.0),
+                FuelFlow::PerHour(Fuel::new(Mass::kg(10.0), FuelType::AvGas)),
+            )
+        },
+        VerticalDistance::Altitude(10000),
+    )
+}
+
+#[test]
+fn descent_starts_before_fix() {
+    let route = route("N0120 A0650 EDDH N2 N1 DCT EDHF");
+
+    // 3000 ft at 500 fpm takes 6 min which are 12 NM at 120 kt
+    let dist = route
+        .top_of_descent("N1", &VerticalDistance::Altitude(3500), &descent())
+        .expect("descent should be required");
+
+    assert!(
+        (*dist.convert_to(LengthUnit::NauticalMiles).value() - 12.0).abs() < 0.05,
+        "descent should start ~12 NM before N1, got {dist}"
+    );
+}
+
+#[test]
+fn headwind_shortens_descent_distance() {
+    let route = route("36020KT N0120 A0650 EDDH N2 N1 DCT EDHF");
+
+    let dist = route
+        .top_of_descent("N1", &VerticalDistance::Altitude(3500), &descent())
+        .expect("descent should be required");
+
+    assert!(dist < Length::nm(12.0), "{dist} should be less than 12 NM");
+}
+
+#[test]
+fn no_descent_below_target() {
+    let route = route("N0120 A0250 EDDH N2 N1 DCT EDHF");
+
+    assert_eq!(
+        route.top_of_descent("N1", &VerticalDistance::Altitude(3500), &descent()),
+        None
+    );
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use efb::fms::FMS;
+use efb::fp::{FlightPlanning, Performance};
+use efb::measurements::{Mass, Speed};
+use efb::nd::NavigationData;
+use efb::{Fuel, FuelFlow, FuelType, VerticalDistance};
+
+const ARINC_424_RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+SEURP EDHFEDGRW20    0034122060 N53594752E009344856                          098                                           120792502
+"#;
+
+fn fms() -> FMS {
+    let mut fms = FMS::new();
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    fms.modify_nd(|fms_nd| fms_nd.append(nd))
+        .expect("navigation data should be added");
+    fms
+}
+
+#[test]
+fn route_string_is_entered_route() {
+    let mut fms = fms();
+    let route = "N0107 A0250 EDDH33 EDHF20";
+
+    fms.decode(route.to_string()).expect("route should decode");
+
+    assert_eq!(fms.route_string(), route);
+}
+
+#[test]
+fn undo_and_redo_route() {
+    let mut fms = fms();
+    let a = "N0107 A0250 EDDH EDHF";
+    let b = "N0107 A0250 EDHF EDDH";
+
+    fms.decode(a.to_string()).expect("route should decode");
+    fms.decode(b.to_string()).expect("route should decode");
+    assert_eq!(fms.route().to_string(), b);
+
+    fms.undo().expect("route should decode");
+    assert_eq!(fms.route().to_string(), a);
+    assert!(fms.can_redo());
+
+    fms.redo().expect("route should decode");
+    assert_eq!(fms.route().to_string(), b);
+    assert!(!fms.can_redo());
+}
+
+#[test]
+fn clear_flight_planning_keeps_route() {
+    let mut fms = fms();
+    fms.decode("N0107 A0250 EDDH EDHF".to_string())
+        .expect("route should decode");
+
+    let perf = Performance::from_fn(
+        |_| {
+            (
+                Speed::kt(107.0),
+                FuelFlow::PerHour(Fuel::new(Mass::kg(18.0), FuelType::AvGas)),
+            )
+        },
+        VerticalDistance::Altitude(14000),
+    );
+    let mut builder = FlightPlanning::builder();
+    builder.perf(perf);
+
+    fms.set_flight_planning(builder)
+        .expect("flight planning should build");
+    assert!(fms.flight_planning().is_some());
+
+    fms.clear_flight_planning();
+
+    assert!(fms.flight_planning().is_none());
+    assert!(!fms.route().legs().is_empty());
+}
@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
-// Copyright 2025 Joe Pearson
+// Copyright 2025, 2026 Joe Pearson
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,10 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use efb::nd::{Fix, NavigationData};
-use efb::route::Route;
+use std::str::FromStr;
 
-const ARINC_424_RECORDS: &'static [u8] = br#"
+use geo::Point;
+
+use efb::error::Error;
+use efb::fp::{LegPerformance, Performance};
+use efb::measurements::{Angle, Length, Mass, Speed, Volume};
+use efb::nd::{Fix, NavAid, NavigationData, Shared, Waypoint};
+use efb::route::{BandedWindModel, FixResolver, Route, RouteString};
+use efb::{diesel, Fuel, FuelFlow, FuelType, VerticalDistance, Wind};
+
+const ARINC_424_RECORDS: &[u8] = br#"
 SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
 SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
 SEURPCEDDHED N1    ED0    V     N53482105E010015451                                 WGE           NOVEMBER1                359892409
@@ -25,7 +33,7 @@ SEURP EDHFEDA        0        N N53593300E009343600E000000082
 SEURP EDHFEDGRW20    0034122060 N53594752E009344856                          098                                           120792502
 "#;
 
-const ROUTE: &'static str = r#"EDDH33 N2 N1 DCT EDHF20"#;
+const ROUTE: &str = r#"EDDH33 N2 N1 DCT EDHF20"#;
 
 fn route() -> Route {
     let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
@@ -49,6 +57,22 @@ fn origin_and_destination() {
     assert_eq!(destination.ident(), "EDHF");
 }
 
+#[test]
+fn number_of_legs_and_fixes() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    assert!(route.is_empty());
+    assert_eq!(route.num_fixes(), 0);
+
+    route
+        .decode("EDDH33 N2 EDHF20", &nd)
+        .expect("route should decode");
+
+    assert!(!route.is_empty());
+    assert_eq!(route.num_legs(), 2);
+    assert_eq!(route.num_fixes(), 3);
+}
+
 #[test]
 fn takeoff_rwy() {
     let route = route();
@@ -63,6 +87,265 @@ fn landing_rwy() {
     assert_eq!(designator, Some(String::from("20")));
 }
 
+#[test]
+fn fixes_of_navigation_data() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut fixes: Vec<_> = nd
+        .fixes()
+        .map(|fix| match fix {
+            NavAid::Airport(_) => format!("airport {}", fix.ident()),
+            NavAid::Waypoint(_) => format!("waypoint {}", fix.ident()),
+        })
+        .collect();
+    fixes.sort();
+
+    assert_eq!(
+        fixes,
+        vec!["airport EDDH", "airport EDHF", "waypoint N1", "waypoint N2"]
+    );
+}
+
+#[test]
+fn bounds_enclose_fixes() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let route = route();
+
+    let origin = route.origin().expect("route should have an origin");
+    let destination = route
+        .destination()
+        .expect("route should have a destination");
+
+    let bounds = route.bounds().expect("route should have bounds");
+    for fix in [origin.coordinate(), destination.coordinate()] {
+        assert!((bounds.min().x..=bounds.max().x).contains(&fix.x()));
+        assert!((bounds.min().y..=bounds.max().y).contains(&fix.y()));
+    }
+
+    let nd_bounds = nd.bounds().expect("navigation data should have bounds");
+    assert!(nd_bounds.min().x <= bounds.min().x && nd_bounds.max().x >= bounds.max().x);
+    assert!(nd_bounds.min().y <= bounds.min().y && nd_bounds.max().y >= bounds.max().y);
+    assert_eq!(NavigationData::new().bounds(), None);
+}
+
+#[test]
+fn strict_decode_requires_origin_and_destination() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+
+    // a lone airport is fine while the route is entered
+    assert_eq!(route.decode("EDDH", &nd), Ok(()));
+    assert_eq!(
+        route.decode_strict("EDDH", &nd),
+        Err(Error::IncompleteRoute)
+    );
+    assert_eq!(
+        route.decode_strict("EDDH N2 N1", &nd),
+        Err(Error::IncompleteRoute)
+    );
+    assert_eq!(route.decode_strict(ROUTE, &nd), Ok(()));
+}
+
+#[test]
+fn resolver_provides_fix_missing_in_nd() {
+    struct UserWaypoints;
+
+    impl FixResolver for UserWaypoints {
+        fn resolve(&self, ident: &str) -> Option<NavAid> {
+            (ident == "HOME").then(|| {
+                NavAid::Waypoint(Shared::new(Waypoint::new(
+                    "HOME".to_string(),
+                    "My home field".to_string(),
+                    Point::new(9.8, 53.8),
+                )))
+            })
+        }
+    }
+
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    assert!(nd.find("HOME").is_none());
+
+    let mut route = Route::new();
+    route
+        .decode_with_resolvers("EDDH33 HOME EDHF20", &nd, &[&UserWaypoints])
+        .expect("route should decode");
+
+    let idents: Vec<String> = route.legs().iter().map(|leg| leg.to().ident()).collect();
+    assert_eq!(idents, vec!["HOME", "EDHF"]);
+}
+
+#[test]
+fn repeated_fix_is_dropped() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+
+    route
+        .decode("EDDH33 N2 N2 N1 DCT EDHF20", &nd)
+        .expect("route should decode");
+
+    assert_eq!(route.legs().len(), self::route().legs().len());
+    assert_eq!(route.warnings(), [Error::RepeatedFix("N2".to_string())]);
+    assert_eq!(
+        route.decode_strict("EDDH33 N2 N2 N1 DCT EDHF20", &nd),
+        Err(Error::RepeatedFix("N2".to_string()))
+    );
+}
+
+#[test]
+fn wind_of_nearest_forecast_band() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route
+        .decode("N0107 F090 EDDH EDHF", &nd)
+        .expect("route should decode");
+
+    let fl050 = Wind::from_str("27010KT").expect("wind should parse");
+    let fl100 = Wind::from_str("29025KT").expect("wind should parse");
+    let model = BandedWindModel::new(vec![
+        (VerticalDistance::Fl(50), fl050),
+        (VerticalDistance::Fl(100), fl100),
+    ]);
+
+    route.apply_wind_model(&model);
+
+    assert_eq!(route.legs()[0].wind(), Some(&fl100));
+    assert!(route.legs()[0].gs().is_some());
+}
+
+#[test]
+fn wind_kept_without_forecast_bands() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route
+        .decode("29020KT N0107 F090 EDDH EDHF", &nd)
+        .expect("route should decode");
+
+    route.apply_wind_model(&BandedWindModel::default());
+
+    assert_eq!(
+        route.legs()[0].wind(),
+        Some(&Wind::from_str("29020KT").expect("wind should parse"))
+    );
+}
+
+#[test]
+fn summary() {
+    let route = route();
+    let summary = route.summary(None);
+
+    // no TAS or wind to compute ETE and no performance to compute fuel
+    assert_eq!(summary, "EDDH→EDHF, 3 legs, 30 NM");
+}
+
+#[test]
+fn summary_of_flight_longer_than_a_day() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route
+        .decode("N0001 EDDH EDHF", &nd)
+        .expect("route should decode");
+
+    // 26 NM at 1 kt takes more than a day
+    assert_eq!(route.summary(None), "EDDH→EDHF, 1 leg, 26 NM, 26:14");
+}
+
+#[test]
+fn alternate_totals() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route
+        .decode("N0107 A0025 EDDH33 N2 N1 DCT EDHF20", &nd)
+        .expect("route should decode");
+    route.set_alternate(nd.find("EDDH"));
+
+    let cruise = Performance::from_fn(
+        |_| {
+            (
+                Speed::kt(107.0),
+                FuelFlow::PerHour(diesel!(Volume::l(21.0))),
+            )
+        },
+        VerticalDistance::Fl(100),
+    );
+    let perf = LegPerformance::new(Some(&cruise), None, None);
+
+    let alternate = route.alternate().expect("route should have an alternate");
+    let totals = route
+        .alternate_totals(Some(&perf))
+        .expect("route should have an alternate");
+    let fuel = *totals.fuel().expect("fuel should be computed").total();
+
+    assert!(*totals.dist().value() > 0.0);
+    assert!(*fuel.mass.value() > 0.0);
+    assert_eq!(totals.dist(), alternate.dist());
+    assert_eq!(totals.ete(), alternate.ete());
+    assert_eq!(
+        Some(fuel),
+        alternate.fuel(&perf).map(|leg_fuel| *leg_fuel.total())
+    );
+}
+
+#[test]
+fn point_of_no_return_with_headwind() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let cruise = Performance::from_fn(
+        |_| {
+            (
+                Speed::kt(107.0),
+                FuelFlow::PerHour(diesel!(Volume::l(21.0))),
+            )
+        },
+        VerticalDistance::Fl(100),
+    );
+
+    let pnr = |route: &str| {
+        let mut r = Route::new();
+        r.decode(route, &nd).expect("route should decode");
+        let (_, dist) = r
+            .point_of_no_return(Mass::kg(25.0), Mass::kg(20.0), &cruise)
+            .expect("PNR should be on the route");
+        let gs = *r.legs()[0].gs().expect("leg should have a ground speed");
+        (dist, dist / gs)
+    };
+
+    // EDHF is north-west of EDDH, so the wind is a headwind outbound
+    let (calm_dist, calm_time) = pnr("N0107 A0025 EDDH33 EDHF20");
+    let (dist, time) = pnr("31030KT N0107 A0025 EDDH33 EDHF20");
+
+    // in calm air the PNR is halfway, with a headwind more than half of the
+    // endurance is spent outbound
+    assert!(dist < calm_dist);
+    assert!(time > calm_time);
+}
+
+#[test]
+fn cumulative_distances() {
+    let route = route();
+    let totals: Vec<_> = route
+        .accumulate_legs(None)
+        .map(|totals| *totals.dist())
+        .collect();
+
+    assert_eq!(route.cumulative_distances(), totals);
+}
+
+#[test]
+fn position_at_distance_along_route() {
+    let route = route();
+    let second = &route.legs()[1];
+
+    // N2 is 3 NM and N1 11 NM along the route
+    let (leg, point) = route
+        .position_at(Length::nm(7.0))
+        .expect("position should be on the route");
+
+    let (from, to) = (second.from().coordinate(), second.to().coordinate());
+    assert_eq!(leg, 1);
+    assert!(point.y() > from.y().min(to.y()) && point.y() < from.y().max(to.y()));
+    assert!(point.x() > from.x().min(to.x()) && point.x() < from.x().max(to.x()));
+
+    assert_eq!(route.position_at(Length::nm(31.0)), None);
+}
+
 #[test]
 fn accumulate_legs() {
     let route = route();
@@ -98,3 +381,35 @@ fn accumulate_legs() {
         30.0
     );
 }
+
+#[test]
+fn route_string_decodes_like_handwritten_route() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+
+    let prompt = RouteString::new()
+        .wind(Wind {
+            direction: Angle::t(290.0),
+            speed: Speed::kt(20.0),
+        })
+        .speed(Speed::kt(107.0))
+        .level(VerticalDistance::Altitude(2500))
+        .fix("EDDH33")
+        .fix("N2")
+        .fix("N1")
+        .direct()
+        .fix("EDHF20")
+        .build()
+        .expect("route string should build");
+
+    let mut built = Route::new();
+    built
+        .decode(&prompt, &nd)
+        .expect("built route should decode");
+
+    let mut handwritten = Route::new();
+    handwritten
+        .decode("29020KT N0107 A0250 EDDH33 N2 N1 DCT EDHF20", &nd)
+        .expect("handwritten route should decode");
+
+    assert_eq!(built.legs(), handwritten.legs());
+}
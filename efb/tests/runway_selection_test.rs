@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use efb::error::Error;
+use efb::fms::FMS;
+use efb::nd::NavigationData;
+
+const ARINC_424_RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDDHEDGRW33    0120273330 N53374300E009595081                          151                                           124362502
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+SEURP EDHFEDGRW20    0034122060 N53594752E009344856                          098                                           120792502
+"#;
+
+fn fms(route: &str) -> FMS {
+    let mut fms = FMS::new();
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    fms.modify_nd(|fms_nd| fms_nd.append(nd))
+        .expect("navigation data should be added");
+    fms.decode(route.to_string()).expect("route should decode");
+    fms
+}
+
+#[test]
+fn sets_takeoff_and_landing_runway() {
+    let mut fms = fms("N0107 A0250 EDDH EDHF");
+
+    fms.set_takeoff_runway("33").expect("EDDH has runway 33");
+    fms.set_landing_runway("20").expect("EDHF has runway 20");
+
+    let route = fms.route();
+    assert_eq!(
+        route.takeoff_rwy().map(|rwy| rwy.designator.as_str()),
+        Some("33")
+    );
+    assert_eq!(
+        route.landing_rwy().map(|rwy| rwy.designator.as_str()),
+        Some("20")
+    );
+    assert_eq!(route.to_string(), "N0107 A0250 EDDH33 EDHF20");
+}
+
+#[test]
+fn rejects_unknown_runway() {
+    let mut fms = fms("N0107 A0250 EDDH33 EDHF");
+
+    assert_eq!(
+        fms.set_takeoff_runway("15"),
+        Err(Error::UnknownRunwayInRoute {
+            arpt: "EDDH".to_string(),
+            rwy: "15".to_string(),
+        })
+    );
+    assert_eq!(
+        fms.set_landing_runway("02"),
+        Err(Error::UnknownRunwayInRoute {
+            arpt: "EDHF".to_string(),
+            rwy: "02".to_string(),
+        })
+    );
+
+    // the route stays untouched
+    assert_eq!(fms.route().to_string(), "N0107 A0250 EDDH33 EDHF");
+}
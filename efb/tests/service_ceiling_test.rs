@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2026 Joe Pearson
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use efb::error::Error;
+use efb::fp::{FlightPlanning, Performance};
+use efb::measurements::{Mass, Speed};
+use efb::nd::NavigationData;
+use efb::route::Route;
+use efb::{Fuel, FuelFlow, FuelType, VerticalDistance};
+
+const ARINC_424_RECORDS: &[u8] = br#"
+SEURP EDDHEDA        0        N N53374900E009591762E002000053                   P    MWGE    HAMBURG                       356462409
+SEURP EDHFEDA        0        N N53593300E009343600E000000082                   P    MWGE    ITZEHOE/HUNGRIGER WOLF        320782409
+"#;
+
+fn planning(prompt: &str, ceiling: VerticalDistance) -> FlightPlanning {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route.decode(prompt, &nd).expect("route should decode");
+
+    let perf = Performance::from_fn(
+        |_| {
+            (
+                Speed::kt(107.0),
+                FuelFlow::PerHour(Fuel::new(Mass::kg(18.0), FuelType::AvGas)),
+            )
+        },
+        VerticalDistance::Fl(200),
+    )
+    .with_ceiling(ceiling);
+
+    FlightPlanning::builder()
+        .perf(perf)
+        .build(&route)
+        .expect("flight planning should build")
+}
+
+#[test]
+fn warns_above_service_ceiling() {
+    // a piston single with a service ceiling of 14,000 ft
+    let fp = planning("N0107 F180 EDDH EDHF", VerticalDistance::Altitude(14000));
+
+    assert_eq!(
+        fp.warnings(),
+        &[Error::AboveServiceCeiling {
+            level: VerticalDistance::Fl(180),
+            ceiling: VerticalDistance::Altitude(14000),
+        }]
+    );
+}
+
+#[test]
+fn no_warning_below_service_ceiling() {
+    let fp = planning("N0107 A0250 EDDH EDHF", VerticalDistance::Altitude(14000));
+    assert!(fp.warnings().is_empty());
+}
+
+#[test]
+fn warns_above_service_ceiling_in_other_datum() {
+    let ceiling = VerticalDistance::PressureAltitude(14000);
+    let fp = planning("N0107 F180 EDDH EDHF", ceiling);
+
+    assert_eq!(
+        fp.warnings(),
+        &[Error::AboveServiceCeiling {
+            level: VerticalDistance::Fl(180),
+            ceiling,
+        }]
+    );
+}
+
+#[test]
+fn no_service_ceiling_from_table() {
+    let nd = NavigationData::try_from_arinc424(ARINC_424_RECORDS).expect("records should be valid");
+    let mut route = Route::new();
+    route
+        .decode("N0107 F180 EDDH EDHF", &nd)
+        .expect("route should decode");
+
+    // the table ends below the level, but no service ceiling is set
+    let perf = Performance::from_fn(
+        |_| {
+            (
+                Speed::kt(107.0),
+                FuelFlow::PerHour(Fuel::new(Mass::kg(18.0), FuelType::AvGas)),
+            )
+        },
+        VerticalDistance::Altitude(14000),
+    );
+
+    let fp = FlightPlanning::builder()
+        .perf(perf)
+        .build(&route)
+        .expect("flight planning should build");
+
+    assert!(fp.warnings().is_empty());
+}